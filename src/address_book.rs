@@ -0,0 +1,159 @@
+use {
+    crate::constants::ADDRESS_BOOK_RELATIVE_PATH,
+    anyhow::{Context, bail},
+    serde::{Deserialize, Serialize},
+    solana_pubkey::Pubkey,
+    std::{
+        collections::BTreeMap,
+        env::home_dir,
+        fs,
+        path::{Path, PathBuf},
+        str::FromStr,
+    },
+};
+
+pub fn address_book_path() -> PathBuf {
+    let mut path = home_dir().expect("Error getting home path");
+    path.push(ADDRESS_BOOK_RELATIVE_PATH);
+    path
+}
+
+/// Named addresses, stored as a `name = "pubkey"` table. Names are matched
+/// case-insensitively.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AddressBook {
+    #[serde(default)]
+    addresses: BTreeMap<String, String>,
+}
+
+impl AddressBook {
+    /// Loads the address book, or an empty one if none was saved yet
+    pub fn load() -> anyhow::Result<Self> {
+        Self::load_from_path(&address_book_path())
+    }
+
+    pub fn load_from_path(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let data = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read address book {}", path.display()))?;
+        let book: AddressBook = toml::from_str(&data)
+            .with_context(|| format!("Failed to parse address book {}", path.display()))?;
+
+        for (name, address) in &book.addresses {
+            Pubkey::from_str(address)
+                .with_context(|| format!("Address book entry `{name}` is not a valid pubkey"))?;
+        }
+
+        Ok(book)
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        self.save_to_path(&address_book_path())
+    }
+
+    pub fn save_to_path(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, toml::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Adds or replaces an entry, returning the address it replaced
+    pub fn insert(&mut self, name: &str, address: Pubkey) -> anyhow::Result<Option<Pubkey>> {
+        let name = name.trim();
+
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            bail!("Names can't be empty or contain spaces");
+        }
+        // A name that parses as a pubkey would never be looked up
+        if Pubkey::from_str(name).is_ok() {
+            bail!("`{name}` is itself a pubkey, pick a shorter name");
+        }
+
+        let previous = self.get(name);
+        self.addresses
+            .insert(name.to_lowercase(), address.to_string());
+        Ok(previous)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Pubkey> {
+        self.addresses
+            .remove(&name.trim().to_lowercase())
+            .and_then(|address| address.parse().ok())
+    }
+
+    pub fn get(&self, name: &str) -> Option<Pubkey> {
+        self.addresses
+            .get(&name.trim().to_lowercase())
+            .and_then(|address| address.parse().ok())
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = (&str, Pubkey)> {
+        self.addresses
+            .iter()
+            .filter_map(|(name, address)| Some((name.as_str(), address.parse().ok()?)))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.addresses.is_empty()
+    }
+}
+
+/// The address `name` stands for in the saved address book, if any
+pub fn resolve_address_name(name: &str) -> Option<Pubkey> {
+    AddressBook::load().ok()?.get(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, tempfile::TempDir};
+
+    #[test]
+    fn test_address_book_round_trip() -> anyhow::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir.path().join("addresses.toml");
+        let exchange = Pubkey::new_unique();
+        let validator = Pubkey::new_unique();
+
+        let mut book = AddressBook::load_from_path(&path)?;
+        assert!(book.is_empty());
+
+        assert_eq!(book.insert("Exchange", exchange)?, None);
+        assert_eq!(book.insert("validator", validator)?, None);
+        assert_eq!(book.insert("exchange", validator)?, Some(exchange));
+        book.save_to_path(&path)?;
+
+        let book = AddressBook::load_from_path(&path)?;
+        assert_eq!(book.get("EXCHANGE"), Some(validator));
+        assert_eq!(book.get(" validator "), Some(validator));
+        assert_eq!(book.get("unknown"), None);
+        assert_eq!(book.entries().count(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_address_book_rejects_bad_names() {
+        let mut book = AddressBook::default();
+        let address = Pubkey::new_unique();
+
+        assert!(book.insert("", address).is_err());
+        assert!(book.insert("cold wallet", address).is_err());
+        assert!(book.insert(&address.to_string(), address).is_err());
+        assert!(book.remove("missing").is_none());
+    }
+
+    #[test]
+    fn test_address_book_rejects_invalid_entries() -> anyhow::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir.path().join("addresses.toml");
+        fs::write(&path, "[addresses]\nexchange = \"not-a-pubkey\"\n")?;
+
+        assert!(AddressBook::load_from_path(&path).is_err());
+        Ok(())
+    }
+}
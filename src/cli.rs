@@ -0,0 +1,114 @@
+use {
+    crate::commands::{
+        Command, account::AccountCommand, address_book::AddressBookCommand,
+        cluster::ClusterCommand, config::ConfigCommand, program::ProgramCommand,
+        stake::StakeCommand, token::TokenCommand, transaction::TransactionCommand,
+        vote::VoteCommand,
+    },
+    clap::{Parser, Subcommand},
+    solana_commitment_config::CommitmentLevel,
+};
+
+/// Runs a single command and exits. Without a subcommand Scilla starts the
+/// interactive session
+#[derive(Debug, Parser)]
+#[command(name = "scilla", version, about = "Hacking Through the Solana Matrix")]
+pub struct Cli {
+    /// Print command results as JSON instead of tables
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    /// Skip confirmation prompts, answering yes
+    #[arg(long, short = 'y', global = true)]
+    pub yes: bool,
+
+    /// Print without colors or text styling. Also enabled by setting
+    /// `NO_COLOR`
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// Commitment to read and confirm at for this run, instead of the one in
+    /// the config: processed, confirmed or finalized
+    #[arg(long, global = true, value_parser = parse_commitment)]
+    pub commitment: Option<CommitmentLevel>,
+
+    #[command(subcommand)]
+    pub command: Option<CliCommand>,
+}
+
+/// Each group takes the command name followed by the answers to its prompts,
+/// in the order the interactive session asks for them. Choices are answered
+/// by their label, yes/no questions with `yes` or `no`. A missing answer reads
+/// as empty input, which only optional prompts accept. Confirmations of
+/// costly operations are skipped with `--yes` instead.
+#[derive(Debug, Subcommand)]
+pub enum CliCommand {
+    /// Account operations
+    Account {
+        command: AccountCommand,
+        args: Vec<String>,
+    },
+    /// SPL token operations
+    Token {
+        command: TokenCommand,
+        args: Vec<String>,
+    },
+    /// Cluster queries
+    Cluster {
+        command: ClusterCommand,
+        args: Vec<String>,
+    },
+    /// Stake account operations
+    Stake {
+        command: StakeCommand,
+        args: Vec<String>,
+    },
+    /// Vote account operations
+    Vote {
+        command: VoteCommand,
+        args: Vec<String>,
+    },
+    /// Transaction operations
+    Transaction {
+        command: TransactionCommand,
+        args: Vec<String>,
+    },
+    /// On-chain program deployment
+    Program {
+        command: ProgramCommand,
+        args: Vec<String>,
+    },
+    /// Named addresses
+    AddressBook {
+        command: AddressBookCommand,
+        args: Vec<String>,
+    },
+    /// Scilla configuration
+    Config {
+        command: ConfigCommand,
+        args: Vec<String>,
+    },
+}
+
+fn parse_commitment(value: &str) -> Result<CommitmentLevel, String> {
+    value
+        .parse()
+        .map_err(|_| "expected processed, confirmed or finalized".to_string())
+}
+
+impl CliCommand {
+    /// Splits into the command to run and the prompt answers to feed it
+    pub fn into_parts(self) -> (Command, Vec<String>) {
+        match self {
+            CliCommand::Account { command, args } => (Command::Account(command), args),
+            CliCommand::Token { command, args } => (Command::Token(command), args),
+            CliCommand::Cluster { command, args } => (Command::Cluster(command), args),
+            CliCommand::Stake { command, args } => (Command::Stake(command), args),
+            CliCommand::Vote { command, args } => (Command::Vote(command), args),
+            CliCommand::Transaction { command, args } => (Command::Transaction(command), args),
+            CliCommand::Program { command, args } => (Command::Program(command), args),
+            CliCommand::AddressBook { command, args } => (Command::AddressBook(command), args),
+            CliCommand::Config { command, args } => (Command::ScillaConfig(command), args),
+        }
+    }
+}
@@ -1,31 +1,88 @@
 use {
     crate::{
-        commands::CommandExec,
+        commands::{CommandExec, nonce::fetch_nonce_data, stake::stake_state_summary},
+        config::{ScillaConfig, expand_tilde},
+        constants::{
+            ACTIVE_STAKE_EPOCH_BOUND, AIRDROP_FAUCET_LIMIT_SOL, AIRDROP_RETRY_BASE_DELAY_SECS,
+            DEFAULT_AIRDROP_ATTEMPTS, DEFAULT_CONFIRMATION_TIMEOUT_SECS, DEFAULT_DERIVATION_PATH,
+            DEFAULT_MS_PER_SLOT, HEXDUMP_PREVIEW_BYTES, LOOKUP_TABLE_COOLDOWN_SLOTS,
+            LOOKUP_TABLE_EXTEND_CHUNK_SIZE, SOLANA_FAUCET_URL, WS_RECONNECT_DELAY_SECS,
+        },
         context::ScillaContext,
         error::ScillaResult,
-        misc::helpers::{bincode_deserialize, lamports_to_sol},
-        prompt::prompt_data,
-        ui::{print_error, show_spinner},
+        misc::helpers::{
+            DurableNonce, OptionalInput, SolAmount, TransferRow, bincode_deserialize,
+            build_and_send_nonce_tx, build_and_send_tx, build_and_send_versioned_tx,
+            confirm_keypair_overwrite, create_lookup_table, deactivate_lookup_table,
+            ensure_sufficient_balance, estimate_fee, fetch_accounts, format_duration,
+            format_sol_exact, generate_seed_phrase, hexdump, is_rate_limit_error,
+            is_valid_message_signature, keypair_from_seed_phrase, lamports_to_sol,
+            lookup_table_candidates, lookup_table_rent, lookup_table_setup_fee, memo_instruction,
+            pack_instructions, parse_pubkey_list, parse_transfer_csv, read_keypair_from_path,
+            render_qr, save_keypair, slots_to_duration, solana_pay_uri, truncate_middle,
+            wait_for_signature_notification, with_compute_budget,
+        },
+        notice,
+        prompt::{
+            PubkeyCheck, prompt_confirm, prompt_data, prompt_data_with_default, prompt_nonce,
+            prompt_pubkey, prompt_select, prompt_yes_no,
+        },
+        ui::{
+            new_table, print_error, print_output, print_tx_output, show_spinner,
+            show_spinner_with_status,
+        },
     },
-    anyhow::bail,
-    comfy_table::{Cell, Table, presets::UTF8_FULL},
+    anyhow::{Context, bail},
+    base64::Engine,
+    clap::ValueEnum,
+    comfy_table::{Cell, presets::UTF8_FULL},
     console::style,
-    inquire::Select,
-    solana_nonce::versions::Versions,
+    futures::StreamExt,
+    indicatif::ProgressBar,
+    inquire::{Confirm, Select},
+    serde_json::json,
+    solana_account::Account,
+    solana_account_decoder_client_types::{UiAccount, UiAccountEncoding},
+    solana_hash::Hash,
+    solana_instruction::Instruction,
+    solana_keypair::{Keypair, Signer},
+    solana_message::AddressLookupTableAccount,
+    solana_nonce::{state::State as NonceState, versions::Versions},
     solana_pubkey::Pubkey,
-    solana_rpc_client_api::config::{RpcLargestAccountsConfig, RpcLargestAccountsFilter},
-    std::fmt,
+    solana_pubsub_client::nonblocking::pubsub_client::PubsubClient,
+    solana_rpc_client_api::config::{
+        RpcAccountInfoConfig, RpcLargestAccountsConfig, RpcLargestAccountsFilter,
+    },
+    solana_signature::Signature,
+    solana_stake_interface::{program::id as stake_program_id, state::StakeStateV2},
+    solana_system_interface::instruction::{advance_nonce_account, transfer},
+    solana_vote_program::vote_state::VoteStateV4,
+    std::{
+        fmt, fs,
+        ops::Range,
+        path::{Path, PathBuf},
+        time::{Duration, Instant},
+    },
 };
 
 /// Commands related to wallet or account management
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, ValueEnum)]
 pub enum AccountCommand {
     FetchAccount,
     Balance,
+    MultiBalance,
     Transfer,
+    BatchTransfer,
     Airdrop,
+    ConfirmTransaction,
     LargestAccounts,
     NonceAccount,
+    NewKeypair,
+    SignMessage,
+    VerifyMessage,
+    Watch,
+    Receive,
+    #[value(skip)]
     GoBack,
 }
 
@@ -34,10 +91,18 @@ impl AccountCommand {
         match self {
             AccountCommand::FetchAccount => "Fetching account…",
             AccountCommand::Balance => "Checking SOL balance…",
+            AccountCommand::MultiBalance => "Checking balances…",
             AccountCommand::Transfer => "Sending SOL…",
+            AccountCommand::BatchTransfer => "Sending batch transfers…",
             AccountCommand::Airdrop => "Requesting SOL on devnet/testnet…",
+            AccountCommand::ConfirmTransaction => "Waiting for transaction confirmation…",
             AccountCommand::LargestAccounts => "Fetching largest accounts on the cluster…",
             AccountCommand::NonceAccount => "Inspecting or managing durable nonces…",
+            AccountCommand::NewKeypair => "Generating keypair…",
+            AccountCommand::SignMessage => "Signing message…",
+            AccountCommand::VerifyMessage => "Verifying signature…",
+            AccountCommand::Watch => "Watching account…",
+            AccountCommand::Receive => "Building payment request…",
             AccountCommand::GoBack => "Going back…",
         }
     }
@@ -48,10 +113,18 @@ impl fmt::Display for AccountCommand {
         let command = match self {
             AccountCommand::FetchAccount => "Fetch account",
             AccountCommand::Balance => "Check balance",
+            AccountCommand::MultiBalance => "Check multiple balances",
             AccountCommand::Transfer => "Transfer SOL",
+            AccountCommand::BatchTransfer => "Batch transfer SOL from CSV",
             AccountCommand::Airdrop => "Request airdrop",
+            AccountCommand::ConfirmTransaction => "Confirm transaction",
             AccountCommand::LargestAccounts => "View largest accounts",
-            AccountCommand::NonceAccount => "View nonce account",
+            AccountCommand::NonceAccount => "Manage nonce accounts",
+            AccountCommand::NewKeypair => "Generate new keypair",
+            AccountCommand::SignMessage => "Sign message",
+            AccountCommand::VerifyMessage => "Verify signed message",
+            AccountCommand::Watch => "Watch account live",
+            AccountCommand::Receive => "Receive SOL (QR code)",
             AccountCommand::GoBack => "Go back",
         };
         write!(f, "{command}")
@@ -62,25 +135,282 @@ impl AccountCommand {
     pub async fn process_command(&self, ctx: &ScillaContext) -> ScillaResult<()> {
         match self {
             AccountCommand::FetchAccount => {
-                let pubkey: Pubkey = prompt_data("Enter Pubkey:")?;
-                show_spinner(self.spinner_msg(), fetch_acc_data(ctx, &pubkey)).await?;
+                let pubkey = prompt_pubkey(ctx, "Enter Pubkey:", PubkeyCheck::Existing).await?;
+                let dump_path: OptionalInput<String> =
+                    prompt_data("Save raw data to file (leave empty to skip):")?;
+                let dump_path = dump_path.value().map(|path| expand_tilde(&path));
+                show_spinner(
+                    self.spinner_msg(),
+                    fetch_acc_data(ctx, &pubkey, dump_path.as_deref()),
+                )
+                .await?;
             }
             AccountCommand::Balance => {
-                let pubkey: Pubkey = prompt_data("Enter Pubkey :")?;
+                let pubkey: OptionalInput<Pubkey> =
+                    prompt_data("Enter Pubkey (leave empty for your wallet):")?;
+                let pubkey = pubkey.value().unwrap_or(*ctx.pubkey());
                 show_spinner(self.spinner_msg(), fetch_account_balance(ctx, &pubkey)).await?;
             }
+            AccountCommand::MultiBalance => {
+                let input: String =
+                    prompt_data("Enter Pubkeys separated by commas, or a file with one per line:")?;
+                let path = Path::new(input.trim());
+                let content = match path.is_file() {
+                    true => fs::read_to_string(path)
+                        .with_context(|| format!("Failed to read {}", path.display()))?,
+                    false => input,
+                };
+                let pubkeys = parse_pubkey_list(&content)?;
+
+                show_spinner(self.spinner_msg(), fetch_multiple_balances(ctx, &pubkeys)).await?;
+            }
             AccountCommand::Transfer => {
-                // show_spinner(self.spinner_msg(), todo!()).await?;
+                let recipient =
+                    prompt_pubkey(ctx, "Enter Recipient Address:", PubkeyCheck::Wallet).await?;
+                let amount: SolAmount = prompt_data("Enter Amount to Transfer (SOL):")?;
+                let memo: OptionalInput<String> =
+                    prompt_data("Enter Memo (leave empty for no memo):")?;
+                let nonce_account: OptionalInput<Pubkey> =
+                    prompt_data("Enter Nonce Account (leave empty to use a recent blockhash):")?;
+                let nonce = match nonce_account.value() {
+                    Some(account) => {
+                        let authority_path: OptionalInput<PathBuf> = prompt_data(
+                            "Enter Nonce Authority Keypair Path (leave empty for your wallet):",
+                        )?;
+                        let authority = authority_path
+                            .value()
+                            .map(read_keypair_from_path)
+                            .transpose()?;
+                        Some(NonceSigner { account, authority })
+                    }
+                    None => None,
+                };
+
+                let memo = memo.value();
+                let plan = show_spinner(
+                    "Checking recipient account…",
+                    plan_transfer(
+                        ctx,
+                        &recipient,
+                        amount.to_lamports(),
+                        memo.as_deref(),
+                        nonce.as_ref(),
+                    ),
+                )
+                .await?;
+                print_transfer_preview(&recipient, amount.to_lamports(), &plan);
+
+                if !prompt_confirm(&format!(
+                    "Send {} SOL to {recipient}?",
+                    lamports_to_sol(amount.to_lamports())
+                ))? {
+                    notice!("{}", style("Transfer cancelled.").yellow());
+                    return Ok(CommandExec::Process(()));
+                }
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_transfer(
+                        ctx,
+                        &recipient,
+                        amount.to_lamports(),
+                        memo,
+                        nonce.as_ref(),
+                        plan,
+                    ),
+                )
+                .await?;
+            }
+            AccountCommand::BatchTransfer => {
+                let csv_path: PathBuf =
+                    prompt_data("Enter CSV file path (columns: pubkey, amount_sol):")?;
+                let content = fs::read_to_string(&csv_path)
+                    .with_context(|| format!("Failed to read {}", csv_path.display()))?;
+                let rows = parse_transfer_csv(&content)?;
+
+                let instructions: Vec<Instruction> = rows
+                    .iter()
+                    .map(|row| transfer(ctx.pubkey(), &row.recipient, row.lamports))
+                    .collect();
+                let batches = pack_instructions(
+                    ctx.pubkey(),
+                    &instructions,
+                    &[],
+                    ctx.priority_fee_micro_lamports(),
+                    ctx.compute_unit_limit(),
+                )?;
+
+                // Recipients loaded from a lookup table take one byte instead
+                // of 32, at the cost of setting the table up and its rent
+                let lookup_table = AddressLookupTableAccount {
+                    key: Pubkey::default(),
+                    addresses: lookup_table_candidates(&instructions),
+                };
+                let table_batches = pack_instructions(
+                    ctx.pubkey(),
+                    &instructions,
+                    std::slice::from_ref(&lookup_table),
+                    ctx.priority_fee_micro_lamports(),
+                    ctx.compute_unit_limit(),
+                )?;
+                // Create and fill the table, then deactivate it afterwards
+                let setup_txs = 2 + lookup_table
+                    .addresses
+                    .len()
+                    .div_ceil(LOOKUP_TABLE_EXTEND_CHUNK_SIZE);
+
+                let mut lookup_addresses = None;
+                let mut batches = batches;
+                if table_batches.len() + setup_txs < batches.len() {
+                    let rent = show_spinner(
+                        "Checking lookup table rent…",
+                        lookup_table_rent(ctx, lookup_table.addresses.len()),
+                    )
+                    .await?;
+                    let use_table = prompt_yes_no(Confirm::new(&format!(
+                        "Load recipients from an address lookup table? {} transactions instead of \
+                         {}, plus {setup_txs} to set the table up and deactivate it. Its {} SOL \
+                         rent can be reclaimed by closing it a few minutes later.",
+                        table_batches.len(),
+                        batches.len(),
+                        lamports_to_sol(rent)
+                    )))?;
+                    if use_table {
+                        lookup_addresses = Some(lookup_table.addresses);
+                        batches = table_batches;
+                    }
+                }
+
+                print_batch_transfer_plan(&rows, batches.len());
+
+                let proceed = prompt_confirm(&format!(
+                    "Send {} transfers in {} transactions?",
+                    rows.len(),
+                    batches.len()
+                ))?;
+
+                if !proceed {
+                    notice!("{}", style("Batch transfer cancelled.").yellow());
+                    return Ok(CommandExec::Process(()));
+                }
+
+                show_spinner_with_status(self.spinner_msg(), |spinner| {
+                    process_batch_transfer(
+                        ctx,
+                        &rows,
+                        &instructions,
+                        &batches,
+                        lookup_addresses,
+                        spinner,
+                    )
+                })
+                .await?;
             }
             AccountCommand::Airdrop => {
-                show_spinner(self.spinner_msg(), request_sol_airdrop(ctx)).await?;
+                let amount: SolAmount = prompt_data("Enter Airdrop Amount (SOL):")?;
+
+                if amount.value() > AIRDROP_FAUCET_LIMIT_SOL {
+                    notice!(
+                        "{}",
+                        style(format!(
+                            "⚠ {} SOL is above the usual faucet limit of \
+                             {AIRDROP_FAUCET_LIMIT_SOL} SOL, the request may be rejected",
+                            amount.value()
+                        ))
+                        .yellow()
+                    );
+                }
+
+                let attempts: OptionalInput<u32> = prompt_data(&format!(
+                    "Enter max attempts when rate limited (default {DEFAULT_AIRDROP_ATTEMPTS}):"
+                ))?;
+                let attempts = attempts.value().unwrap_or(DEFAULT_AIRDROP_ATTEMPTS).max(1);
+
+                show_spinner_with_status(self.spinner_msg(), |spinner| {
+                    request_sol_airdrop(ctx, amount.to_lamports(), attempts, spinner)
+                })
+                .await?;
+            }
+            AccountCommand::ConfirmTransaction => {
+                let signature: Signature = prompt_data("Enter transaction signature:")?;
+                let timeout_secs: OptionalInput<u64> = prompt_data(&format!(
+                    "Enter timeout in seconds (default {DEFAULT_CONFIRMATION_TIMEOUT_SECS}):"
+                ))?;
+                let timeout = Duration::from_secs(
+                    timeout_secs
+                        .value()
+                        .unwrap_or(DEFAULT_CONFIRMATION_TIMEOUT_SECS),
+                );
+
+                show_spinner_with_status(self.spinner_msg(), |spinner| {
+                    poll_transaction_confirmation(ctx, &signature, timeout, spinner)
+                })
+                .await?;
             }
             AccountCommand::LargestAccounts => {
                 show_spinner(self.spinner_msg(), fetch_largest_accounts(ctx)).await?;
             }
             AccountCommand::NonceAccount => {
-                let pubkey: Pubkey = prompt_data("Enter nonce account pubkey:")?;
-                show_spinner(self.spinner_msg(), fetch_nonce_account(ctx, &pubkey)).await?;
+                let nonce_command = prompt_nonce()?;
+                return nonce_command.process_command(ctx).await;
+            }
+            AccountCommand::NewKeypair => {
+                let default_path = ScillaConfig::default().keypair_path;
+                let path: String = prompt_data_with_default(
+                    "Enter path to save the keypair:",
+                    &default_path.to_string_lossy(),
+                )?;
+                let path = expand_tilde(&path);
+
+                if !confirm_keypair_overwrite(&path)? {
+                    notice!("{}", style("Keypair generation cancelled.").yellow());
+                    return Ok(CommandExec::Process(()));
+                }
+
+                let with_seed_phrase = prompt_yes_no(
+                    Confirm::new("Back it with a BIP39 seed phrase?").with_default(true),
+                )?;
+                let seed_phrase = match with_seed_phrase {
+                    true => {
+                        let word_count =
+                            prompt_select(Select::new("Seed phrase length:", vec![12, 24]))?;
+                        Some(generate_seed_phrase(word_count)?)
+                    }
+                    false => None,
+                };
+
+                process_new_keypair(ctx, &path, seed_phrase.as_deref())?;
+
+                let use_it = prompt_yes_no(
+                    Confirm::new("Use this keypair for Scilla from now on?").with_default(true),
+                )?;
+                if use_it {
+                    use_keypair_in_config(&path).await?;
+                }
+            }
+            AccountCommand::SignMessage => {
+                let message: String = prompt_data("Enter message to sign:")?;
+                sign_message(ctx, &message)?;
+            }
+            AccountCommand::VerifyMessage => {
+                let pubkey: Pubkey = prompt_data("Enter signer Pubkey:")?;
+                let message: String = prompt_data("Enter signed message:")?;
+                let signature: Signature = prompt_data("Enter signature (base58):")?;
+                verify_message(ctx, &pubkey, &message, &signature)?;
+            }
+            AccountCommand::Watch => {
+                let pubkey: OptionalInput<Pubkey> =
+                    prompt_data("Enter Pubkey to watch (leave empty for your wallet):")?;
+                let pubkey = pubkey.value().unwrap_or(*ctx.pubkey());
+                watch_account(ctx, &pubkey).await?;
+            }
+            AccountCommand::Receive => {
+                let recipient: OptionalInput<Pubkey> =
+                    prompt_data("Enter Pubkey to receive at (leave empty for your wallet):")?;
+                let amount: OptionalInput<SolAmount> =
+                    prompt_data("Enter Amount to request (SOL, leave empty for any amount):")?;
+                let recipient = recipient.value().unwrap_or(*ctx.pubkey());
+                show_receive_qr(ctx, &recipient, amount.value())?;
             }
             AccountCommand::GoBack => {
                 return Ok(CommandExec::GoBack);
@@ -91,28 +421,639 @@ impl AccountCommand {
     }
 }
 
-async fn request_sol_airdrop(ctx: &ScillaContext) -> anyhow::Result<()> {
-    let sig = ctx.rpc().request_airdrop(ctx.pubkey(), 1).await;
-    match sig {
-        Ok(signature) => {
+/// Requests an airdrop, waiting out rate limits with exponential backoff for
+/// up to `attempts` tries. Any other error is reported straight away.
+async fn request_sol_airdrop(
+    ctx: &ScillaContext,
+    lamports: u64,
+    attempts: u32,
+    spinner: ProgressBar,
+) -> anyhow::Result<()> {
+    if ctx.rpc().url().contains("mainnet") {
+        bail!("Airdrops are not available on mainnet. Switch to a devnet or testnet RPC URL");
+    }
+
+    let mut attempt = 1;
+    let signature = loop {
+        spinner.set_message(format!(
+            "Requesting airdrop (attempt {attempt}/{attempts})…"
+        ));
+
+        let err = match ctx.rpc().request_airdrop(ctx.pubkey(), lamports).await {
+            Ok(signature) => break signature,
+            Err(err) => err,
+        };
+        if !is_rate_limit_error(&err.to_string()) {
+            bail!("Airdrop failed: {err}");
+        }
+        if attempt == attempts {
+            bail!(
+                "Airdrop still rate limited after {attempts} attempts. Try again later or use the \
+                 web faucet at {SOLANA_FAUCET_URL}"
+            );
+        }
+
+        let backoff = AIRDROP_RETRY_BASE_DELAY_SECS << (attempt - 1);
+        spinner.set_message(format!(
+            "Rate limited, retrying in {backoff}s (attempt {attempt}/{attempts})…"
+        ));
+        tokio::time::sleep(Duration::from_secs(backoff)).await;
+        attempt += 1;
+    };
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "lamports": lamports,
+            "sol": lamports_to_sol(lamports),
+            "attempts": attempt,
+        }),
+        || {
             println!(
                 "{} {}",
                 style("Airdrop requested successfully!").green().bold(),
                 style(format!("Signature: {signature}")).cyan()
+            )
+        },
+    )
+}
+
+/// Writes a new keypair in the `id.json` byte-array format. With a seed phrase
+/// it is derived on the default path, so the phrase restores it in any wallet.
+fn process_new_keypair(
+    ctx: &ScillaContext,
+    path: &Path,
+    seed_phrase: Option<&str>,
+) -> anyhow::Result<Pubkey> {
+    let keypair = match seed_phrase {
+        Some(seed_phrase) => keypair_from_seed_phrase(seed_phrase, "", DEFAULT_DERIVATION_PATH)?,
+        None => Keypair::new(),
+    };
+    let pubkey = keypair.pubkey();
+
+    save_keypair(&keypair, path)?;
+
+    print_output(
+        ctx,
+        &json!({
+            "pubkey": pubkey.to_string(),
+            "path": path.display().to_string(),
+            "seed_phrase": seed_phrase,
+            "derivation_path": seed_phrase.map(|_| DEFAULT_DERIVATION_PATH),
+        }),
+        || {
+            println!(
+                "\n{}\n{}\n{}",
+                style("Keypair Generated!").green().bold(),
+                style(format!("Pubkey: {pubkey}")).yellow(),
+                style(format!("Saved to: {}", path.display())).cyan()
             );
+
+            if let Some(seed_phrase) = seed_phrase {
+                println!(
+                    "\n{}\n{}\n{}",
+                    style(
+                        "⚠ Write down this seed phrase and keep it offline. It is the only way to \
+                         recover the keypair and won't be shown again."
+                    )
+                    .yellow()
+                    .bold(),
+                    style(seed_phrase).bold(),
+                    style(format!("Derivation path: {DEFAULT_DERIVATION_PATH}")).dim()
+                );
+            }
+        },
+    )?;
+
+    Ok(pubkey)
+}
+
+/// Points the stored config at `path` so the next command signs with it
+async fn use_keypair_in_config(path: &Path) -> anyhow::Result<()> {
+    let mut config = ScillaConfig::load_stored().await?;
+    config.keypair_path = path.to_path_buf();
+    config.seed_phrase = None;
+    config.save()?;
+
+    notice!(
+        "{}",
+        style(format!(
+            "✓ Profile '{}' now uses {}",
+            config.active_profile_name(),
+            path.display()
+        ))
+        .green()
+    );
+    Ok(())
+}
+
+/// The recipient's balance, `None` when the account doesn't exist yet
+async fn fetch_recipient_balance(
+    ctx: &ScillaContext,
+    recipient: &Pubkey,
+) -> anyhow::Result<Option<u64>> {
+    let response = ctx
+        .rpc()
+        .get_account_with_commitment(recipient, ctx.rpc().commitment())
+        .await?;
+
+    Ok(response.value.map(|account| account.lamports))
+}
+
+/// Shows where the SOL is going and what it costs before it's sent. A balance
+/// that looks off is often the first sign of a mistyped address.
+fn print_transfer_preview(recipient: &Pubkey, lamports: u64, plan: &TransferPlan) {
+    let recipient_balance = plan.recipient_balance;
+    let mut table = new_table();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![
+            Cell::new("Recipient"),
+            Cell::new(recipient.to_string()),
+        ])
+        .add_row(vec![
+            Cell::new("Account Exists"),
+            Cell::new(if recipient_balance.is_some() {
+                "Yes"
+            } else {
+                "No"
+            }),
+        ])
+        .add_row(vec![
+            Cell::new("Current Balance (SOL)"),
+            Cell::new(lamports_to_sol(recipient_balance.unwrap_or_default())),
+        ])
+        .add_row(vec![
+            Cell::new("Amount (SOL)"),
+            Cell::new(lamports_to_sol(lamports)),
+        ])
+        .add_row(vec![
+            Cell::new("Estimated Fee (SOL)"),
+            Cell::new(lamports_to_sol(plan.fee)),
+        ]);
+
+    notice!("\n{}", style("TRANSFER PREVIEW").green().bold());
+    notice!("{}", table);
+
+    if recipient_balance.is_none() {
+        notice!(
+            "{}",
+            style(format!(
+                "⚠ Recipient {recipient} does not exist yet. This transfer will fund a new \
+                 account."
+            ))
+            .yellow()
+            .bold()
+        );
+    }
+}
+
+/// A nonce account to sign a transfer against, and its authority when that
+/// is not the wallet
+struct NonceSigner {
+    account: Pubkey,
+    authority: Option<Keypair>,
+}
+
+/// A transfer that passed the balance checks, with what the preview shows
+struct TransferPlan {
+    instructions: Vec<Instruction>,
+    durable_nonce: Option<(DurableNonce, Hash)>,
+    fee: u64,
+    /// `None` when the recipient account doesn't exist yet
+    recipient_balance: Option<u64>,
+}
+
+async fn plan_transfer(
+    ctx: &ScillaContext,
+    recipient: &Pubkey,
+    lamports: u64,
+    memo: Option<&str>,
+    nonce: Option<&NonceSigner>,
+) -> anyhow::Result<TransferPlan> {
+    if recipient == ctx.pubkey() {
+        bail!("Recipient cannot be your own wallet");
+    }
+
+    // The stored nonce value stands in for the blockhash
+    let durable_nonce = match nonce {
+        Some(nonce) => {
+            let (_, data) = fetch_nonce_data(ctx, &nonce.account).await?;
+            let authority = nonce
+                .authority
+                .as_ref()
+                .map_or(*ctx.pubkey(), |authority| authority.pubkey());
+            if data.authority != authority {
+                bail!(
+                    "{authority} is not the authority of nonce account {}. Nonce authority: {}",
+                    nonce.account,
+                    data.authority
+                );
+            }
+            Some((
+                DurableNonce {
+                    account: nonce.account,
+                    authority,
+                },
+                data.blockhash(),
+            ))
         }
-        Err(err) => {
-            print_error(format!("Airdrop failed: {err}"));
+        None => None,
+    };
+    let advance_ix =
+        durable_nonce.map(|(nonce, _)| advance_nonce_account(&nonce.account, &nonce.authority));
+
+    let transfer_ix = transfer(ctx.pubkey(), recipient, lamports);
+    let mut instructions = Vec::with_capacity(2);
+    // The memo goes first, as exchanges expect to find it there
+    if let Some(memo) = memo {
+        let others: Vec<_> = advance_ix
+            .iter()
+            .cloned()
+            .chain([transfer_ix.clone()])
+            .collect();
+        instructions.push(memo_instruction(
+            memo,
+            ctx.pubkey(),
+            &others,
+            ctx.priority_fee_micro_lamports(),
+            ctx.compute_unit_limit(),
+        )?);
+    }
+    instructions.push(transfer_ix);
+
+    // Advancing the nonce is part of what gets paid for
+    let priced: Vec<_> = advance_ix
+        .into_iter()
+        .chain(instructions.iter().cloned())
+        .collect();
+    ensure_sufficient_balance(ctx, &priced, &[("transfer", lamports)]).await?;
+
+    let fee = estimate_fee(
+        ctx,
+        &with_compute_budget(
+            &priced,
+            ctx.priority_fee_micro_lamports(),
+            ctx.compute_unit_limit(),
+        ),
+    )
+    .await?;
+    let recipient_balance = fetch_recipient_balance(ctx, recipient).await?;
+
+    Ok(TransferPlan {
+        instructions,
+        durable_nonce,
+        fee,
+        recipient_balance,
+    })
+}
+
+async fn process_transfer(
+    ctx: &ScillaContext,
+    recipient: &Pubkey,
+    lamports: u64,
+    memo: Option<String>,
+    nonce: Option<&NonceSigner>,
+    plan: TransferPlan,
+) -> anyhow::Result<()> {
+    let TransferPlan {
+        instructions,
+        durable_nonce,
+        ..
+    } = plan;
+    let signature = match durable_nonce {
+        Some((durable_nonce, nonce_blockhash)) => {
+            let mut signers = vec![ctx.signer()];
+            if let Some(authority) = nonce.and_then(|nonce| nonce.authority.as_ref()) {
+                signers.push(authority);
+            }
+            build_and_send_nonce_tx(ctx, &instructions, &signers, durable_nonce, nonce_blockhash)
+                .await?
         }
+        None => build_and_send_tx(ctx, &instructions, &[ctx.signer()]).await?,
+    };
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "recipient": recipient.to_string(),
+            "lamports": lamports,
+            "sol": lamports_to_sol(lamports),
+            "memo": memo,
+            "nonce_account": nonce.map(|nonce| nonce.account.to_string()),
+        }),
+        || {
+            println!(
+                "\n{}\n{}\n{}",
+                style("Transfer Successful!").green().bold(),
+                style(format!("To: {recipient}")).yellow(),
+                style(format!("Amount: {} SOL", lamports_to_sol(lamports))).cyan(),
+            );
+            if let Some(memo) = &memo {
+                println!("{}", style(format!("Memo: {memo}")).cyan());
+            }
+            if let Some(nonce) = nonce {
+                println!(
+                    "{}",
+                    style(format!("Nonce Account: {}", nonce.account)).cyan()
+                );
+            }
+            println!("{}", style(format!("Signature: {signature}")).cyan());
+        },
+    )?;
+
+    Ok(())
+}
+
+fn print_batch_transfer_plan(rows: &[TransferRow], batch_count: usize) {
+    let total: u64 = rows.iter().map(|row| row.lamports).sum();
+
+    let mut table = new_table();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Line").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Recipient").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Amount (SOL)").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for row in rows {
+        table.add_row(vec![
+            Cell::new(row.line),
+            Cell::new(row.recipient.to_string()),
+            Cell::new(lamports_to_sol(row.lamports)),
+        ]);
+    }
+
+    notice!("\n{}", style("PLANNED TRANSFERS").green().bold());
+    notice!("{}", table);
+    notice!(
+        "{}",
+        style(format!(
+            "{} transfers, {} SOL total, sent in {batch_count} transactions",
+            rows.len(),
+            lamports_to_sol(total)
+        ))
+        .cyan()
+    );
+}
+
+/// Sends each batch in order and keeps going after a failed batch, so the
+/// report shows exactly which rows landed
+async fn process_batch_transfer(
+    ctx: &ScillaContext,
+    rows: &[TransferRow],
+    instructions: &[Instruction],
+    batches: &[Range<usize>],
+    lookup_addresses: Option<Vec<Pubkey>>,
+    spinner: ProgressBar,
+) -> anyhow::Result<()> {
+    let total: u64 = rows.iter().map(|row| row.lamports).sum();
+    let mut fees = 0u64;
+    for batch in batches {
+        let instructions = with_compute_budget(
+            &instructions[batch.clone()],
+            ctx.priority_fee_micro_lamports(),
+            ctx.compute_unit_limit(),
+        );
+        fees = fees.saturating_add(estimate_fee(ctx, &instructions).await?);
+    }
+    let (rent, setup_fees) = match &lookup_addresses {
+        Some(addresses) => tokio::try_join!(
+            lookup_table_rent(ctx, addresses.len()),
+            lookup_table_setup_fee(ctx, addresses),
+        )?,
+        None => (0, 0),
+    };
+    let fees = fees.saturating_add(setup_fees);
+    let required = total.saturating_add(fees).saturating_add(rent);
+    let balance = ctx.rpc().get_balance(ctx.pubkey()).await?;
+
+    if required > balance {
+        bail!(
+            "Insufficient balance. Have {} SOL, need about {} SOL ({} SOL + {} SOL fees + {} SOL \
+             lookup table rent)",
+            lamports_to_sol(balance),
+            lamports_to_sol(required),
+            lamports_to_sol(total),
+            lamports_to_sol(fees),
+            lamports_to_sol(rent)
+        );
+    }
+
+    let lookup_table = match lookup_addresses {
+        Some(addresses) => {
+            spinner.set_message("Creating address lookup table…");
+            Some(create_lookup_table(ctx, &addresses).await?)
+        }
+        None => None,
+    };
+
+    let mut results = Vec::with_capacity(batches.len());
+    for (idx, batch) in batches.iter().enumerate() {
+        spinner.set_message(format!("Sending batch {}/{}…", idx + 1, batches.len()));
+        let instructions = &instructions[batch.clone()];
+        let result = match &lookup_table {
+            Some(table) => {
+                build_and_send_versioned_tx(
+                    ctx,
+                    instructions,
+                    &[ctx.signer()],
+                    std::slice::from_ref(table),
+                )
+                .await
+            }
+            None => build_and_send_tx(ctx, instructions, &[ctx.signer()]).await,
+        };
+        results.push(result);
+    }
+    let lookup_table = lookup_table.map(|table| table.key);
+
+    // Start the table's cooldown right away, so its rent can be reclaimed
+    // a few minutes from now
+    let deactivation = match &lookup_table {
+        Some(table) => {
+            spinner.set_message("Deactivating address lookup table…");
+            Some(deactivate_lookup_table(ctx, table).await)
+        }
+        None => None,
+    };
+
+    let failed = results.iter().filter(|result| result.is_err()).count();
+
+    let batch_values: Vec<_> = batches
+        .iter()
+        .zip(&results)
+        .enumerate()
+        .map(|(idx, (batch, result))| {
+            let rows = &rows[batch.clone()];
+            json!({
+                "batch": idx + 1,
+                "lines": rows.iter().map(|row| row.line).collect::<Vec<_>>(),
+                "lamports": rows.iter().map(|row| row.lamports).sum::<u64>(),
+                "signature": result.as_ref().ok().map(|signature| signature.to_string()),
+                "error": result.as_ref().err().map(|err| err.to_string()),
+            })
+        })
+        .collect();
+
+    let value = json!({
+        "landed": batches.len() - failed,
+        "failed": failed,
+        "lookup_table": lookup_table.map(|table| table.to_string()),
+        "lookup_table_deactivation": deactivation
+            .as_ref()
+            .and_then(|result| result.as_ref().ok())
+            .map(|signature| signature.to_string()),
+        "batches": batch_values,
+    });
+
+    print_output(ctx, &value, || {
+        let mut table = new_table();
+        table.load_preset(UTF8_FULL).set_header(vec![
+            Cell::new("Batch").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Lines").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Amount (SOL)").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Result").add_attribute(comfy_table::Attribute::Bold),
+        ]);
+
+        for (idx, (batch, result)) in batches.iter().zip(&results).enumerate() {
+            let rows = &rows[batch.clone()];
+            let lines = match (rows.first(), rows.last()) {
+                (Some(first), Some(last)) if first.line != last.line => {
+                    format!("{}-{}", first.line, last.line)
+                }
+                (Some(first), _) => first.line.to_string(),
+                _ => String::new(),
+            };
+            let amount: u64 = rows.iter().map(|row| row.lamports).sum();
+            let outcome = match result {
+                Ok(signature) => style(signature.to_string()).green().to_string(),
+                Err(err) => style(format!("Failed: {err}")).red().to_string(),
+            };
+
+            table.add_row(vec![
+                Cell::new(idx + 1),
+                Cell::new(lines),
+                Cell::new(lamports_to_sol(amount)),
+                Cell::new(outcome),
+            ]);
+        }
+
+        println!("\n{}", style("BATCH TRANSFER RESULTS").green().bold());
+        println!("{}", table);
+        if let (Some(table), Some(deactivation)) = (lookup_table, &deactivation) {
+            let note = match deactivation {
+                Ok(_) => format!(
+                    "Lookup table {table} is deactivated and holds {} SOL of rent. Get it back \
+                     with `solana address-lookup-table close {table}` in about {}.",
+                    lamports_to_sol(rent),
+                    format_duration(slots_to_duration(
+                        LOOKUP_TABLE_COOLDOWN_SLOTS,
+                        DEFAULT_MS_PER_SLOT as f64
+                    ))
+                ),
+                Err(err) => format!(
+                    "Lookup table {table} holds {} SOL of rent but could not be deactivated \
+                     ({err}). Get it back with `solana address-lookup-table deactivate {table}`, \
+                     then `close` once it has cooled down.",
+                    lamports_to_sol(rent)
+                ),
+            };
+            println!("{}", style(note).dim());
+        }
+    })?;
+
+    if failed > 0 {
+        bail!(
+            "{failed} of {} batches failed, the rows in those batches were not sent",
+            batches.len()
+        );
     }
 
     Ok(())
 }
 
-async fn fetch_acc_data(ctx: &ScillaContext, pubkey: &Pubkey) -> anyhow::Result<()> {
+async fn poll_transaction_confirmation(
+    ctx: &ScillaContext,
+    signature: &Signature,
+    timeout: Duration,
+    spinner: ProgressBar,
+) -> anyhow::Result<()> {
+    let commitment = ctx.rpc().commitment();
+    let started = Instant::now();
+
+    // A notification only saves polling time; the final status still comes
+    // from the loop below, which is also the fallback when it doesn't arrive
+    if ctx.websocket_confirmation() {
+        spinner.set_message("Waiting for a WebSocket notification…");
+        wait_for_signature_notification(ctx, signature, timeout).await;
+    }
+
+    loop {
+        let statuses = ctx.rpc().get_signature_statuses(&[*signature]).await?;
+
+        if let Some(Some(status)) = statuses.value.first() {
+            if let Some(err) = &status.err {
+                print_error(format!("Transaction failed at slot {}: {err}", status.slot));
+                return Ok(());
+            }
+
+            if status.satisfies_commitment(commitment) {
+                let value = json!({
+                    "signature": signature.to_string(),
+                    "slot": status.slot,
+                    "confirmation_status": status.confirmation_status(),
+                });
+                return print_output(ctx, &value, || {
+                    println!(
+                        "\n{}\n{}\n{}",
+                        style("Transaction Confirmed!").green().bold(),
+                        style(format!("Slot: {}", status.slot)).yellow(),
+                        style(format!("Status: {:?}", status.confirmation_status())).cyan()
+                    )
+                });
+            }
+
+            spinner.set_message(format!(
+                "Status: {:?} at slot {}, waiting for {}…",
+                status.confirmation_status(),
+                status.slot,
+                commitment.commitment
+            ));
+        } else {
+            spinner.set_message("Transaction not found yet, waiting…");
+        }
+
+        if started.elapsed() >= timeout {
+            bail!(
+                "Timed out after {}s waiting for {signature} to reach {}",
+                timeout.as_secs(),
+                commitment.commitment
+            );
+        }
+
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+}
+
+async fn fetch_acc_data(
+    ctx: &ScillaContext,
+    pubkey: &Pubkey,
+    dump_path: Option<&Path>,
+) -> anyhow::Result<()> {
     let acc = ctx.rpc().get_account(pubkey).await?;
 
-    let mut table = Table::new();
+    if let Some(dump_path) = dump_path {
+        fs::write(dump_path, &acc.data)
+            .with_context(|| format!("Failed to write {}", dump_path.display()))?;
+    }
+
+    let mut table = new_table();
     table
         .load_preset(UTF8_FULL)
         .set_header(vec![
@@ -140,30 +1081,244 @@ async fn fetch_acc_data(ctx: &ScillaContext, pubkey: &Pubkey) -> anyhow::Result<
             Cell::new(format!("{}", acc.rent_epoch)),
         ]);
 
-    println!("{}\n{}", style("ACCOUNT INFO").green().bold(), table);
+    let decoded = decode_account(pubkey, &acc);
 
-    Ok(())
+    let value = json!({
+        "pubkey": pubkey.to_string(),
+        "lamports": acc.lamports,
+        "data_length": acc.data.len(),
+        "owner": acc.owner.to_string(),
+        "executable": acc.executable,
+        "rent_epoch": acc.rent_epoch,
+        "decoded": decoded.as_ref().map(|(kind, fields)| {
+            json!({
+                "type": kind,
+                "fields": fields
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), json!(value)))
+                    .collect::<serde_json::Map<_, _>>(),
+            })
+        }),
+        "data_base64": base64::engine::general_purpose::STANDARD.encode(&acc.data),
+    });
+
+    print_output(ctx, &value, || {
+        println!("{}\n{}", style("ACCOUNT INFO").green().bold(), table);
+
+        if let Some((kind, fields)) = &decoded {
+            let mut decoded_table = new_table();
+            decoded_table.load_preset(UTF8_FULL).set_header(vec![
+                Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+                Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+            ]);
+            for (field, value) in fields {
+                decoded_table.add_row(vec![Cell::new(field), Cell::new(value)]);
+            }
+            println!(
+                "\n{}\n{}",
+                style(kind.to_uppercase()).green().bold(),
+                decoded_table
+            );
+        }
+
+        if acc.data.is_empty() {
+            return;
+        }
+
+        let preview = &acc.data[..acc.data.len().min(HEXDUMP_PREVIEW_BYTES)];
+        println!("\n{}", style("DATA").green().bold());
+        println!("{}", hexdump(preview));
+        if preview.len() < acc.data.len() {
+            println!(
+                "{}",
+                style(format!(
+                    "… {} more bytes. Save the data to a file to see all of it.",
+                    acc.data.len() - preview.len()
+                ))
+                .dim()
+            );
+        }
+        if let Some(dump_path) = dump_path {
+            println!(
+                "{}",
+                style(format!("Raw data written to {}", dump_path.display())).cyan()
+            );
+        }
+    })
+}
+
+/// A structured view of accounts owned by the system, stake or vote program,
+/// as their kind and named fields
+fn decode_account(
+    pubkey: &Pubkey,
+    account: &Account,
+) -> Option<(&'static str, Vec<(&'static str, String)>)> {
+    if account.owner == solana_sdk_ids::system_program::id() {
+        if account.data.is_empty() {
+            return Some((
+                "Wallet",
+                vec![(
+                    "Balance (SOL)",
+                    lamports_to_sol(account.lamports).to_string(),
+                )],
+            ));
+        }
+
+        let versions = bincode_deserialize::<Versions>(&account.data, "nonce account data").ok()?;
+        let NonceState::Initialized(data) = versions.state() else {
+            return Some((
+                "Nonce account",
+                vec![("State", "Uninitialized".to_string())],
+            ));
+        };
+        return Some((
+            "Nonce account",
+            vec![
+                ("Authority", data.authority.to_string()),
+                ("Nonce Blockhash", data.blockhash().to_string()),
+                (
+                    "Lamports per Signature",
+                    data.fee_calculator.lamports_per_signature.to_string(),
+                ),
+            ],
+        ));
+    }
+
+    if account.owner == stake_program_id() {
+        let state: StakeStateV2 = bincode_deserialize(&account.data, "stake account data").ok()?;
+        let mut fields = vec![("State", stake_state_summary(account)?)];
+        if let StakeStateV2::Initialized(meta) | StakeStateV2::Stake(meta, _, _) = &state {
+            fields.push(("Staker", meta.authorized.staker.to_string()));
+            fields.push(("Withdrawer", meta.authorized.withdrawer.to_string()));
+            fields.push((
+                "Rent Exempt Reserve (SOL)",
+                lamports_to_sol(meta.rent_exempt_reserve).to_string(),
+            ));
+        }
+        if let StakeStateV2::Stake(_, stake, _) = &state {
+            let delegation = stake.delegation;
+            fields.push(("Voter", delegation.voter_pubkey.to_string()));
+            fields.push(("Activation Epoch", delegation.activation_epoch.to_string()));
+            if delegation.deactivation_epoch != ACTIVE_STAKE_EPOCH_BOUND {
+                fields.push((
+                    "Deactivation Epoch",
+                    delegation.deactivation_epoch.to_string(),
+                ));
+            }
+            fields.push(("Credits Observed", stake.credits_observed.to_string()));
+        }
+        return Some(("Stake account", fields));
+    }
+
+    if account.owner == solana_vote_program::id() {
+        let vote_state = VoteStateV4::deserialize(&account.data, pubkey).ok()?;
+        return Some((
+            "Vote account",
+            vec![
+                ("Validator Identity", vote_state.node_pubkey.to_string()),
+                (
+                    "Withdraw Authority",
+                    vote_state.authorized_withdrawer.to_string(),
+                ),
+                (
+                    "Commission",
+                    format!("{}%", vote_state.inflation_rewards_commission_bps / 100),
+                ),
+                ("Credits", vote_state.credits().to_string()),
+                (
+                    "Root Slot",
+                    vote_state
+                        .root_slot
+                        .map_or("~".to_string(), |slot| slot.to_string()),
+                ),
+            ],
+        ));
+    }
+
+    None
 }
 
 async fn fetch_account_balance(ctx: &ScillaContext, pubkey: &Pubkey) -> anyhow::Result<()> {
-    let acc = ctx.rpc().get_account(pubkey).await?;
-    let acc_balance = lamports_to_sol(acc.lamports);
+    // get_balance reports 0 for accounts that don't exist yet instead of erroring
+    let lamports = ctx.rpc().get_balance(pubkey).await?;
+    let acc_balance = lamports_to_sol(lamports);
 
-    println!(
-        "{}\n{}",
-        style("Account balance in SOL:").green().bold(),
-        style(format!("{acc_balance:#?}")).cyan()
-    );
+    let commitment = ctx.rpc().commitment().commitment;
+    let value = json!({
+        "pubkey": pubkey.to_string(),
+        "lamports": lamports,
+        "sol": acc_balance,
+        "commitment": commitment.to_string(),
+    });
 
-    Ok(())
+    print_output(ctx, &value, || {
+        println!(
+            "{} {}\n{}\n{}",
+            style("Account balance for").green().bold(),
+            style(pubkey).green().bold(),
+            style(format!("{acc_balance} SOL ({lamports} lamports)")).cyan(),
+            style(format!("Commitment: {commitment}")).dim()
+        )
+    })
+}
+
+async fn fetch_multiple_balances(ctx: &ScillaContext, pubkeys: &[Pubkey]) -> anyhow::Result<()> {
+    let accounts = fetch_accounts(ctx, pubkeys).await?;
+    // An address that was never funded is listed with a zero balance
+    let balances: Vec<_> = pubkeys
+        .iter()
+        .zip(&accounts)
+        .map(|(pubkey, account)| (pubkey, account.as_ref().map(|account| account.lamports)))
+        .collect();
+    let total: u64 = balances
+        .iter()
+        .map(|(_, lamports)| lamports.unwrap_or(0))
+        .sum();
+
+    let value = json!({
+        "accounts": balances
+            .iter()
+            .map(|(pubkey, lamports)| {
+                json!({
+                    "pubkey": pubkey.to_string(),
+                    "lamports": lamports.unwrap_or(0),
+                    "sol": lamports_to_sol(lamports.unwrap_or(0)),
+                    "exists": lamports.is_some(),
+                })
+            })
+            .collect::<Vec<_>>(),
+        "total_lamports": total,
+        "total_sol": lamports_to_sol(total),
+    });
+
+    let mut table = new_table();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Address").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Balance (SOL)").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+    for (pubkey, lamports) in &balances {
+        let balance = match lamports {
+            Some(lamports) => Cell::new(format_sol_exact(*lamports)),
+            None => Cell::new("0 (not found)").fg(comfy_table::Color::DarkGrey),
+        };
+        table.add_row(vec![Cell::new(pubkey), balance]);
+    }
+    table.add_row(vec![
+        Cell::new("Total").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new(format_sol_exact(total)).add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    print_output(ctx, &value, || {
+        println!("\n{}", style("BALANCES").green().bold());
+        println!("{}", table);
+    })
 }
 
 async fn fetch_largest_accounts(ctx: &ScillaContext) -> anyhow::Result<()> {
-    let filter_choice = Select::new(
+    let filter_choice = prompt_select(Select::new(
         "Filter accounts by:",
         vec!["All", "Circulating", "Non-Circulating"],
-    )
-    .prompt()?;
+    ))?;
 
     let filter = match filter_choice {
         "Circulating" => Some(RpcLargestAccountsFilter::Circulating),
@@ -180,7 +1335,7 @@ async fn fetch_largest_accounts(ctx: &ScillaContext) -> anyhow::Result<()> {
     let response = ctx.rpc().get_largest_accounts_with_config(config).await?;
     let largest_accounts = response.value;
 
-    let mut table = Table::new();
+    let mut table = new_table();
     table.load_preset(UTF8_FULL).set_header(vec![
         Cell::new("#").add_attribute(comfy_table::Attribute::Bold),
         Cell::new("Address").add_attribute(comfy_table::Attribute::Bold),
@@ -191,66 +1346,232 @@ async fn fetch_largest_accounts(ctx: &ScillaContext) -> anyhow::Result<()> {
         let balance_sol = lamports_to_sol(account.lamports);
         table.add_row(vec![
             Cell::new(format!("{}", idx + 1)),
-            Cell::new(account.address.clone()),
+            Cell::new(truncate_middle(&account.address, 8)),
             Cell::new(format!("{balance_sol:.2}")),
         ]);
     }
 
-    println!("\n{}", style("LARGEST ACCOUNTS").green().bold());
-    println!("{table}");
+    let value: Vec<_> = largest_accounts
+        .iter()
+        .map(|account| {
+            json!({
+                "address": account.address,
+                "lamports": account.lamports,
+                "sol": lamports_to_sol(account.lamports),
+            })
+        })
+        .collect();
 
-    Ok(())
+    print_output(ctx, &value, || {
+        println!("\n{}", style("LARGEST ACCOUNTS").green().bold());
+        println!("{table}");
+        println!(
+            "{}",
+            style(format!("{} accounts returned", largest_accounts.len())).dim()
+        );
+    })
+}
+
+fn show_receive_qr(
+    ctx: &ScillaContext,
+    recipient: &Pubkey,
+    amount: Option<SolAmount>,
+) -> anyhow::Result<()> {
+    let lamports = amount.map(|amount| amount.to_lamports());
+    let uri = solana_pay_uri(recipient, lamports);
+    let qr = render_qr(&uri)?;
+
+    let value = json!({
+        "address": recipient.to_string(),
+        "lamports": lamports,
+        "uri": uri,
+    });
+
+    print_output(ctx, &value, || {
+        println!("\n{}", style("RECEIVE SOL").green().bold());
+        println!("{qr}");
+        println!("{} {}", style("Address:").cyan(), recipient);
+        if let Some(lamports) = lamports {
+            println!(
+                "{} {} SOL",
+                style("Amount:").cyan(),
+                format_sol_exact(lamports)
+            );
+        }
+        println!("{}", style(&uri).dim());
+    })
+}
+
+fn sign_message(ctx: &ScillaContext, message: &str) -> anyhow::Result<()> {
+    let signature = ctx.signer().try_sign_message(message.as_bytes())?;
+
+    let value = json!({
+        "pubkey": ctx.pubkey().to_string(),
+        "message": message,
+        "signature": signature.to_string(),
+    });
+
+    print_output(ctx, &value, || {
+        println!("\n{}", style("SIGNED MESSAGE").green().bold());
+        let mut table = new_table();
+        table
+            .load_preset(UTF8_FULL)
+            .set_header(vec![
+                Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+                Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+            ])
+            .add_row(vec![Cell::new("Signer"), Cell::new(ctx.pubkey())])
+            .add_row(vec![Cell::new("Message"), Cell::new(message)])
+            .add_row(vec![Cell::new("Signature"), Cell::new(signature)]);
+        println!("{table}");
+    })
 }
 
-async fn fetch_nonce_account(ctx: &ScillaContext, pubkey: &Pubkey) -> anyhow::Result<()> {
-    let account = ctx.rpc().get_account(pubkey).await?;
+fn verify_message(
+    ctx: &ScillaContext,
+    pubkey: &Pubkey,
+    message: &str,
+    signature: &Signature,
+) -> anyhow::Result<()> {
+    let valid = is_valid_message_signature(pubkey, message, signature);
 
-    let versions = bincode_deserialize::<Versions>(&account.data, "nonce account data")?;
+    let value = json!({
+        "pubkey": pubkey.to_string(),
+        "message": message,
+        "signature": signature.to_string(),
+        "valid": valid,
+    });
 
-    let solana_nonce::state::State::Initialized(data) = versions.state() else {
-        bail!("This account is not an initialized nonce account");
+    print_output(ctx, &value, || {
+        if valid {
+            println!(
+                "\n{}",
+                style(format!("✓ Valid signature by {pubkey}"))
+                    .green()
+                    .bold()
+            );
+        } else {
+            println!(
+                "\n{}",
+                style(format!(
+                    "✗ Not a valid signature by {pubkey} for this message"
+                ))
+                .red()
+                .bold()
+            );
+        }
+    })
+}
+
+/// Prints every change to the account until Ctrl-C, resubscribing whenever the
+/// WebSocket connection drops
+async fn watch_account(ctx: &ScillaContext, pubkey: &Pubkey) -> anyhow::Result<()> {
+    let ws_url = ctx.ws_url()?;
+    notice!(
+        "{}",
+        style(format!(
+            "Watching {pubkey} via {ws_url} at {:?} commitment. Press Ctrl-C to stop.",
+            ctx.rpc().commitment().commitment
+        ))
+        .dim()
+    );
+
+    let mut last_lamports = None;
+    let watch = async {
+        loop {
+            let reason =
+                match stream_account_updates(ctx, &ws_url, pubkey, &mut last_lamports).await {
+                    Ok(()) => "subscription closed".to_string(),
+                    Err(err) => err.to_string(),
+                };
+            notice!(
+                "{}",
+                style(format!(
+                    "Connection lost ({reason}), reconnecting in {WS_RECONNECT_DELAY_SECS}s…"
+                ))
+                .yellow()
+            );
+            tokio::time::sleep(Duration::from_secs(WS_RECONNECT_DELAY_SECS)).await;
+        }
     };
-    let data = data.clone();
 
-    let mut table = Table::new();
-    table
-        .load_preset(UTF8_FULL)
-        .set_header(vec![
-            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
-            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
-        ])
-        .add_row(vec![Cell::new("Address"), Cell::new(pubkey.to_string())])
-        .add_row(vec![
-            Cell::new("Lamports"),
-            Cell::new(format!("{}", account.lamports)),
-        ])
-        .add_row(vec![
-            Cell::new("Balance (SOL)"),
-            Cell::new(format!("{:.6}", lamports_to_sol(account.lamports))),
-        ])
-        .add_row(vec![
-            Cell::new("Owner"),
-            Cell::new(account.owner.to_string()),
-        ])
-        .add_row(vec![
-            Cell::new("Executable"),
-            Cell::new(format!("{}", account.executable)),
-        ])
-        .add_row(vec![
-            Cell::new("Rent Epoch"),
-            Cell::new(format!("{}", account.rent_epoch)),
-        ])
-        .add_row(vec![
-            Cell::new("Nonce blockhash"),
-            Cell::new(data.blockhash().to_string()),
-        ])
-        .add_row(vec![
-            Cell::new("Authority"),
-            Cell::new(data.authority.to_string()),
-        ]);
+    tokio::select! {
+        _ = watch => {}
+        result = tokio::signal::ctrl_c() => result?,
+    }
+
+    notice!("{}", style("Stopped watching.").dim());
+    Ok(())
+}
+
+async fn stream_account_updates(
+    ctx: &ScillaContext,
+    ws_url: &str,
+    pubkey: &Pubkey,
+    last_lamports: &mut Option<u64>,
+) -> anyhow::Result<()> {
+    let client = PubsubClient::new(ws_url).await?;
+    let config = RpcAccountInfoConfig {
+        encoding: Some(UiAccountEncoding::Base64),
+        commitment: Some(ctx.rpc().commitment()),
+        ..Default::default()
+    };
+    let (mut updates, unsubscribe) = client.account_subscribe(pubkey, Some(config)).await?;
 
-    println!("\n{}", style("NONCE ACCOUNT INFO").green().bold());
-    println!("{table}");
+    while let Some(update) = updates.next().await {
+        print_account_update(ctx, update.context.slot, &update.value, *last_lamports)?;
+        *last_lamports = Some(update.value.lamports);
+    }
 
+    unsubscribe().await;
     Ok(())
 }
+
+fn print_account_update(
+    ctx: &ScillaContext,
+    slot: u64,
+    account: &UiAccount,
+    previous_lamports: Option<u64>,
+) -> anyhow::Result<()> {
+    let change = previous_lamports.map(|previous| account.lamports as i128 - previous as i128);
+    let stake_summary = account
+        .decode::<Account>()
+        .as_ref()
+        .and_then(stake_state_summary);
+
+    let value = json!({
+        "slot": slot,
+        "lamports": account.lamports,
+        "sol": lamports_to_sol(account.lamports),
+        "change_lamports": change,
+        "stake": stake_summary,
+    });
+
+    print_output(ctx, &value, || {
+        let change = match change {
+            Some(0) | None => String::new(),
+            Some(change) if change > 0 => style(format!(
+                " (+{} SOL)",
+                lamports_to_sol(change.unsigned_abs() as u64)
+            ))
+            .green()
+            .to_string(),
+            Some(change) => style(format!(
+                " (-{} SOL)",
+                lamports_to_sol(change.unsigned_abs() as u64)
+            ))
+            .red()
+            .to_string(),
+        };
+
+        println!(
+            "{} slot {} · {} SOL{change}",
+            style(chrono::Local::now().format("%H:%M:%S")).dim(),
+            style(slot).cyan(),
+            lamports_to_sol(account.lamports)
+        );
+        if let Some(summary) = &stake_summary {
+            println!("  {}", style(summary).dim());
+        }
+    })
+}
@@ -0,0 +1,153 @@
+use {
+    crate::{
+        address_book::{AddressBook, address_book_path},
+        commands::CommandExec,
+        context::ScillaContext,
+        error::ScillaResult,
+        notice,
+        prompt::{prompt_data, prompt_yes_no},
+        ui::{new_table, print_output},
+    },
+    anyhow::bail,
+    clap::ValueEnum,
+    comfy_table::{Cell, presets::UTF8_FULL},
+    console::style,
+    inquire::Confirm,
+    serde_json::json,
+    solana_pubkey::Pubkey,
+    std::fmt,
+};
+
+/// Commands for the named addresses that any pubkey prompt accepts
+#[derive(Debug, Clone, ValueEnum)]
+pub enum AddressBookCommand {
+    Add,
+    List,
+    Remove,
+    #[value(skip)]
+    GoBack,
+}
+
+impl AddressBookCommand {
+    pub fn spinner_msg(&self) -> &'static str {
+        match self {
+            AddressBookCommand::Add => "Saving a named address…",
+            AddressBookCommand::List => "Listing saved addresses…",
+            AddressBookCommand::Remove => "Removing a saved address…",
+            AddressBookCommand::GoBack => "Going back…",
+        }
+    }
+}
+
+impl fmt::Display for AddressBookCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let command = match self {
+            AddressBookCommand::Add => "Add address",
+            AddressBookCommand::List => "List addresses",
+            AddressBookCommand::Remove => "Remove address",
+            AddressBookCommand::GoBack => "Go back",
+        };
+        write!(f, "{command}")
+    }
+}
+
+impl AddressBookCommand {
+    pub async fn process_command(&self, ctx: &ScillaContext) -> ScillaResult<()> {
+        match self {
+            AddressBookCommand::Add => {
+                let name: String = prompt_data("Enter a name for the address:")?;
+                let address: Pubkey = prompt_data("Enter Pubkey:")?;
+
+                let mut book = AddressBook::load()?;
+                if let Some(existing) = book.get(&name)
+                    && existing != address
+                {
+                    let replace = prompt_yes_no(
+                        Confirm::new(&format!(
+                            "`{}` already points to {existing}. Replace it?",
+                            name.trim()
+                        ))
+                        .with_default(false),
+                    )?;
+
+                    if !replace {
+                        notice!("{}", style("Address not saved.").yellow());
+                        return Ok(CommandExec::Process(()));
+                    }
+                }
+
+                book.insert(&name, address)?;
+                book.save()?;
+
+                notice!(
+                    "\n{}",
+                    style(format!(
+                        "✓ Saved `{}` as {address}",
+                        name.trim().to_lowercase()
+                    ))
+                    .green()
+                    .bold()
+                );
+            }
+            AddressBookCommand::List => list_addresses(ctx)?,
+            AddressBookCommand::Remove => {
+                let name: String = prompt_data("Enter the name to remove:")?;
+
+                let mut book = AddressBook::load()?;
+                let Some(address) = book.remove(&name) else {
+                    bail!("No address named `{}`", name.trim());
+                };
+                book.save()?;
+
+                notice!(
+                    "\n{}",
+                    style(format!("✓ Removed `{}` ({address})", name.trim()))
+                        .green()
+                        .bold()
+                );
+            }
+            AddressBookCommand::GoBack => return Ok(CommandExec::GoBack),
+        }
+
+        Ok(CommandExec::Process(()))
+    }
+}
+
+fn list_addresses(ctx: &ScillaContext) -> anyhow::Result<()> {
+    let book = AddressBook::load()?;
+
+    let value: Vec<_> = book
+        .entries()
+        .map(|(name, address)| json!({ "name": name, "address": address.to_string() }))
+        .collect();
+
+    let mut table = new_table();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Name").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Address").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+    for (name, address) in book.entries() {
+        table.add_row(vec![Cell::new(name), Cell::new(address)]);
+    }
+
+    print_output(ctx, &value, || {
+        if book.is_empty() {
+            println!(
+                "\n{}",
+                style(
+                    "The address book is empty. Add an address to use its name in place of a \
+                     pubkey."
+                )
+                .yellow()
+            );
+            return;
+        }
+
+        println!("\n{}", style("ADDRESS BOOK").green().bold());
+        println!("{}", table);
+        println!(
+            "{}",
+            style(format!("Stored in {}", address_book_path().display())).dim()
+        );
+    })
+}
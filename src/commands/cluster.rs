@@ -1,24 +1,72 @@
 use {
     crate::{
-        commands::CommandExec, constants::LAMPORTS_PER_SOL, context::ScillaContext,
-        error::ScillaResult, ui::show_spinner,
+        commands::CommandExec,
+        constants::{
+            DEFAULT_FINDER_MAX_COMMISSION, DEFAULT_PING_COUNT, DEFAULT_TPS_SAMPLES,
+            DEFAULT_VALIDATOR_LIMIT, EPOCH_PROGRESS_BAR_WIDTH, HIGH_SKIP_RATE_PCT,
+            LAMPORTS_PER_SOL, RECENT_EPOCH_CREDITS_LIMIT, TOKEN_ACCOUNT_SIZE, TOKEN_MINT_SIZE,
+        },
+        context::ScillaContext,
+        error::ScillaResult,
+        misc::helpers::{
+            Commission, OptionalInput, SolAmount, average_epoch_credits, average_slot_time_ms,
+            build_and_send_tx, format_duration, lamports_to_sol, percentile, progress_bar,
+            slot_runs, slots_to_duration, slots_until_epoch, sparkline, version_key,
+        },
+        prompt::{prompt_data, prompt_select, prompt_yes_no},
+        ui::{new_table, print_output, show_spinner, show_spinner_with_status},
     },
-    comfy_table::{Cell, Table, presets::UTF8_FULL},
+    anyhow::{anyhow, bail},
+    clap::ValueEnum,
+    comfy_table::{Cell, Color, Table, presets::UTF8_FULL},
     console::style,
-    std::{fmt, ops::Div},
+    indicatif::ProgressBar,
+    inquire::{Confirm, Select},
+    serde_json::json,
+    solana_epoch_info::EpochInfo,
+    solana_nonce::state::State as NonceState,
+    solana_pubkey::Pubkey,
+    solana_rpc_client_api::{
+        client_error::ErrorKind as ClientErrorKind,
+        config::{
+            RpcBlockProductionConfig, RpcBlockProductionConfigRange, RpcLeaderScheduleConfig,
+        },
+        request::{RpcError, RpcResponseErrorData},
+        response::{RpcInflationRate, RpcLeaderSchedule},
+    },
+    solana_stake_interface::state::StakeStateV2,
+    solana_system_interface::instruction::transfer,
+    std::{
+        cmp::Reverse,
+        fmt,
+        ops::Div,
+        str::FromStr,
+        time::{Duration, Instant},
+    },
+    tokio::try_join,
 };
 
 /// Commands related to cluster operations
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, ValueEnum)]
 pub enum ClusterCommand {
     EpochInfo,
     CurrentSlot,
     BlockHeight,
     BlockTime,
+    Activity,
     Validators,
+    FindValidators,
+    Nodes,
     SupplyInfo,
     Inflation,
     ClusterVersion,
+    Health,
+    Ping,
+    Performance,
+    LeaderSchedule,
+    BlockProduction,
+    Rent,
+    #[value(skip)]
     GoBack,
 }
 
@@ -29,10 +77,19 @@ impl ClusterCommand {
             ClusterCommand::CurrentSlot => "Fetching latest confirmed slot…",
             ClusterCommand::BlockHeight => "Fetching current block height…",
             ClusterCommand::BlockTime => "Fetching block timestamp…",
+            ClusterCommand::Activity => "Checking network activity…",
             ClusterCommand::Validators => "Fetching active validators…",
+            ClusterCommand::FindValidators => "Finding validators that match…",
+            ClusterCommand::Nodes => "Fetching cluster nodes…",
             ClusterCommand::ClusterVersion => "Fetching cluster Solana version…",
-            ClusterCommand::SupplyInfo => "Fetching total and circulating supply…",
+            ClusterCommand::Health => "Checking RPC node health…",
+            ClusterCommand::SupplyInfo => "Fetching supply and inflation rate…",
             ClusterCommand::Inflation => "Fetching inflation parameters…",
+            ClusterCommand::Ping => "Pinging cluster…",
+            ClusterCommand::Performance => "Fetching recent performance samples…",
+            ClusterCommand::LeaderSchedule => "Fetching leader schedule…",
+            ClusterCommand::BlockProduction => "Fetching block production…",
+            ClusterCommand::Rent => "Fetching rent-exempt minimum…",
             ClusterCommand::GoBack => "Going back…",
         }
     }
@@ -45,10 +102,19 @@ impl fmt::Display for ClusterCommand {
             ClusterCommand::CurrentSlot => "Current Slot",
             ClusterCommand::BlockHeight => "Block Height",
             ClusterCommand::BlockTime => "Block Time",
+            ClusterCommand::Activity => "Network Activity",
             ClusterCommand::Validators => "Validators",
+            ClusterCommand::FindValidators => "Find Validators",
+            ClusterCommand::Nodes => "Cluster Nodes",
             ClusterCommand::ClusterVersion => "Cluster Version",
-            ClusterCommand::SupplyInfo => "Supply Info",
+            ClusterCommand::Health => "Health Check",
+            ClusterCommand::SupplyInfo => "Supply & Inflation",
             ClusterCommand::Inflation => "Inflation",
+            ClusterCommand::Ping => "Ping",
+            ClusterCommand::Performance => "Performance (TPS)",
+            ClusterCommand::LeaderSchedule => "Leader Schedule",
+            ClusterCommand::BlockProduction => "Block Production",
+            ClusterCommand::Rent => "Rent Calculator",
             ClusterCommand::GoBack => "Go back",
         };
         write!(f, "{command}")
@@ -68,10 +134,57 @@ impl ClusterCommand {
                 show_spinner(self.spinner_msg(), fetch_block_height(ctx)).await?;
             }
             ClusterCommand::BlockTime => {
-                show_spinner(self.spinner_msg(), fetch_block_time(ctx)).await?;
+                let slot: OptionalInput<u64> =
+                    prompt_data("Enter Slot (leave empty for the current slot):")?;
+                show_spinner(self.spinner_msg(), fetch_block_time(ctx, slot.value())).await?;
+            }
+            ClusterCommand::Activity => {
+                show_spinner(self.spinner_msg(), fetch_network_activity(ctx)).await?;
             }
             ClusterCommand::Validators => {
-                show_spinner(self.spinner_msg(), fetch_validators(ctx)).await?;
+                let limit: OptionalInput<usize> = prompt_data(&format!(
+                    "Number of validators to show (default {DEFAULT_VALIDATOR_LIMIT}):"
+                ))?;
+                let limit = limit.value().unwrap_or(DEFAULT_VALIDATOR_LIMIT);
+                show_spinner(self.spinner_msg(), fetch_validators(ctx, limit)).await?;
+            }
+            ClusterCommand::FindValidators => {
+                let max_commission: OptionalInput<Commission> = prompt_data(&format!(
+                    "Maximum commission in % (default {DEFAULT_FINDER_MAX_COMMISSION}):"
+                ))?;
+                let min_stake: OptionalInput<SolAmount> =
+                    prompt_data("Minimum activated stake in SOL (leave empty for no minimum):")?;
+                let max_stake: OptionalInput<SolAmount> = prompt_data(
+                    "Maximum activated stake in SOL, lower favors smaller validators (leave empty \
+                     for no maximum):",
+                )?;
+                let limit: OptionalInput<usize> = prompt_data(&format!(
+                    "Number of validators to show (default {DEFAULT_VALIDATOR_LIMIT}):"
+                ))?;
+
+                let filter = ValidatorFilter {
+                    max_commission: max_commission
+                        .value()
+                        .map_or(DEFAULT_FINDER_MAX_COMMISSION, |commission| {
+                            commission.value()
+                        }),
+                    min_stake: min_stake.value().map(|amount| amount.to_lamports()),
+                    max_stake: max_stake.value().map(|amount| amount.to_lamports()),
+                };
+                if let (Some(min_stake), Some(max_stake)) = (filter.min_stake, filter.max_stake)
+                    && min_stake > max_stake
+                {
+                    bail!("The minimum stake cannot be above the maximum stake");
+                }
+                let limit = limit.value().unwrap_or(DEFAULT_VALIDATOR_LIMIT);
+                show_spinner(self.spinner_msg(), find_validators(ctx, &filter, limit)).await?;
+            }
+            ClusterCommand::Nodes => {
+                let rpc_only = prompt_yes_no(
+                    Confirm::new("Only show nodes exposing a public RPC endpoint?")
+                        .with_default(false),
+                )?;
+                show_spinner(self.spinner_msg(), fetch_cluster_nodes(ctx, rpc_only)).await?;
             }
             ClusterCommand::SupplyInfo => {
                 show_spinner(self.spinner_msg(), fetch_supply_info(ctx)).await?;
@@ -82,6 +195,77 @@ impl ClusterCommand {
             ClusterCommand::ClusterVersion => {
                 show_spinner(self.spinner_msg(), fetch_cluster_version(ctx)).await?;
             }
+            ClusterCommand::Health => {
+                show_spinner(self.spinner_msg(), fetch_node_health(ctx)).await?;
+            }
+            ClusterCommand::Ping => {
+                let count: OptionalInput<usize> = prompt_data(&format!(
+                    "Number of pings, each a fee-paying self-transfer (default \
+                     {DEFAULT_PING_COUNT}):"
+                ))?;
+                let count = count.value().unwrap_or(DEFAULT_PING_COUNT);
+                show_spinner_with_status(self.spinner_msg(), |spinner| {
+                    process_ping(ctx, count, spinner)
+                })
+                .await?;
+            }
+            ClusterCommand::Performance => {
+                let samples: OptionalInput<usize> = prompt_data(&format!(
+                    "Number of one-minute samples to show (default {DEFAULT_TPS_SAMPLES}):"
+                ))?;
+                let samples = samples.value().unwrap_or(DEFAULT_TPS_SAMPLES);
+                show_spinner(self.spinner_msg(), fetch_performance(ctx, samples)).await?;
+            }
+            ClusterCommand::LeaderSchedule => {
+                let scope: LeaderScope = prompt_data(
+                    "Enter Validator Identity (leave empty for your wallet, `all` for every \
+                     validator):",
+                )?;
+                let identity = match scope {
+                    LeaderScope::Wallet => Some(*ctx.pubkey()),
+                    LeaderScope::Identity(identity) => Some(identity),
+                    LeaderScope::All => None,
+                };
+                show_spinner(self.spinner_msg(), fetch_leader_schedule(ctx, identity)).await?;
+            }
+            ClusterCommand::BlockProduction => {
+                let identity: OptionalInput<Pubkey> =
+                    prompt_data("Enter Validator Identity (leave empty for your wallet):")?;
+                let first_slot: OptionalInput<u64> =
+                    prompt_data("Enter First Slot (leave empty for the current epoch):")?;
+                let range = match first_slot.value() {
+                    Some(first_slot) => {
+                        let last_slot: OptionalInput<u64> =
+                            prompt_data("Enter Last Slot (leave empty for the latest slot):")?;
+                        Some(RpcBlockProductionConfigRange {
+                            first_slot,
+                            last_slot: last_slot.value(),
+                        })
+                    }
+                    None => None,
+                };
+                let identity = identity.value().unwrap_or(*ctx.pubkey());
+                show_spinner(
+                    self.spinner_msg(),
+                    fetch_block_production(ctx, &identity, range),
+                )
+                .await?;
+            }
+            ClusterCommand::Rent => {
+                let preset = prompt_select(Select::new(
+                    "Account type:",
+                    AccountSizePreset::ALL.to_vec(),
+                ))?;
+                let data_len = match preset.data_len() {
+                    Some(data_len) => data_len,
+                    None => prompt_data("Enter data size in bytes:")?,
+                };
+                show_spinner(
+                    self.spinner_msg(),
+                    fetch_rent_exemption(ctx, preset, data_len),
+                )
+                .await?;
+            }
             ClusterCommand::GoBack => {
                 return Ok(CommandExec::GoBack);
             }
@@ -91,10 +275,129 @@ impl ClusterCommand {
     }
 }
 
+/// Whose leader slots to look up
+enum LeaderScope {
+    Wallet,
+    Identity(Pubkey),
+    All,
+}
+
+impl FromStr for LeaderScope {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "" => Ok(Self::Wallet),
+            all if all.eq_ignore_ascii_case("all") => Ok(Self::All),
+            identity => Pubkey::from_str(identity)
+                .map(Self::Identity)
+                .map_err(|e| anyhow!("{e}")),
+        }
+    }
+}
+
+/// Common account layouts to look up the rent-exempt minimum for
+#[derive(Debug, Clone, Copy)]
+enum AccountSizePreset {
+    Custom,
+    System,
+    TokenAccount,
+    TokenMint,
+    StakeAccount,
+    NonceAccount,
+}
+
+impl AccountSizePreset {
+    const ALL: [Self; 6] = [
+        Self::Custom,
+        Self::System,
+        Self::TokenAccount,
+        Self::TokenMint,
+        Self::StakeAccount,
+        Self::NonceAccount,
+    ];
+
+    /// `None` for a custom size that still has to be asked for
+    fn data_len(&self) -> Option<usize> {
+        match self {
+            Self::Custom => None,
+            Self::System => Some(0),
+            Self::TokenAccount => Some(TOKEN_ACCOUNT_SIZE),
+            Self::TokenMint => Some(TOKEN_MINT_SIZE),
+            Self::StakeAccount => Some(StakeStateV2::size_of()),
+            Self::NonceAccount => Some(NonceState::size()),
+        }
+    }
+}
+
+impl fmt::Display for AccountSizePreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Custom => "Custom size",
+            Self::System => "Wallet (system account)",
+            Self::TokenAccount => "Token account",
+            Self::TokenMint => "Token mint",
+            Self::StakeAccount => "Stake account",
+            Self::NonceAccount => "Nonce account",
+        };
+        match self.data_len() {
+            Some(data_len) => write!(f, "{name} ({data_len} bytes)"),
+            None => write!(f, "{name}"),
+        }
+    }
+}
+
+async fn fetch_rent_exemption(
+    ctx: &ScillaContext,
+    preset: AccountSizePreset,
+    data_len: usize,
+) -> anyhow::Result<()> {
+    let lamports = ctx
+        .rpc()
+        .get_minimum_balance_for_rent_exemption(data_len)
+        .await?;
+
+    let mut table = new_table();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![Cell::new("Data Size (bytes)"), Cell::new(data_len)])
+        .add_row(vec![
+            Cell::new("Rent-Exempt Minimum (SOL)"),
+            Cell::new(lamports_to_sol(lamports)),
+        ])
+        .add_row(vec![
+            Cell::new("Rent-Exempt Minimum (lamports)"),
+            Cell::new(lamports),
+        ]);
+
+    let value = json!({
+        "data_len": data_len,
+        "lamports": lamports,
+        "sol": lamports_to_sol(lamports),
+    });
+
+    print_output(ctx, &value, || {
+        println!(
+            "\n{}",
+            style(format!("RENT EXEMPTION · {preset}")).green().bold()
+        );
+        println!("{table}");
+    })
+}
+
 async fn fetch_epoch_info(ctx: &ScillaContext) -> anyhow::Result<()> {
     let epoch_info = ctx.rpc().get_epoch_info().await?;
+    let slot_time_ms = average_slot_time_ms(ctx).await;
+
+    let progress = epoch_info.slot_index as f64 / epoch_info.slots_in_epoch as f64;
+    let slots_remaining = slots_until_epoch(&epoch_info, epoch_info.epoch + 1);
+    let time_remaining = slots_to_duration(slots_remaining, slot_time_ms);
 
-    let mut table = Table::new();
+    let mut table = new_table();
     table
         .load_preset(UTF8_FULL)
         .set_header(vec![
@@ -113,6 +416,21 @@ async fn fetch_epoch_info(ctx: &ScillaContext) -> anyhow::Result<()> {
             Cell::new("Slots in Epoch"),
             Cell::new(format!("{}", epoch_info.slots_in_epoch)),
         ])
+        .add_row(vec![
+            Cell::new("Progress"),
+            Cell::new(format!(
+                "{} {:.2}%",
+                progress_bar(progress, EPOCH_PROGRESS_BAR_WIDTH),
+                progress * 100.0
+            )),
+        ])
+        .add_row(vec![
+            Cell::new("Time Remaining (est.)"),
+            Cell::new(format!(
+                "~{} ({slots_remaining} slots at {slot_time_ms:.0}ms)",
+                format_duration(time_remaining)
+            )),
+        ])
         .add_row(vec![
             Cell::new("Absolute Slot"),
             Cell::new(format!("{}", epoch_info.absolute_slot)),
@@ -126,16 +444,28 @@ async fn fetch_epoch_info(ctx: &ScillaContext) -> anyhow::Result<()> {
             Cell::new(format!("{}", epoch_info.transaction_count.unwrap_or(0))),
         ]);
 
-    println!("\n{}", style("EPOCH INFORMATION").green().bold());
-    println!("{table}");
+    let value = json!({
+        "epoch": epoch_info.epoch,
+        "slot_index": epoch_info.slot_index,
+        "slots_in_epoch": epoch_info.slots_in_epoch,
+        "progress_pct": progress * 100.0,
+        "slots_remaining": slots_remaining,
+        "estimated_secs_remaining": time_remaining.as_secs(),
+        "absolute_slot": epoch_info.absolute_slot,
+        "block_height": epoch_info.block_height,
+        "transaction_count": epoch_info.transaction_count,
+    });
 
-    Ok(())
+    print_output(ctx, &value, || {
+        println!("\n{}", style("EPOCH INFORMATION").green().bold());
+        println!("{table}");
+    })
 }
 
 async fn fetch_current_slot(ctx: &ScillaContext) -> anyhow::Result<()> {
     let slot = ctx.rpc().get_slot().await?;
 
-    let mut table = Table::new();
+    let mut table = new_table();
     table
         .load_preset(UTF8_FULL)
         .set_header(vec![
@@ -147,16 +477,16 @@ async fn fetch_current_slot(ctx: &ScillaContext) -> anyhow::Result<()> {
             Cell::new(format!("{slot}")),
         ]);
 
-    println!("\n{}", style("CURRENT SLOT").green().bold());
-    println!("{table}");
-
-    Ok(())
+    print_output(ctx, &json!({ "slot": slot }), || {
+        println!("\n{}", style("CURRENT SLOT").green().bold());
+        println!("{table}");
+    })
 }
 
 async fn fetch_block_height(ctx: &ScillaContext) -> anyhow::Result<()> {
     let block_height = ctx.rpc().get_block_height().await?;
 
-    let mut table = Table::new();
+    let mut table = new_table();
     table
         .load_preset(UTF8_FULL)
         .set_header(vec![
@@ -168,21 +498,46 @@ async fn fetch_block_height(ctx: &ScillaContext) -> anyhow::Result<()> {
             Cell::new(format!("{block_height}")),
         ]);
 
-    println!("\n{}", style("BLOCK HEIGHT").green().bold());
-    println!("{table}");
-
-    Ok(())
+    print_output(ctx, &json!({ "block_height": block_height }), || {
+        println!("\n{}", style("BLOCK HEIGHT").green().bold());
+        println!("{table}");
+    })
 }
 
-async fn fetch_block_time(ctx: &ScillaContext) -> anyhow::Result<()> {
-    let slot = ctx.rpc().get_slot().await?;
-    let block_time = ctx.rpc().get_block_time(slot).await?;
+async fn fetch_block_time(ctx: &ScillaContext, slot: Option<u64>) -> anyhow::Result<()> {
+    let current_slot = ctx.rpc().get_slot().await?;
+    let slot = slot.unwrap_or(current_slot);
+    if slot > current_slot {
+        bail!("Slot {slot} hasn't been reached yet, the cluster is at slot {current_slot}");
+    }
 
-    let datetime = chrono::DateTime::<chrono::Utc>::from_timestamp_secs(block_time)
-        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
-        .unwrap_or_else(|| "Invalid timestamp".to_string());
+    let block_time = match ctx.rpc().get_block_time(slot).await {
+        Ok(block_time) => block_time,
+        Err(err) if err.to_string().contains("skipped") => {
+            bail!("Slot {slot} was skipped, no block was produced for it")
+        }
+        Err(err) => {
+            let first_available = ctx.rpc().get_first_available_block().await?;
+            if slot < first_available {
+                bail!(
+                    "Slot {slot} is too old for this RPC node, which keeps blocks from slot \
+                     {first_available}. Try an archive RPC"
+                );
+            }
+            bail!("Block time for slot {slot} is not available: {err}");
+        }
+    };
+
+    let utc = chrono::DateTime::<chrono::Utc>::from_timestamp_secs(block_time)
+        .ok_or_else(|| anyhow!("Slot {slot} has an invalid timestamp {block_time}"))?;
+    let datetime = utc.format("%Y-%m-%d %H:%M:%S UTC").to_string();
+    let local = utc
+        .with_timezone(&chrono::Local)
+        .format("%Y-%m-%d %H:%M:%S %:z")
+        .to_string();
+    let age = (chrono::Utc::now() - utc).to_std().unwrap_or_default();
 
-    let mut table = Table::new();
+    let mut table = new_table();
     table
         .load_preset(UTF8_FULL)
         .set_header(vec![
@@ -194,73 +549,742 @@ async fn fetch_block_time(ctx: &ScillaContext) -> anyhow::Result<()> {
             Cell::new("Unix Timestamp"),
             Cell::new(format!("{block_time}")),
         ])
-        .add_row(vec![Cell::new("Date/Time"), Cell::new(datetime)]);
+        .add_row(vec![Cell::new("Date/Time"), Cell::new(&datetime)])
+        .add_row(vec![Cell::new("Local Time"), Cell::new(&local)])
+        .add_row(vec![
+            Cell::new("Age"),
+            Cell::new(format!("{} ago", format_duration(age))),
+        ]);
+
+    let value = json!({
+        "slot": slot,
+        "unix_timestamp": block_time,
+        "datetime": datetime,
+        "local_datetime": local,
+        "age_secs": age.as_secs(),
+    });
+
+    print_output(ctx, &value, || {
+        println!("\n{}", style("BLOCK TIME").green().bold());
+        println!("{table}");
+    })
+}
 
-    println!("\n{}", style("BLOCK TIME").green().bold());
-    println!("{table}");
+async fn fetch_network_activity(ctx: &ScillaContext) -> anyhow::Result<()> {
+    let (transaction_count, slot, samples) = try_join!(
+        ctx.rpc().get_transaction_count(),
+        ctx.rpc().get_slot(),
+        ctx.rpc().get_recent_performance_samples(Some(1)),
+    )?;
+    // The latest sample covers about the last minute
+    let tps = samples
+        .first()
+        .filter(|sample| sample.sample_period_secs > 0)
+        .map(|sample| sample.num_transactions as f64 / sample.sample_period_secs as f64);
 
-    Ok(())
+    let value = json!({
+        "transaction_count": transaction_count,
+        "slot": slot,
+        "tps": tps,
+    });
+
+    print_output(ctx, &value, || {
+        println!("\n{}", style("NETWORK ACTIVITY").green().bold());
+        println!(
+            "{} {}",
+            style("Transactions:").cyan(),
+            style(transaction_count).bold()
+        );
+        println!("{} {}", style("Slot:").cyan(), style(slot).bold());
+        match tps {
+            Some(tps) => println!(
+                "{} {}",
+                style("Recent TPS:").cyan(),
+                style(format!("{tps:.0}")).bold()
+            ),
+            None => println!(
+                "{} {}",
+                style("Recent TPS:").cyan(),
+                style("no recent samples").yellow()
+            ),
+        }
+    })
 }
 
-async fn fetch_validators(ctx: &ScillaContext) -> anyhow::Result<()> {
+async fn fetch_validators(ctx: &ScillaContext, limit: usize) -> anyhow::Result<()> {
     let validators = ctx.rpc().get_vote_accounts().await?;
 
-    // Summary table
-    let mut summary_table = Table::new();
-    summary_table
-        .load_preset(UTF8_FULL)
-        .set_header(vec![
-            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
-            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
-        ])
-        .add_row(vec![
-            Cell::new("Current Validators"),
-            Cell::new(format!("{}", validators.current.len())),
-        ])
-        .add_row(vec![
-            Cell::new("Delinquent Validators"),
-            Cell::new(format!("{}", validators.delinquent.len())),
-        ]);
+    let mut all_validators: Vec<_> = validators
+        .current
+        .iter()
+        .map(|v| (v, false))
+        .chain(validators.delinquent.iter().map(|v| (v, true)))
+        .collect();
+    all_validators.sort_by_key(|(v, _)| Reverse(v.activated_stake));
+    let shown = &all_validators[..limit.min(all_validators.len())];
 
-    println!("\n{}", style("VALIDATORS SUMMARY").green().bold());
-    println!("{summary_table}");
+    let value = json!({
+        "current": validators.current.len(),
+        "delinquent": validators.delinquent.len(),
+        "validators": shown
+            .iter()
+            .map(|(validator, delinquent)| {
+                json!({
+                    "node_pubkey": validator.node_pubkey,
+                    "vote_pubkey": validator.vote_pubkey,
+                    "activated_stake": validator.activated_stake,
+                    "commission": validator.commission,
+                    "last_vote": validator.last_vote,
+                    "delinquent": delinquent,
+                })
+            })
+            .collect::<Vec<_>>(),
+    });
 
-    // Validators detail table
-    if !validators.current.is_empty() {
-        let mut validators_table = Table::new();
+    print_output(ctx, &value, || {
+        // Summary table
+        let mut summary_table = new_table();
+        summary_table
+            .load_preset(UTF8_FULL)
+            .set_header(vec![
+                Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+                Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+            ])
+            .add_row(vec![
+                Cell::new("Current Validators"),
+                Cell::new(format!("{}", validators.current.len())),
+            ])
+            .add_row(vec![
+                Cell::new("Delinquent Validators"),
+                Cell::new(format!("{}", validators.delinquent.len())),
+            ]);
+
+        println!("\n{}", style("VALIDATORS SUMMARY").green().bold());
+        println!("{summary_table}");
+
+        if shown.is_empty() {
+            return;
+        }
+
+        // Validators detail table
+        let mut validators_table = new_table();
         validators_table.load_preset(UTF8_FULL).set_header(vec![
             Cell::new("#").add_attribute(comfy_table::Attribute::Bold),
             Cell::new("Node Pubkey").add_attribute(comfy_table::Attribute::Bold),
             Cell::new("Vote Account").add_attribute(comfy_table::Attribute::Bold),
             Cell::new("Activated Stake (SOL)").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Commission").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Last Vote").add_attribute(comfy_table::Attribute::Bold),
         ]);
 
-        for (idx, validator) in validators.current.iter().enumerate() {
+        for (idx, (validator, delinquent)) in shown.iter().enumerate() {
             let stake_sol = (validator.activated_stake as f64).div(LAMPORTS_PER_SOL as f64);
-            validators_table.add_row(vec![
+            let row = vec![
                 Cell::new(format!("{}", idx + 1)),
                 Cell::new(validator.node_pubkey.clone()),
                 Cell::new(validator.vote_pubkey.clone()),
                 Cell::new(format!("{stake_sol:.2}")),
-            ]);
+                Cell::new(format!("{}%", validator.commission)),
+                Cell::new(format!("{}", validator.last_vote)),
+            ];
+            let row = if *delinquent {
+                row.into_iter().map(|cell| cell.fg(Color::Red)).collect()
+            } else {
+                row
+            };
+            validators_table.add_row(row);
         }
 
-        println!("\n{}", style("TOP VALIDATORS").green().bold());
+        println!(
+            "\n{}",
+            style(format!(
+                "TOP {} OF {} VALIDATORS BY STAKE",
+                shown.len(),
+                all_validators.len()
+            ))
+            .green()
+            .bold()
+        );
         println!("{validators_table}");
+
+        if shown.iter().any(|(_, delinquent)| *delinquent) {
+            println!("{}", style("Delinquent validators are shown in red.").red());
+        }
+    })
+}
+
+/// Thresholds a validator has to meet to be suggested for delegation
+struct ValidatorFilter {
+    max_commission: u8,
+    min_stake: Option<u64>,
+    max_stake: Option<u64>,
+}
+
+impl ValidatorFilter {
+    fn matches(&self, commission: u8, activated_stake: u64) -> bool {
+        commission <= self.max_commission
+            && self.min_stake.is_none_or(|min| activated_stake >= min)
+            && self.max_stake.is_none_or(|max| activated_stake <= max)
     }
+}
+
+async fn find_validators(
+    ctx: &ScillaContext,
+    filter: &ValidatorFilter,
+    limit: usize,
+) -> anyhow::Result<()> {
+    let validators = ctx.rpc().get_vote_accounts().await?;
+    let epoch_info = ctx.epoch_info().await?;
+
+    // Delinquent validators earn nothing for their delegators, so they are
+    // never suggested
+    let credits: Vec<_> = validators
+        .current
+        .iter()
+        .map(|validator| {
+            let average = average_epoch_credits(
+                &validator.epoch_credits,
+                epoch_info.epoch,
+                RECENT_EPOCH_CREDITS_LIMIT,
+            );
+            (validator, average)
+        })
+        .collect();
+    let best = credits
+        .iter()
+        .filter_map(|(_, average)| *average)
+        .fold(0.0, f64::max);
 
-    Ok(())
+    let mut candidates: Vec<_> = credits
+        .into_iter()
+        .filter(|(validator, _)| filter.matches(validator.commission, validator.activated_stake))
+        .collect();
+    // Validators too new to have a completed epoch go last
+    candidates.sort_by(|(_, a), (_, b)| b.unwrap_or(-1.0).total_cmp(&a.unwrap_or(-1.0)));
+    let shown = &candidates[..limit.min(candidates.len())];
+    let performance = |average: Option<f64>| {
+        average
+            .filter(|_| best > 0.0)
+            .map(|average| average / best * 100.0)
+    };
+
+    let value = json!({
+        "max_commission": filter.max_commission,
+        "min_stake": filter.min_stake,
+        "max_stake": filter.max_stake,
+        "matching": candidates.len(),
+        "active": validators.current.len(),
+        "validators": shown
+            .iter()
+            .map(|(validator, average)| {
+                json!({
+                    "node_pubkey": validator.node_pubkey,
+                    "vote_pubkey": validator.vote_pubkey,
+                    "activated_stake": validator.activated_stake,
+                    "commission": validator.commission,
+                    "average_epoch_credits": average,
+                    "performance_pct": performance(*average),
+                })
+            })
+            .collect::<Vec<_>>(),
+    });
+
+    print_output(ctx, &value, || {
+        if shown.is_empty() {
+            println!(
+                "\n{}",
+                style("No active validators match these thresholds.").yellow()
+            );
+            return;
+        }
+
+        let mut table = new_table();
+        table.load_preset(UTF8_FULL).set_header(vec![
+            Cell::new("#").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Vote Account").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Node Pubkey").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Commission").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Activated Stake (SOL)").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Credits / Epoch").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Performance").add_attribute(comfy_table::Attribute::Bold),
+        ]);
+
+        for (idx, (validator, average)) in shown.iter().enumerate() {
+            table.add_row(vec![
+                Cell::new(format!("{}", idx + 1)),
+                Cell::new(validator.vote_pubkey.clone()),
+                Cell::new(validator.node_pubkey.clone()),
+                Cell::new(format!("{}%", validator.commission)),
+                Cell::new(format!("{:.2}", lamports_to_sol(validator.activated_stake))),
+                Cell::new(average.map_or("-".to_string(), |average| format!("{average:.0}"))),
+                Cell::new(
+                    performance(*average)
+                        .map_or("-".to_string(), |performance| format!("{performance:.1}%")),
+                ),
+            ]);
+        }
+
+        println!(
+            "\n{}",
+            style(format!(
+                "{} OF {} MATCHING VALIDATORS BY PERFORMANCE",
+                shown.len(),
+                candidates.len()
+            ))
+            .green()
+            .bold()
+        );
+        println!("{table}");
+        println!(
+            "{}",
+            style(format!(
+                "Credits are averaged over the last {RECENT_EPOCH_CREDITS_LIMIT} completed \
+                 epochs; performance is relative to the best validator on the cluster."
+            ))
+            .dim()
+        );
+    })
+}
+
+async fn fetch_cluster_nodes(ctx: &ScillaContext, rpc_only: bool) -> anyhow::Result<()> {
+    let mut nodes = ctx.rpc().get_cluster_nodes().await?;
+    let total = nodes.len();
+    if rpc_only {
+        nodes.retain(|node| node.rpc.is_some());
+    }
+    // Oldest first so outdated nodes are at the top, unknown versions last
+    nodes.sort_by_key(|node| {
+        let version = node.version.as_deref().map(version_key);
+        (version.is_none(), version)
+    });
+
+    let mut versions: Vec<(&str, usize)> = Vec::new();
+    for node in &nodes {
+        let version = node.version.as_deref().unwrap_or("unknown");
+        match versions.iter_mut().find(|(seen, _)| *seen == version) {
+            Some((_, count)) => *count += 1,
+            None => versions.push((version, 1)),
+        }
+    }
+
+    let value = json!({
+        "total": total,
+        "shown": nodes.len(),
+        "versions": versions
+            .iter()
+            .map(|(version, count)| json!({ "version": version, "nodes": count }))
+            .collect::<Vec<_>>(),
+        "nodes": nodes,
+    });
+
+    print_output(ctx, &value, || {
+        if nodes.is_empty() {
+            println!("\n{}", style("No cluster nodes to show.").yellow());
+            return;
+        }
+
+        let address = |addr: Option<std::net::SocketAddr>| {
+            addr.map_or("-".to_string(), |addr| addr.to_string())
+        };
+        let mut table = new_table();
+        table.load_preset(UTF8_FULL).set_header(vec![
+            Cell::new("Identity").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Gossip").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("TPU").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("RPC").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Version").add_attribute(comfy_table::Attribute::Bold),
+        ]);
+        for node in &nodes {
+            table.add_row(vec![
+                Cell::new(&node.pubkey),
+                Cell::new(address(node.gossip)),
+                Cell::new(address(node.tpu.or(node.tpu_quic))),
+                Cell::new(address(node.rpc)),
+                Cell::new(node.version.as_deref().unwrap_or("unknown")),
+            ]);
+        }
+
+        let mut versions_table = new_table();
+        versions_table.load_preset(UTF8_FULL).set_header(vec![
+            Cell::new("Version").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Nodes").add_attribute(comfy_table::Attribute::Bold),
+        ]);
+        for (version, count) in versions.iter().rev() {
+            versions_table.add_row(vec![Cell::new(version), Cell::new(count)]);
+        }
+
+        let title = match rpc_only {
+            true => format!("{} OF {total} NODES EXPOSING RPC", nodes.len()),
+            false => format!("{total} CLUSTER NODES"),
+        };
+        println!("\n{}", style(title).green().bold());
+        println!("{table}");
+        println!("\n{}", style("VERSION SPREAD").green().bold());
+        println!("{versions_table}");
+    })
+}
+
+async fn fetch_performance(ctx: &ScillaContext, limit: usize) -> anyhow::Result<()> {
+    let samples = ctx
+        .rpc()
+        .get_recent_performance_samples(Some(limit))
+        .await?;
+    let samples: Vec<_> = samples
+        .into_iter()
+        .filter(|sample| sample.sample_period_secs > 0)
+        .collect();
+    if samples.is_empty() {
+        bail!("The RPC node returned no performance samples");
+    }
+
+    let tps = |transactions: u64, secs: u16| transactions as f64 / secs as f64;
+    // Samples come newest first
+    let total_tps: Vec<f64> = samples
+        .iter()
+        .map(|sample| tps(sample.num_transactions, sample.sample_period_secs))
+        .collect();
+    let current = total_tps[0];
+    let average = total_tps.iter().sum::<f64>() / total_tps.len() as f64;
+    let peak = total_tps.iter().copied().fold(0.0, f64::max);
+
+    let mut table = new_table();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Slot").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Period (s)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Transactions").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("TPS").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Non-Vote TPS").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+    for (sample, total) in samples.iter().zip(&total_tps) {
+        let non_vote = sample
+            .num_non_vote_transactions
+            .map_or("-".to_string(), |count| {
+                format!("{:.0}", tps(count, sample.sample_period_secs))
+            });
+        table.add_row(vec![
+            Cell::new(sample.slot),
+            Cell::new(sample.sample_period_secs),
+            Cell::new(sample.num_transactions),
+            Cell::new(format!("{total:.0}")),
+            Cell::new(non_vote),
+        ]);
+    }
+
+    let oldest_first: Vec<f64> = total_tps.iter().rev().copied().collect();
+
+    let value = json!({
+        "current_tps": current,
+        "average_tps": average,
+        "peak_tps": peak,
+        "samples": samples,
+    });
+
+    print_output(ctx, &value, || {
+        println!("\n{}", style("CLUSTER PERFORMANCE").green().bold());
+        println!(
+            "{}  {}  {}",
+            style(format!("Current: {current:.0} TPS")).cyan().bold(),
+            style(format!("Average: {average:.0} TPS")).cyan(),
+            style(format!("Peak: {peak:.0} TPS")).cyan()
+        );
+        println!(
+            "{} {}",
+            sparkline(&oldest_first),
+            style("(oldest → newest)").dim()
+        );
+        println!("{table}");
+    })
+}
+
+async fn fetch_leader_schedule(
+    ctx: &ScillaContext,
+    identity: Option<Pubkey>,
+) -> anyhow::Result<()> {
+    let (epoch_info, slot_time_ms) =
+        tokio::join!(ctx.rpc().get_epoch_info(), average_slot_time_ms(ctx));
+    let epoch_info = epoch_info?;
+
+    let schedule = ctx
+        .rpc()
+        .get_leader_schedule_with_config(
+            Some(epoch_info.absolute_slot),
+            RpcLeaderScheduleConfig {
+                identity: identity.map(|identity| identity.to_string()),
+                commitment: Some(ctx.rpc().commitment()),
+            },
+        )
+        .await?
+        .ok_or_else(|| anyhow!("No leader schedule for epoch {}", epoch_info.epoch))?;
+
+    match identity {
+        Some(identity) => {
+            let slots = schedule
+                .get(&identity.to_string())
+                .cloned()
+                .unwrap_or_default();
+            print_identity_schedule(ctx, &identity, &epoch_info, slot_time_ms, &slots)
+        }
+        None => print_schedule_summary(ctx, &epoch_info, schedule),
+    }
+}
+
+fn print_identity_schedule(
+    ctx: &ScillaContext,
+    identity: &Pubkey,
+    epoch_info: &EpochInfo,
+    slot_time_ms: f64,
+    slots: &[usize],
+) -> anyhow::Result<()> {
+    let first_slot = epoch_info.absolute_slot - epoch_info.slot_index;
+    let now = chrono::Utc::now();
+    // Estimated from the current slot, backwards for slots already past
+    let estimated_time = |slot_index: usize| {
+        let offset_slots = slot_index as i64 - epoch_info.slot_index as i64;
+        let offset_ms = (offset_slots as f64 * slot_time_ms).round() as i64;
+        (now + chrono::Duration::milliseconds(offset_ms))
+            .format("%Y-%m-%d %H:%M:%S UTC")
+            .to_string()
+    };
+
+    let runs = slot_runs(slots);
+    let upcoming = slots
+        .iter()
+        .filter(|&&slot_index| slot_index as u64 >= epoch_info.slot_index)
+        .count();
+
+    let mut table = new_table();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Slot Index").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Slots").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Estimated Time").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Status").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+    for &(first, last) in &runs {
+        let status = if (last as u64) < epoch_info.slot_index {
+            Cell::new("Past").fg(Color::DarkGrey)
+        } else {
+            Cell::new("Upcoming").fg(Color::Green)
+        };
+        table.add_row(vec![
+            Cell::new(format!("{first}-{last}")),
+            Cell::new(format!(
+                "{}-{}",
+                first_slot + first as u64,
+                first_slot + last as u64
+            )),
+            Cell::new(estimated_time(first)),
+            status,
+        ]);
+    }
+
+    let value = json!({
+        "identity": identity.to_string(),
+        "epoch": epoch_info.epoch,
+        "first_slot": first_slot,
+        "leader_slots": slots.len(),
+        "upcoming_slots": upcoming,
+        "slot_indices": slots,
+        "slots": slots.iter().map(|&slot_index| json!({
+            "slot_index": slot_index,
+            "slot": first_slot + slot_index as u64,
+            "estimated_time": estimated_time(slot_index),
+        })).collect::<Vec<_>>(),
+    });
+
+    print_output(ctx, &value, || {
+        if slots.is_empty() {
+            println!(
+                "\n{}",
+                style(format!(
+                    "{identity} has no leader slots in epoch {}.",
+                    epoch_info.epoch
+                ))
+                .yellow()
+            );
+            return;
+        }
+
+        println!(
+            "\n{}",
+            style(format!("LEADER SCHEDULE, EPOCH {}", epoch_info.epoch))
+                .green()
+                .bold()
+        );
+        println!("{table}");
+        println!(
+            "{}",
+            style(format!(
+                "{} leader slots, {upcoming} still to come. Times assume {slot_time_ms:.0}ms \
+                 slots.",
+                slots.len()
+            ))
+            .dim()
+        );
+    })
+}
+
+fn print_schedule_summary(
+    ctx: &ScillaContext,
+    epoch_info: &EpochInfo,
+    schedule: RpcLeaderSchedule,
+) -> anyhow::Result<()> {
+    let mut leaders: Vec<(String, usize)> = schedule
+        .into_iter()
+        .map(|(identity, slots)| (identity, slots.len()))
+        .collect();
+    leaders.sort_by_key(|(_, slots)| Reverse(*slots));
+    let total_slots: usize = leaders.iter().map(|(_, slots)| slots).sum();
+    let shown = &leaders[..DEFAULT_VALIDATOR_LIMIT.min(leaders.len())];
+    let share = |slots: usize| slots as f64 / total_slots.max(1) as f64 * 100.0;
+
+    let mut table = new_table();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("#").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Identity").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Leader Slots").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Share").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+    for (idx, (identity, slots)) in shown.iter().enumerate() {
+        table.add_row(vec![
+            Cell::new(idx + 1),
+            Cell::new(identity),
+            Cell::new(slots),
+            Cell::new(format!("{:.2}%", share(*slots))),
+        ]);
+    }
+
+    let value = json!({
+        "epoch": epoch_info.epoch,
+        "leaders": leaders.len(),
+        "total_slots": total_slots,
+        "leader_slots": leaders
+            .iter()
+            .map(|(identity, slots)| json!({ "identity": identity, "slots": slots }))
+            .collect::<Vec<_>>(),
+    });
+
+    print_output(ctx, &value, || {
+        println!(
+            "\n{}",
+            style(format!(
+                "TOP {} OF {} LEADERS, EPOCH {}",
+                shown.len(),
+                leaders.len(),
+                epoch_info.epoch
+            ))
+            .green()
+            .bold()
+        );
+        println!("{table}");
+    })
+}
+
+async fn fetch_block_production(
+    ctx: &ScillaContext,
+    identity: &Pubkey,
+    range: Option<RpcBlockProductionConfigRange>,
+) -> anyhow::Result<()> {
+    if let Some(range) = &range
+        && range
+            .last_slot
+            .is_some_and(|last_slot| last_slot < range.first_slot)
+    {
+        bail!("The last slot can't come before the first slot");
+    }
+
+    let production = ctx
+        .rpc()
+        .get_block_production_with_config(RpcBlockProductionConfig {
+            identity: Some(identity.to_string()),
+            range,
+            commitment: Some(ctx.rpc().commitment()),
+        })
+        .await?
+        .value;
+
+    let (leader_slots, blocks_produced) = production
+        .by_identity
+        .get(&identity.to_string())
+        .copied()
+        .unwrap_or_default();
+    let skipped_slots = leader_slots.saturating_sub(blocks_produced);
+    let skip_rate = match leader_slots {
+        0 => 0.0,
+        _ => skipped_slots as f64 / leader_slots as f64 * 100.0,
+    };
+    let high_skip_rate = skip_rate > HIGH_SKIP_RATE_PCT;
+
+    let skip_rate_cell = Cell::new(format!("{skip_rate:.2}%"));
+    let skip_rate_cell = if high_skip_rate {
+        skip_rate_cell.fg(Color::Red)
+    } else {
+        skip_rate_cell
+    };
+
+    let mut table = new_table();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![Cell::new("Identity"), Cell::new(identity)])
+        .add_row(vec![
+            Cell::new("Slot Range"),
+            Cell::new(format!(
+                "{}-{}",
+                production.range.first_slot, production.range.last_slot
+            )),
+        ])
+        .add_row(vec![Cell::new("Leader Slots"), Cell::new(leader_slots)])
+        .add_row(vec![
+            Cell::new("Blocks Produced"),
+            Cell::new(blocks_produced),
+        ])
+        .add_row(vec![Cell::new("Skipped Slots"), Cell::new(skipped_slots)])
+        .add_row(vec![Cell::new("Skip Rate"), skip_rate_cell]);
+
+    let value = json!({
+        "identity": identity.to_string(),
+        "first_slot": production.range.first_slot,
+        "last_slot": production.range.last_slot,
+        "leader_slots": leader_slots,
+        "blocks_produced": blocks_produced,
+        "skipped_slots": skipped_slots,
+        "skip_rate_pct": skip_rate,
+    });
+
+    print_output(ctx, &value, || {
+        println!("\n{}", style("BLOCK PRODUCTION").green().bold());
+        println!("{table}");
+
+        if leader_slots == 0 {
+            println!(
+                "{}",
+                style("No leader slots in this range have passed yet.").dim()
+            );
+        } else if high_skip_rate {
+            println!(
+                "{}",
+                style(format!(
+                    "⚠ Skip rate is above {HIGH_SKIP_RATE_PCT:.0}%. Check the node is caught up \
+                     and producing blocks."
+                ))
+                .red()
+            );
+        }
+    })
 }
 
 async fn fetch_supply_info(ctx: &ScillaContext) -> anyhow::Result<()> {
-    let supply = ctx.rpc().supply().await?;
+    let (supply, inflation) = try_join!(ctx.rpc().supply(), ctx.rpc().get_inflation_rate())?;
 
-    let total_sol = (supply.value.total as f64).div(LAMPORTS_PER_SOL as f64);
-    let circulating_sol = (supply.value.circulating as f64).div(LAMPORTS_PER_SOL as f64);
-    let non_circulating_sol = (supply.value.non_circulating as f64).div(LAMPORTS_PER_SOL as f64);
+    let total_sol = lamports_to_sol(supply.value.total);
+    let circulating_sol = lamports_to_sol(supply.value.circulating);
+    let non_circulating_sol = lamports_to_sol(supply.value.non_circulating);
     let circulating_pct = (circulating_sol / total_sol) * 100.0;
 
-    let mut table = Table::new();
+    let mut table = new_table();
     table
         .load_preset(UTF8_FULL)
         .set_header(vec![
@@ -284,15 +1308,27 @@ async fn fetch_supply_info(ctx: &ScillaContext) -> anyhow::Result<()> {
             Cell::new(format!("{:.2}%", 100.0 - circulating_pct)),
         ]);
 
-    println!("\n{}", style("SUPPLY INFORMATION").green().bold());
-    println!("{table}");
+    let value = json!({
+        "total_lamports": supply.value.total,
+        "circulating_lamports": supply.value.circulating,
+        "non_circulating_lamports": supply.value.non_circulating,
+        "total_sol": total_sol,
+        "circulating_sol": circulating_sol,
+        "non_circulating_sol": non_circulating_sol,
+        "inflation": inflation,
+    });
 
-    Ok(())
+    print_output(ctx, &value, || {
+        println!("\n{}", style("SUPPLY INFORMATION").green().bold());
+        println!("{table}");
+
+        println!("\n{}", style("INFLATION INFORMATION").green().bold());
+        println!("{}", inflation_table(&inflation));
+    })
 }
 
-async fn fetch_inflation_info(ctx: &ScillaContext) -> anyhow::Result<()> {
-    let inflation = ctx.rpc().get_inflation_rate().await?;
-    let mut table = Table::new();
+fn inflation_table(inflation: &RpcInflationRate) -> Table {
+    let mut table = new_table();
     table
         .load_preset(UTF8_FULL)
         .set_header(vec![
@@ -315,17 +1351,22 @@ async fn fetch_inflation_info(ctx: &ScillaContext) -> anyhow::Result<()> {
             Cell::new("Foundation Inflation"),
             Cell::new(format!("{:.4}%", inflation.foundation * 100.0)),
         ]);
+    table
+}
 
-    println!("\n{}", style("INFLATION INFORMATION").green().bold());
-    println!("{table}");
+async fn fetch_inflation_info(ctx: &ScillaContext) -> anyhow::Result<()> {
+    let inflation = ctx.rpc().get_inflation_rate().await?;
 
-    Ok(())
+    print_output(ctx, &inflation, || {
+        println!("\n{}", style("INFLATION INFORMATION").green().bold());
+        println!("{}", inflation_table(&inflation));
+    })
 }
 
 async fn fetch_cluster_version(ctx: &ScillaContext) -> anyhow::Result<()> {
     let version = ctx.rpc().get_version().await?;
 
-    let mut table = Table::new();
+    let mut table = new_table();
     table
         .load_preset(UTF8_FULL)
         .set_header(vec![
@@ -344,8 +1385,160 @@ async fn fetch_cluster_version(ctx: &ScillaContext) -> anyhow::Result<()> {
         ]);
     }
 
-    println!("\n{}", style("CLUSTER VERSION").green().bold());
-    println!("{table}");
+    print_output(ctx, &version, || {
+        println!("\n{}", style("CLUSTER VERSION").green().bold());
+        println!("{table}");
+    })
+}
+
+async fn fetch_node_health(ctx: &ScillaContext) -> anyhow::Result<()> {
+    let (health, version) = tokio::join!(ctx.rpc().get_health(), ctx.rpc().get_version());
+    let version = version?;
+
+    // A lagging node answers getHealth with an error carrying how far behind
+    // it is, when it knows
+    let slots_behind = match health {
+        Ok(()) => None,
+        Err(err) => match err.kind() {
+            ClientErrorKind::RpcError(RpcError::RpcResponseError {
+                data: RpcResponseErrorData::NodeUnhealthy { num_slots_behind },
+                ..
+            }) => Some(*num_slots_behind),
+            _ => return Err(err.into()),
+        },
+    };
+
+    let status = match slots_behind {
+        None => Cell::new("OK").fg(Color::Green),
+        Some(Some(slots)) => Cell::new(format!("Behind by {slots} slots")).fg(Color::Red),
+        Some(None) => Cell::new("Behind").fg(Color::Red),
+    };
+
+    let mut table = new_table();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![Cell::new("RPC URL"), Cell::new(ctx.rpc().url())])
+        .add_row(vec![Cell::new("Health"), status])
+        .add_row(vec![
+            Cell::new("Solana Core"),
+            Cell::new(version.solana_core.clone()),
+        ]);
+
+    if let Some(feature_set) = version.feature_set {
+        table.add_row(vec![
+            Cell::new("Feature Set"),
+            Cell::new(format!("{feature_set}")),
+        ]);
+    }
+
+    let value = json!({
+        "rpc_url": ctx.rpc().url(),
+        "healthy": slots_behind.is_none(),
+        "slots_behind": slots_behind.flatten(),
+        "solana_core": version.solana_core,
+        "feature_set": version.feature_set,
+    });
+
+    print_output(ctx, &value, || {
+        println!("\n{}", style("NODE HEALTH").green().bold());
+        println!("{table}");
+    })
+}
+
+async fn process_ping(
+    ctx: &ScillaContext,
+    count: usize,
+    spinner: ProgressBar,
+) -> anyhow::Result<()> {
+    if count == 0 {
+        bail!("Ping count must be greater than zero");
+    }
+
+    let mut latencies = Vec::with_capacity(count);
+    let mut failures = 0usize;
+
+    for seq in 0..count {
+        spinner.set_message(format!("Pinging cluster… {}/{count}", seq + 1));
+
+        // A distinct amount per ping keeps signatures unique within a blockhash
+        let ix = transfer(ctx.pubkey(), ctx.pubkey(), seq as u64 + 1);
+
+        let started = Instant::now();
+        match build_and_send_tx(ctx, &[ix], &[ctx.signer()]).await {
+            Ok(_) => latencies.push(started.elapsed()),
+            Err(err) => {
+                failures += 1;
+                spinner.println(
+                    style(format!("Ping {} failed: {err}", seq + 1))
+                        .red()
+                        .to_string(),
+                );
+            }
+        }
+    }
+
+    latencies.sort();
+
+    let fmt_latency = |latency: Option<Duration>| {
+        latency
+            .map(|d| format!("{}ms", d.as_millis()))
+            .unwrap_or_else(|| "~".to_string())
+    };
+    let mean = (!latencies.is_empty())
+        .then(|| latencies.iter().sum::<Duration>() / latencies.len() as u32);
+    let success_rate = latencies.len() as f64 / count as f64 * 100.0;
+
+    let mut table = new_table();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![Cell::new("RPC URL"), Cell::new(ctx.rpc().url())])
+        .add_row(vec![Cell::new("Sent"), Cell::new(format!("{count}"))])
+        .add_row(vec![
+            Cell::new("Confirmed"),
+            Cell::new(format!("{}", latencies.len())),
+        ])
+        .add_row(vec![Cell::new("Failed"), Cell::new(format!("{failures}"))])
+        .add_row(vec![
+            Cell::new("Success Rate"),
+            Cell::new(format!("{success_rate:.1}%")),
+        ])
+        .add_row(vec![
+            Cell::new("Min"),
+            Cell::new(fmt_latency(latencies.first().copied())),
+        ])
+        .add_row(vec![Cell::new("Mean"), Cell::new(fmt_latency(mean))])
+        .add_row(vec![
+            Cell::new("p90"),
+            Cell::new(fmt_latency(percentile(&latencies, 90.0))),
+        ])
+        .add_row(vec![
+            Cell::new("Max"),
+            Cell::new(fmt_latency(latencies.last().copied())),
+        ]);
+
+    let as_millis = |latency: Option<Duration>| latency.map(|d| d.as_millis() as u64);
+    let value = json!({
+        "rpc_url": ctx.rpc().url(),
+        "sent": count,
+        "confirmed": latencies.len(),
+        "failed": failures,
+        "success_rate_pct": success_rate,
+        "min_ms": as_millis(latencies.first().copied()),
+        "mean_ms": as_millis(mean),
+        "p90_ms": as_millis(percentile(&latencies, 90.0)),
+        "max_ms": as_millis(latencies.last().copied()),
+    });
 
-    Ok(())
+    print_output(ctx, &value, || {
+        println!("\n{}", style("PING SUMMARY").green().bold());
+        println!("{table}");
+    })
 }
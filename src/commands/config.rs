@@ -1,23 +1,43 @@
 use {
     crate::{
         commands::CommandExec,
-        config::{ScillaConfig, scilla_config_path},
+        config::{Profile, ScillaConfig, SeedPhraseConfig, backup_config, scilla_config_path},
+        constants::{
+            DEFAULT_DERIVATION_PATH, DEFAULT_EDITOR, DEFAULT_TX_RETRIES, RPC_PROBE_TIMEOUT_SECS,
+        },
+        context::{ScillaContext, is_hardware_wallet_locator, set_next_commitment},
         error::ScillaResult,
-        prompt::prompt_data,
+        misc::helpers::parse_derivation_path,
+        notice,
+        prompt::{
+            prompt_confirm, prompt_data, prompt_data_with_default, prompt_select, prompt_yes_no,
+        },
+        ui::{new_table, print_output, show_spinner},
     },
-    comfy_table::{Cell, Table, presets::UTF8_FULL},
+    anyhow::{Context, bail},
+    clap::ValueEnum,
+    comfy_table::{Cell, presets::UTF8_FULL},
     console::style,
     inquire::{Confirm, Select},
+    serde_json::json,
     solana_commitment_config::CommitmentLevel,
-    std::{fmt, fs, path::PathBuf},
+    solana_rpc_client::nonblocking::rpc_client::RpcClient,
+    std::{env, fmt, fs, path::PathBuf, process, time::Duration},
 };
 
 /// Commands related to configuration like RPC_URL , KEYAPAIR_PATH etc
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, ValueEnum)]
 pub enum ConfigCommand {
     Show,
     Generate,
     Edit,
+    OpenInEditor,
+    SwitchProfile,
+    AddProfile,
+    Reset,
+    #[value(skip)]
+    NextCommitment,
+    #[value(skip)]
     GoBack,
 }
 
@@ -27,6 +47,11 @@ impl ConfigCommand {
             ConfigCommand::Show => "Displaying current Scilla configuration…",
             ConfigCommand::Generate => "Generating new Scilla configuration…",
             ConfigCommand::Edit => "Editing existing Scilla configuration…",
+            ConfigCommand::OpenInEditor => "Opening config in editor…",
+            ConfigCommand::SwitchProfile => "Switching active profile…",
+            ConfigCommand::AddProfile => "Adding a new profile…",
+            ConfigCommand::Reset => "Resetting Scilla configuration…",
+            ConfigCommand::NextCommitment => "Setting commitment for the next command…",
             ConfigCommand::GoBack => "Going back…",
         }
     }
@@ -38,6 +63,11 @@ impl fmt::Display for ConfigCommand {
             ConfigCommand::Show => "View ScillaConfig",
             ConfigCommand::Generate => "Generate ScillaConfig",
             ConfigCommand::Edit => "Edit ScillaConfig",
+            ConfigCommand::OpenInEditor => "Open ScillaConfig in $EDITOR",
+            ConfigCommand::SwitchProfile => "Switch profile",
+            ConfigCommand::AddProfile => "Add profile",
+            ConfigCommand::Reset => "Reset ScillaConfig",
+            ConfigCommand::NextCommitment => "Use another commitment for the next command",
             ConfigCommand::GoBack => "Go back",
         };
         write!(f, "{command}")
@@ -71,6 +101,22 @@ impl ConfigField {
     }
 }
 
+/// What to leave in place of a config being reset
+#[derive(Debug, Clone, Copy)]
+enum ResetMode {
+    Defaults,
+    Delete,
+}
+
+impl fmt::Display for ResetMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResetMode::Defaults => write!(f, "Rewrite with the default settings"),
+            ResetMode::Delete => write!(f, "Delete it and set up again on next start"),
+        }
+    }
+}
+
 fn get_commitment_levels() -> Vec<CommitmentLevel> {
     vec![
         CommitmentLevel::Processed,
@@ -80,10 +126,10 @@ fn get_commitment_levels() -> Vec<CommitmentLevel> {
 }
 
 impl ConfigCommand {
-    pub async fn process_command(&self) -> ScillaResult<()> {
+    pub async fn process_command(&self, ctx: &ScillaContext) -> ScillaResult<()> {
         match self {
             ConfigCommand::Show => {
-                show_config().await?;
+                show_config(ctx).await?;
             }
             ConfigCommand::Generate => {
                 generate_config().await?;
@@ -91,6 +137,33 @@ impl ConfigCommand {
             ConfigCommand::Edit => {
                 edit_config().await?;
             }
+            ConfigCommand::OpenInEditor => {
+                open_config_in_editor()?;
+            }
+            ConfigCommand::SwitchProfile => {
+                switch_profile().await?;
+            }
+            ConfigCommand::AddProfile => {
+                add_profile().await?;
+            }
+            ConfigCommand::Reset => {
+                reset_config()?;
+            }
+            ConfigCommand::NextCommitment => {
+                let commitment = prompt_select(Select::new(
+                    "Select commitment level:",
+                    get_commitment_levels(),
+                ))?;
+                set_next_commitment(Some(commitment));
+                notice!(
+                    "{}",
+                    style(format!(
+                        "✓ The next command runs at {commitment} commitment, then the config's \
+                         applies again"
+                    ))
+                    .green()
+                );
+            }
             ConfigCommand::GoBack => return Ok(CommandExec::GoBack),
         };
 
@@ -98,17 +171,57 @@ impl ConfigCommand {
     }
 }
 
-async fn show_config() -> anyhow::Result<()> {
+async fn show_config(ctx: &ScillaContext) -> anyhow::Result<()> {
     let config = ScillaConfig::load().await?;
-
-    let mut table = Table::new();
+    let tx_retries = config.tx_retries.unwrap_or(DEFAULT_TX_RETRIES);
+    let websocket_confirmation = config.websocket_confirmation.unwrap_or(false);
+
+    let value = json!({
+        "config_file": scilla_config_path().display().to_string(),
+        "active_profile": config.active_profile_name(),
+        "profiles": config.profiles.keys().collect::<Vec<_>>(),
+        "rpc_url": config.rpc_url,
+        "commitment": config.commitment_level.to_string(),
+        "keypair_path": config.keypair_path.display().to_string(),
+        "derivation_path": config
+            .seed_phrase
+            .as_ref()
+            .map(|seed_phrase| seed_phrase.derivation_path.clone()),
+        "priority_fee_micro_lamports": config.priority_fee_micro_lamports,
+        "compute_unit_limit": config.compute_unit_limit,
+        "tx_retries": tx_retries,
+        "websocket_confirmation": websocket_confirmation,
+        "explorer": config.explorer,
+        "timestamps": config.timestamps,
+    });
+
+    let mut table = new_table();
     table
         .load_preset(UTF8_FULL)
         .set_header(vec![
             Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
             Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
         ])
-        .add_row(vec![Cell::new("RPC URL"), Cell::new(config.rpc_url)])
+        .add_row(vec![
+            Cell::new("Config File"),
+            Cell::new(scilla_config_path().display().to_string()),
+        ])
+        .add_row(vec![
+            Cell::new("Active Profile"),
+            Cell::new(config.active_profile_name()),
+        ])
+        .add_row(vec![
+            Cell::new("Profiles"),
+            Cell::new(
+                config
+                    .profiles
+                    .keys()
+                    .cloned()
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+        ])
+        .add_row(vec![Cell::new("RPC URL"), Cell::new(&config.rpc_url)])
         .add_row(vec![
             Cell::new("Commitment Level"),
             Cell::new(config.commitment_level.to_string()),
@@ -116,103 +229,134 @@ async fn show_config() -> anyhow::Result<()> {
         .add_row(vec![
             Cell::new("Keypair Path"),
             Cell::new(config.keypair_path.display().to_string()),
+        ])
+        .add_row(vec![
+            Cell::new("Seed Phrase Derivation"),
+            Cell::new(
+                config
+                    .seed_phrase
+                    .as_ref()
+                    .map_or("None (using keypair file)".to_string(), |seed_phrase| {
+                        seed_phrase.derivation_path.clone()
+                    }),
+            ),
+        ])
+        .add_row(vec![
+            Cell::new("Priority Fee (micro-lamports/CU)"),
+            Cell::new(
+                config
+                    .priority_fee_micro_lamports
+                    .map_or("None".to_string(), |fee| fee.to_string()),
+            ),
+        ])
+        .add_row(vec![
+            Cell::new("Compute Unit Limit"),
+            Cell::new(
+                config
+                    .compute_unit_limit
+                    .map_or("Default".to_string(), |limit| limit.to_string()),
+            ),
+        ])
+        .add_row(vec![
+            Cell::new("Transaction Retries"),
+            Cell::new(tx_retries.to_string()),
+        ])
+        .add_row(vec![
+            Cell::new("Confirmation"),
+            Cell::new(if websocket_confirmation {
+                "WebSocket (polling fallback)"
+            } else {
+                "Polling"
+            }),
+        ])
+        .add_row(vec![
+            Cell::new("Explorer"),
+            Cell::new(config.explorer.to_string()),
+        ])
+        .add_row(vec![
+            Cell::new("Timestamps"),
+            Cell::new(config.timestamps.to_string()),
         ]);
 
-    println!("\n{}", style("SCILLA CONFIG").green().bold());
-    println!("{}", table);
-
-    Ok(())
+    print_output(ctx, &value, || {
+        println!("\n{}", style("SCILLA CONFIG").green().bold());
+        println!("{}", table);
+    })
 }
 
 pub async fn generate_config() -> anyhow::Result<()> {
     // Check if config already exists
     let config_path = scilla_config_path();
     if config_path.exists() {
-        println!(
+        notice!(
             "\n{}",
             style("⚠ Config file already exists!").yellow().bold()
         );
-        println!(
+        notice!(
             "{}",
             style(format!("Location: {}", config_path.display())).cyan()
         );
-        println!(
+        notice!(
             "{}",
             style("Use the 'Edit' option to modify your existing config.").cyan()
         );
         return Ok(());
     }
 
-    println!("\n{}", style("Generate New Config").green().bold());
+    notice!("\n{}", style("Generate New Config").green().bold());
 
     // Ask if user wants to use defaults
-    let use_defaults = Confirm::new("Use default config? (Devnet RPC, Confirmed commitment)")
-        .with_default(true)
-        .prompt()?;
+    let use_defaults = prompt_yes_no(
+        Confirm::new("Use default config? (Devnet RPC, Confirmed commitment)").with_default(true),
+    )?;
 
-    let config = if use_defaults {
+    let mut config = if use_defaults {
         let config = ScillaConfig::default();
 
-        println!("\n{}", style("Using default configuration:").cyan());
-        println!("  RPC: {}", config.rpc_url);
-        println!("  Commitment: {:?}", config.commitment_level);
-        println!("  Keypair: {}", config.keypair_path.display());
+        notice!("\n{}", style("Using default configuration:").cyan());
+        notice!("  RPC: {}", config.rpc_url);
+        notice!("  Commitment: {:?}", config.commitment_level);
+        notice!("  Keypair: {}", config.keypair_path.display());
 
         config
     } else {
-        let rpc_url: String = prompt_data("Enter RPC URL:")?;
-
-        let commitment_level =
-            Select::new("Select commitment level:", get_commitment_levels()).prompt()?;
-
-        let default_keypair_path = ScillaConfig::default().keypair_path;
-
-        let keypair_path = loop {
-            let keypair_input: PathBuf = prompt_data(&format!(
-                "Enter keypair path (press Enter to use default: {}): ",
-                default_keypair_path.display()
-            ))?;
-
-            if keypair_input.as_os_str().is_empty() {
-                break default_keypair_path.clone();
-            }
-
-            if !keypair_input.exists() {
-                println!(
-                    "{}",
-                    style(format!(
-                        "Keypair file not found at: {}",
-                        keypair_input.display()
-                    ))
-                    .red()
-                );
-                continue;
-            }
-
-            break keypair_input;
+        let rpc_url = prompt_rpc_url().await?;
+
+        let commitment_level = prompt_select(Select::new(
+            "Select commitment level:",
+            get_commitment_levels(),
+        ))?;
+
+        let use_seed_phrase = prompt_yes_no(
+            Confirm::new("Derive the keypair from a seed phrase instead of a keypair file?")
+                .with_default(false),
+        )?;
+
+        let (keypair_path, seed_phrase) = if use_seed_phrase {
+            (
+                ScillaConfig::default().keypair_path,
+                Some(prompt_seed_phrase_config()?),
+            )
+        } else {
+            (prompt_keypair_path()?, None)
         };
 
         ScillaConfig {
             rpc_url,
             commitment_level,
             keypair_path,
+            seed_phrase,
+            ..ScillaConfig::default()
         }
     };
 
-    // Write config
-    let config_path = scilla_config_path();
-    if let Some(parent) = config_path.parent() {
-        fs::create_dir_all(parent)?;
-    }
+    config.save()?;
 
-    let toml_string = toml::to_string_pretty(&config)?;
-    fs::write(&config_path, toml_string)?;
-
-    println!(
+    notice!(
         "\n{}",
         style("✓ Config generated successfully!").green().bold()
     );
-    println!(
+    notice!(
         "{}",
         style(format!("Saved to: {}", config_path.display())).cyan()
     );
@@ -221,18 +365,22 @@ pub async fn generate_config() -> anyhow::Result<()> {
 }
 
 async fn edit_config() -> anyhow::Result<()> {
-    let mut config = ScillaConfig::load().await?;
+    let mut config = ScillaConfig::load_stored().await?;
 
-    println!("\n{}", style("Edit Config").green().bold());
+    notice!(
+        "\n{} {}",
+        style("Edit Config").green().bold(),
+        style(format!("(profile: {})", config.active_profile_name())).dim()
+    );
 
     // Show current configuration
-    println!("\n{} {}", style("Current RPC URL:").cyan(), config.rpc_url);
-    println!(
+    notice!("\n{} {}", style("Current RPC URL:").cyan(), config.rpc_url);
+    notice!(
         "{} {:?}",
         style("Current Commitment Level:").cyan(),
         config.commitment_level
     );
-    println!(
+    notice!(
         "{} {}",
         style("Current Keypair Path:").cyan(),
         config.keypair_path.display()
@@ -240,60 +388,312 @@ async fn edit_config() -> anyhow::Result<()> {
 
     // Prompt user to select which field to edit
     let field_options = ConfigField::all();
-    let selected_field = Select::new("\nSelect field to edit:", field_options).prompt()?;
+    let selected_field = prompt_select(Select::new("\nSelect field to edit:", field_options))?;
 
     match selected_field {
         ConfigField::RpcUrl => {
-            config.rpc_url = prompt_data("Enter RPC URL:")?;
+            config.rpc_url = prompt_rpc_url().await?;
         }
         ConfigField::CommitmentLevel => {
-            config.commitment_level =
-                Select::new("Select commitment level:", get_commitment_levels()).prompt()?;
+            config.commitment_level = prompt_select(Select::new(
+                "Select commitment level:",
+                get_commitment_levels(),
+            ))?;
         }
         ConfigField::KeypairPath => {
-            let default_keypair_path = ScillaConfig::default().keypair_path;
+            config.keypair_path = prompt_keypair_path()?;
+            config.seed_phrase = None;
+        }
+    }
 
-            loop {
-                let keypair_input: PathBuf = prompt_data(&format!(
-                    "Enter new keypair path (leave empty to use default: {}): ",
-                    default_keypair_path.display()
-                ))?;
+    config.save()?;
+    let config_path = scilla_config_path();
 
-                if keypair_input.as_os_str().is_empty() {
-                    config.keypair_path = default_keypair_path.clone();
-                    break;
-                }
+    notice!(
+        "\n{}",
+        style("✓ Config updated successfully!").green().bold()
+    );
+    notice!(
+        "{}",
+        style(format!("Saved to: {}", config_path.display())).cyan()
+    );
 
-                if !keypair_input.exists() {
-                    println!(
-                        "{}",
-                        style(format!(
-                            "Keypair file not found at: {}",
-                            keypair_input.display()
-                        ))
-                        .red()
-                    );
-                    continue;
-                }
+    Ok(())
+}
+
+/// Opens the config file in `$VISUAL` or `$EDITOR` and checks it still parses
+/// once the editor exits. A broken file is reopened, or the previous contents
+/// restored, so the session never reloads from it.
+fn open_config_in_editor() -> anyhow::Result<()> {
+    let config_path = scilla_config_path();
+    if !config_path.exists() {
+        bail!("No config at {}. Generate one first", config_path.display());
+    }
+    let original = fs::read_to_string(&config_path)?;
+
+    let editor = env::var("VISUAL")
+        .or_else(|_| env::var("EDITOR"))
+        .unwrap_or_else(|_| DEFAULT_EDITOR.to_string());
+    // Allows editors that need flags, e.g. `code --wait`
+    let mut editor_args = editor.split_whitespace();
+    let program = editor_args.next().unwrap_or(DEFAULT_EDITOR);
 
-                config.keypair_path = keypair_input;
-                break;
+    notice!(
+        "{}",
+        style(format!("Config file: {}", config_path.display())).cyan()
+    );
+
+    loop {
+        let status = process::Command::new(program)
+            .args(editor_args.clone())
+            .arg(&config_path)
+            .status()
+            .with_context(|| format!("Failed to launch editor `{editor}`"))?;
+        if !status.success() {
+            bail!("Editor `{editor}` exited with {status}");
+        }
+
+        let err = match ScillaConfig::load_from_path(&config_path) {
+            Ok(_) => {
+                notice!("\n{}", style("✓ Config is valid").green().bold());
+                return Ok(());
             }
+            Err(err) => err,
+        };
+
+        notice!(
+            "\n{}",
+            style(format!("✗ The config no longer parses: {err}"))
+                .red()
+                .bold()
+        );
+        let reopen =
+            prompt_yes_no(Confirm::new("Open the editor again to fix it?").with_default(true))?;
+        if !reopen {
+            fs::write(&config_path, &original)?;
+            notice!("{}", style("Restored the previous config.").yellow());
+            return Ok(());
         }
     }
+}
 
-    // Write updated config
+/// Backs up the config file, then rewrites it with defaults or deletes it.
+/// Works on files that no longer parse.
+fn reset_config() -> anyhow::Result<()> {
     let config_path = scilla_config_path();
-    let toml_string = toml::to_string_pretty(&config)?;
-    fs::write(&config_path, toml_string)?;
+    if !config_path.exists() {
+        bail!("No config at {}. Nothing to reset", config_path.display());
+    }
+
+    let mode = prompt_select(Select::new(
+        "Reset the config how?",
+        vec![ResetMode::Defaults, ResetMode::Delete],
+    ))?;
+    if !prompt_confirm(&format!(
+        "Reset {}? A backup is kept.",
+        config_path.display()
+    ))? {
+        notice!("{}", style("Config left unchanged.").yellow());
+        return Ok(());
+    }
+
+    let backup = backup_config(&config_path)?;
+    match mode {
+        ResetMode::Defaults => ScillaConfig::default().save()?,
+        ResetMode::Delete => fs::remove_file(&config_path)?,
+    }
+
+    notice!("\n{}", style("✓ Config reset").green().bold());
+    notice!(
+        "{}",
+        style(format!("Previous config backed up to {}", backup.display())).cyan()
+    );
+
+    Ok(())
+}
+
+/// Prompts for an RPC URL and checks that it answers. An unreachable URL can
+/// still be kept so configs can be written offline.
+async fn prompt_rpc_url() -> anyhow::Result<String> {
+    loop {
+        let rpc_url: String = prompt_data("Enter RPC URL:")?;
+        let rpc_url = rpc_url.trim().to_string();
+
+        let probe = show_spinner("Checking RPC endpoint…", probe_rpc_url(&rpc_url)).await;
+
+        match probe {
+            Ok(version) => {
+                notice!(
+                    "{}",
+                    style(format!("✓ RPC reachable (solana-core {version})")).green()
+                );
+                return Ok(rpc_url);
+            }
+            Err(_) => {
+                notice!(
+                    "{}",
+                    style(format!("⚠ Could not reach {rpc_url}"))
+                        .yellow()
+                        .bold()
+                );
+
+                let retry =
+                    prompt_yes_no(Confirm::new("Re-enter the RPC URL?").with_default(true))?;
+                if !retry {
+                    return Ok(rpc_url);
+                }
+            }
+        }
+    }
+}
+
+async fn probe_rpc_url(rpc_url: &str) -> anyhow::Result<String> {
+    let client = RpcClient::new_with_timeout(
+        rpc_url.to_string(),
+        Duration::from_secs(RPC_PROBE_TIMEOUT_SECS),
+    );
+    let version = client.get_version().await?;
+    Ok(version.solana_core)
+}
 
-    println!(
+/// Asks how to derive the keypair from a seed phrase. The phrase itself is only
+/// entered when Scilla starts.
+fn prompt_seed_phrase_config() -> anyhow::Result<SeedPhraseConfig> {
+    let derivation_path = loop {
+        let path: String =
+            prompt_data_with_default("Enter derivation path:", DEFAULT_DERIVATION_PATH)?;
+
+        match parse_derivation_path(&path) {
+            Ok(_) => break path,
+            Err(err) => notice!("{}", style(err).red()),
+        }
+    };
+
+    let passphrase = prompt_yes_no(
+        Confirm::new("Is the seed protected by a BIP39 passphrase?").with_default(false),
+    )?;
+
+    notice!(
+        "{}",
+        style("The seed phrase is not saved. You will be asked for it when Scilla starts.").cyan()
+    );
+
+    Ok(SeedPhraseConfig {
+        derivation_path,
+        passphrase,
+    })
+}
+
+/// Prompts until an existing keypair path is entered, empty input picks the
+/// default keypair
+fn prompt_keypair_path() -> anyhow::Result<PathBuf> {
+    let default_keypair_path = ScillaConfig::default().keypair_path;
+
+    loop {
+        let keypair_input: PathBuf = prompt_data_with_default(
+            "Enter keypair path:",
+            &default_keypair_path.to_string_lossy(),
+        )?;
+
+        // The default is accepted even before the keypair has been created
+        if keypair_input != default_keypair_path
+            && !is_hardware_wallet_locator(&keypair_input)
+            && !keypair_input.exists()
+        {
+            notice!(
+                "{}",
+                style(format!(
+                    "Keypair file not found at: {}",
+                    keypair_input.display()
+                ))
+                .red()
+            );
+            continue;
+        }
+
+        return Ok(keypair_input);
+    }
+}
+
+async fn switch_profile() -> anyhow::Result<()> {
+    let mut config = ScillaConfig::load_stored().await?;
+
+    let names: Vec<String> = config.profiles.keys().cloned().collect();
+    let active_index = names
+        .iter()
+        .position(|name| name == config.active_profile_name())
+        .unwrap_or(0);
+
+    let name =
+        prompt_select(Select::new("Select profile:", names).with_starting_cursor(active_index))?;
+
+    config.switch_profile(&name)?;
+    config.save()?;
+
+    notice!(
         "\n{}",
-        style("✓ Config updated successfully!").green().bold()
+        style(format!("✓ Switched to profile '{name}'"))
+            .green()
+            .bold()
     );
-    println!(
+    notice!("  RPC: {}", config.rpc_url);
+    notice!("  Commitment: {:?}", config.commitment_level);
+    notice!("  Keypair: {}", config.keypair_path.display());
+
+    Ok(())
+}
+
+async fn add_profile() -> anyhow::Result<()> {
+    let mut config = ScillaConfig::load_stored().await?;
+
+    let name = loop {
+        let name: String = prompt_data("Enter profile name:")?;
+        let name = name.trim().to_string();
+
+        if name.is_empty() {
+            notice!("{}", style("Profile name cannot be empty").red());
+        } else if config.profiles.contains_key(&name) {
+            notice!(
+                "{}",
+                style(format!("Profile '{name}' already exists")).red()
+            );
+        } else {
+            break name;
+        }
+    };
+
+    let rpc_url = prompt_rpc_url().await?;
+    let commitment_level = prompt_select(Select::new(
+        "Select commitment level:",
+        get_commitment_levels(),
+    ))?;
+    let keypair_path = prompt_keypair_path()?;
+
+    config.profiles.insert(
+        name.clone(),
+        Profile {
+            rpc_url,
+            commitment_level,
+            keypair_path,
+        },
+    );
+
+    let activate = prompt_yes_no(
+        Confirm::new(&format!("Switch to profile '{name}' now?")).with_default(true),
+    )?;
+    if activate {
+        config.switch_profile(&name)?;
+    }
+
+    config.save()?;
+
+    notice!(
+        "\n{}",
+        style(format!("✓ Profile '{name}' added")).green().bold()
+    );
+    notice!(
         "{}",
-        style(format!("Saved to: {}", config_path.display())).cyan()
+        style(format!("Active profile: {}", config.active_profile_name())).cyan()
     );
 
     Ok(())
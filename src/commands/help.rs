@@ -0,0 +1,156 @@
+use {
+    crate::{
+        commands::{
+            CommandExec, CommandGroup, account::AccountCommand, address_book::AddressBookCommand,
+            cluster::ClusterCommand, config::ConfigCommand, program::ProgramCommand,
+            stake::StakeCommand, token::TokenCommand, transaction::TransactionCommand,
+            vote::VoteCommand,
+        },
+        context::ScillaContext,
+        error::ScillaResult,
+        prompt::prompt_select,
+        ui::{new_table, print_output},
+    },
+    clap::ValueEnum,
+    comfy_table::{Cell, presets::UTF8_FULL},
+    console::style,
+    inquire::Select,
+    serde_json::json,
+    std::fmt,
+};
+
+/// Which menus the help listing covers
+#[derive(Debug, Clone)]
+enum HelpTopic {
+    All,
+    Group(CommandGroup),
+}
+
+impl fmt::Display for HelpTopic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HelpTopic::All => write!(f, "All menus"),
+            HelpTopic::Group(group) => write!(f, "{group}"),
+        }
+    }
+}
+
+/// A command as listed in the help, with the subcommand that runs it directly
+struct HelpEntry {
+    command: String,
+    description: String,
+    usage: String,
+}
+
+/// Groups that hold commands, in menu order
+const HELP_GROUPS: [CommandGroup; 9] = [
+    CommandGroup::Account,
+    CommandGroup::Token,
+    CommandGroup::Cluster,
+    CommandGroup::Stake,
+    CommandGroup::Vote,
+    CommandGroup::Transaction,
+    CommandGroup::Program,
+    CommandGroup::AddressBook,
+    CommandGroup::ScillaConfig,
+];
+
+/// Lists the commands of one group. The description is the spinner message,
+/// which already says what the command does.
+fn entries<T: ValueEnum + fmt::Display>(
+    cli_group: &str,
+    describe: impl Fn(&T) -> &'static str,
+) -> Vec<HelpEntry> {
+    T::value_variants()
+        .iter()
+        .filter_map(|command| {
+            let name = command.to_possible_value()?;
+            Some(HelpEntry {
+                command: command.to_string(),
+                description: describe(command).trim_end_matches('…').to_string(),
+                usage: format!("scilla {cli_group} {}", name.get_name()),
+            })
+        })
+        .collect()
+}
+
+fn group_entries(group: &CommandGroup) -> Vec<HelpEntry> {
+    let Some(cli_group) = group.cli_name() else {
+        return Vec::new();
+    };
+
+    match group {
+        CommandGroup::Account => entries(cli_group, AccountCommand::spinner_msg),
+        CommandGroup::Token => entries(cli_group, TokenCommand::spinner_msg),
+        CommandGroup::Cluster => entries(cli_group, ClusterCommand::spinner_msg),
+        CommandGroup::Stake => entries(cli_group, StakeCommand::spinner_msg),
+        CommandGroup::Vote => entries(cli_group, VoteCommand::spinner_msg),
+        CommandGroup::Transaction => entries(cli_group, TransactionCommand::spinner_msg),
+        CommandGroup::Program => entries(cli_group, ProgramCommand::spinner_msg),
+        CommandGroup::AddressBook => entries(cli_group, AddressBookCommand::spinner_msg),
+        CommandGroup::ScillaConfig => entries(cli_group, ConfigCommand::spinner_msg),
+        CommandGroup::Recent | CommandGroup::Help | CommandGroup::Exit => Vec::new(),
+    }
+}
+
+pub fn process_help(ctx: &ScillaContext) -> ScillaResult<()> {
+    let mut topics = vec![HelpTopic::All];
+    topics.extend(HELP_GROUPS.into_iter().map(HelpTopic::Group));
+    let topic = prompt_select(Select::new("Show help for:", topics))?;
+
+    let groups = match topic {
+        HelpTopic::All => HELP_GROUPS.to_vec(),
+        HelpTopic::Group(group) => vec![group],
+    };
+    let sections: Vec<_> = groups
+        .into_iter()
+        .map(|group| {
+            let entries = group_entries(&group);
+            (group, entries)
+        })
+        .collect();
+
+    let value: Vec<_> = sections
+        .iter()
+        .flat_map(|(group, entries)| {
+            entries.iter().map(move |entry| {
+                json!({
+                    "group": group.to_string(),
+                    "command": entry.command,
+                    "description": entry.description,
+                    "usage": entry.usage,
+                })
+            })
+        })
+        .collect();
+
+    print_output(ctx, &value, || {
+        for (group, entries) in &sections {
+            let mut table = new_table();
+            table.load_preset(UTF8_FULL).set_header(vec![
+                Cell::new("Command").add_attribute(comfy_table::Attribute::Bold),
+                Cell::new("What it does").add_attribute(comfy_table::Attribute::Bold),
+                Cell::new("Run directly").add_attribute(comfy_table::Attribute::Bold),
+            ]);
+            for entry in entries {
+                table.add_row(vec![
+                    Cell::new(&entry.command),
+                    Cell::new(&entry.description),
+                    Cell::new(&entry.usage),
+                ]);
+            }
+
+            println!(
+                "\n{}",
+                style(group.to_string().to_uppercase()).green().bold()
+            );
+            println!("{}", table);
+        }
+        println!(
+            "{}",
+            style("Commands run directly take their prompt answers as extra arguments.").dim()
+        );
+    })?;
+
+    Ok(CommandExec::Process(()))
+}
@@ -1,12 +1,16 @@
 use {
     crate::{
         commands::{
-            account::AccountCommand, cluster::ClusterCommand, config::ConfigCommand,
-            stake::StakeCommand, transaction::TransactionCommand, vote::VoteCommand,
+            account::AccountCommand, address_book::AddressBookCommand, cluster::ClusterCommand,
+            config::ConfigCommand, help::process_help, program::ProgramCommand,
+            stake::StakeCommand, token::TokenCommand, transaction::TransactionCommand,
+            vote::VoteCommand,
         },
         context::ScillaContext,
         error::ScillaResult,
+        notice,
     },
+    clap::ValueEnum,
     console::style,
     std::{
         fmt,
@@ -15,9 +19,14 @@ use {
 };
 
 pub mod account;
+pub mod address_book;
 pub mod cluster;
 pub mod config;
+pub mod help;
+pub mod nonce;
+pub mod program;
 pub mod stake;
+pub mod token;
 pub mod transaction;
 pub mod vote;
 
@@ -29,7 +38,9 @@ pub enum CommandExec<T> {
 
 impl<T> Termination for CommandExec<T> {
     fn report(self) -> std::process::ExitCode {
-        println!("{}", style("Goodbye 👋").dim());
+        if let CommandExec::Exit = self {
+            notice!("{}", style("Goodbye 👋").dim());
+        }
         ExitCode::SUCCESS
     }
 }
@@ -39,9 +50,13 @@ pub enum Command {
     Cluster(ClusterCommand),
     Stake(StakeCommand),
     Account(AccountCommand),
+    Token(TokenCommand),
     Vote(VoteCommand),
     Transaction(TransactionCommand),
+    Program(ProgramCommand),
+    AddressBook(AddressBookCommand),
     ScillaConfig(ConfigCommand),
+    Help,
     Exit,
 }
 
@@ -51,36 +66,99 @@ impl Command {
             Command::Cluster(cluster_command) => cluster_command.process_command(ctx).await,
             Command::Stake(stake_command) => stake_command.process_command(ctx).await,
             Command::Account(account_command) => account_command.process_command(ctx).await,
+            Command::Token(token_command) => token_command.process_command(ctx).await,
             Command::Vote(vote_command) => vote_command.process_command(ctx).await,
             Command::Transaction(transaction_command) => {
                 transaction_command.process_command(ctx).await
             }
-            Command::ScillaConfig(config_command) => config_command.process_command().await,
+            Command::Program(program_command) => program_command.process_command(ctx).await,
+            Command::AddressBook(address_book_command) => {
+                address_book_command.process_command(ctx).await
+            }
+            Command::ScillaConfig(config_command) => config_command.process_command(ctx).await,
+            Command::Help => process_help(ctx),
             Command::Exit => Ok(CommandExec::Exit),
         }
     }
+
+    /// Group and command name that run this command directly, `None` for
+    /// commands only reachable from the menus
+    pub fn cli_words(&self) -> Option<Vec<String>> {
+        let (group, name) = match self {
+            Command::Cluster(command) => (CommandGroup::Cluster, command.to_possible_value()),
+            Command::Stake(command) => (CommandGroup::Stake, command.to_possible_value()),
+            Command::Account(command) => (CommandGroup::Account, command.to_possible_value()),
+            Command::Token(command) => (CommandGroup::Token, command.to_possible_value()),
+            Command::Vote(command) => (CommandGroup::Vote, command.to_possible_value()),
+            Command::Transaction(command) => {
+                (CommandGroup::Transaction, command.to_possible_value())
+            }
+            Command::Program(command) => (CommandGroup::Program, command.to_possible_value()),
+            Command::AddressBook(command) => {
+                (CommandGroup::AddressBook, command.to_possible_value())
+            }
+            Command::ScillaConfig(command) => {
+                (CommandGroup::ScillaConfig, command.to_possible_value())
+            }
+            Command::Help | Command::Exit => return None,
+        };
+
+        Some(vec![
+            group.cli_name()?.to_string(),
+            name?.get_name().to_string(),
+        ])
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum CommandGroup {
     Account,
+    Token,
     Cluster,
     Stake,
     Vote,
     Transaction,
+    Program,
+    AddressBook,
     ScillaConfig,
+    Recent,
+    Help,
     Exit,
 }
 
+impl CommandGroup {
+    /// The subcommand that runs the group's commands directly
+    pub fn cli_name(&self) -> Option<&'static str> {
+        let name = match self {
+            CommandGroup::Account => "account",
+            CommandGroup::Token => "token",
+            CommandGroup::Cluster => "cluster",
+            CommandGroup::Stake => "stake",
+            CommandGroup::Vote => "vote",
+            CommandGroup::Transaction => "transaction",
+            CommandGroup::Program => "program",
+            CommandGroup::AddressBook => "address-book",
+            CommandGroup::ScillaConfig => "config",
+            CommandGroup::Recent | CommandGroup::Help | CommandGroup::Exit => return None,
+        };
+        Some(name)
+    }
+}
+
 impl fmt::Display for CommandGroup {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let command = match self {
             CommandGroup::Account => "Account",
+            CommandGroup::Token => "Token",
             CommandGroup::Cluster => "Cluster",
             CommandGroup::Stake => "Stake",
             CommandGroup::Vote => "Vote",
             CommandGroup::Transaction => "Transaction",
+            CommandGroup::Program => "Program",
+            CommandGroup::AddressBook => "Address Book",
             CommandGroup::ScillaConfig => "ScillaConfig",
+            CommandGroup::Recent => "Recent commands",
+            CommandGroup::Help => "Help",
             CommandGroup::Exit => "Exit",
         };
         write!(f, "{command}")
@@ -0,0 +1,361 @@
+use {
+    crate::{
+        commands::CommandExec,
+        context::ScillaContext,
+        error::ScillaResult,
+        misc::helpers::{SolAmount, bincode_deserialize, build_and_send_tx, lamports_to_sol},
+        prompt::{PubkeyCheck, prompt_data, prompt_pubkey},
+        ui::{new_table, print_output, print_tx_output, show_spinner},
+    },
+    anyhow::{anyhow, bail},
+    comfy_table::{Cell, presets::UTF8_FULL},
+    console::style,
+    serde_json::json,
+    solana_account::Account,
+    solana_keypair::{Keypair, Signer},
+    solana_nonce::{
+        state::{Data, State},
+        versions::Versions,
+    },
+    solana_pubkey::Pubkey,
+    solana_system_interface::instruction::{
+        advance_nonce_account, authorize_nonce_account, create_nonce_account,
+        withdraw_nonce_account,
+    },
+    std::fmt,
+};
+
+/// Commands related to durable nonce accounts
+#[derive(Debug, Clone)]
+pub enum NonceCommand {
+    Create,
+    Show,
+    Advance,
+    Withdraw,
+    Authorize,
+    GoBack,
+}
+
+impl NonceCommand {
+    pub fn spinner_msg(&self) -> &'static str {
+        match self {
+            NonceCommand::Create => "Creating nonce account…",
+            NonceCommand::Show => "Fetching nonce account…",
+            NonceCommand::Advance => "Advancing stored nonce…",
+            NonceCommand::Withdraw => "Withdrawing SOL from nonce account…",
+            NonceCommand::Authorize => "Updating nonce authority…",
+            NonceCommand::GoBack => "Going back…",
+        }
+    }
+}
+
+impl fmt::Display for NonceCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let command = match self {
+            NonceCommand::Create => "Create nonce account",
+            NonceCommand::Show => "Show nonce account",
+            NonceCommand::Advance => "Advance nonce",
+            NonceCommand::Withdraw => "Withdraw from nonce account",
+            NonceCommand::Authorize => "Change nonce authority",
+            NonceCommand::GoBack => "Go back",
+        };
+        write!(f, "{command}")
+    }
+}
+
+impl NonceCommand {
+    pub async fn process_command(&self, ctx: &ScillaContext) -> ScillaResult<()> {
+        match self {
+            NonceCommand::Create => {
+                let amount: SolAmount = prompt_data("Enter Amount to Fund Nonce Account (SOL):")?;
+                show_spinner(
+                    self.spinner_msg(),
+                    process_create_nonce_account(ctx, amount.to_lamports()),
+                )
+                .await?;
+            }
+            NonceCommand::Show => {
+                let pubkey =
+                    prompt_pubkey(ctx, "Enter nonce account pubkey:", PubkeyCheck::Existing)
+                        .await?;
+                show_spinner(self.spinner_msg(), fetch_nonce_account(ctx, &pubkey)).await?;
+            }
+            NonceCommand::Advance => {
+                let pubkey =
+                    prompt_pubkey(ctx, "Enter nonce account pubkey:", PubkeyCheck::Existing)
+                        .await?;
+                show_spinner(self.spinner_msg(), process_advance_nonce(ctx, &pubkey)).await?;
+            }
+            NonceCommand::Withdraw => {
+                let pubkey =
+                    prompt_pubkey(ctx, "Enter nonce account pubkey:", PubkeyCheck::Existing)
+                        .await?;
+                let recipient =
+                    prompt_pubkey(ctx, "Enter Recipient Address:", PubkeyCheck::Wallet).await?;
+                let amount: SolAmount = prompt_data("Enter Amount to Withdraw (SOL):")?;
+                show_spinner(
+                    self.spinner_msg(),
+                    process_withdraw_nonce(ctx, &pubkey, &recipient, amount.to_lamports()),
+                )
+                .await?;
+            }
+            NonceCommand::Authorize => {
+                let pubkey =
+                    prompt_pubkey(ctx, "Enter nonce account pubkey:", PubkeyCheck::Existing)
+                        .await?;
+                let new_authority =
+                    prompt_pubkey(ctx, "Enter New Authority Pubkey:", PubkeyCheck::Wallet).await?;
+                show_spinner(
+                    self.spinner_msg(),
+                    process_authorize_nonce(ctx, &pubkey, &new_authority),
+                )
+                .await?;
+            }
+            NonceCommand::GoBack => return Ok(CommandExec::GoBack),
+        }
+
+        Ok(CommandExec::Process(()))
+    }
+}
+
+/// Fetches a nonce account and its initialized nonce data
+pub async fn fetch_nonce_data(
+    ctx: &ScillaContext,
+    pubkey: &Pubkey,
+) -> anyhow::Result<(Account, Data)> {
+    let account = ctx
+        .rpc()
+        .get_account(pubkey)
+        .await
+        .map_err(|_| anyhow!("{pubkey} account does not exist"))?;
+
+    if account.owner != solana_sdk_ids::system_program::id() {
+        bail!("{pubkey} is not owned by the system program");
+    }
+
+    let versions = bincode_deserialize::<Versions>(&account.data, "nonce account data")?;
+
+    let State::Initialized(data) = versions.state() else {
+        bail!("{pubkey} is not an initialized nonce account");
+    };
+    let data = data.clone();
+
+    Ok((account, data))
+}
+
+fn ensure_nonce_authority(ctx: &ScillaContext, data: &Data) -> anyhow::Result<()> {
+    if &data.authority != ctx.pubkey() {
+        bail!(
+            "You are not the nonce authority. Nonce authority: {}",
+            data.authority
+        );
+    }
+    Ok(())
+}
+
+async fn process_create_nonce_account(ctx: &ScillaContext, lamports: u64) -> anyhow::Result<()> {
+    let rent_exempt_minimum = ctx
+        .rpc()
+        .get_minimum_balance_for_rent_exemption(State::size())
+        .await?;
+
+    if lamports < rent_exempt_minimum {
+        bail!(
+            "Nonce account needs at least {} SOL to be rent exempt, got {} SOL",
+            lamports_to_sol(rent_exempt_minimum),
+            lamports_to_sol(lamports)
+        );
+    }
+
+    let nonce_keypair = Keypair::new();
+    let nonce_pubkey = nonce_keypair.pubkey();
+
+    let instructions = create_nonce_account(ctx.pubkey(), &nonce_pubkey, ctx.pubkey(), lamports);
+
+    let signature = build_and_send_tx(ctx, &instructions, &[ctx.signer(), &nonce_keypair]).await?;
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "nonce_account": nonce_pubkey.to_string(),
+            "authority": ctx.pubkey().to_string(),
+        }),
+        || {
+            println!(
+                "\n{}\n{}\n{}\n{}",
+                style("Nonce Account Created Successfully!").green().bold(),
+                style(format!("Nonce Account: {nonce_pubkey}")).yellow(),
+                style(format!("Authority: {}", ctx.pubkey())).yellow(),
+                style(format!("Signature: {signature}")).cyan()
+            );
+        },
+    )?;
+
+    Ok(())
+}
+
+async fn fetch_nonce_account(ctx: &ScillaContext, pubkey: &Pubkey) -> anyhow::Result<()> {
+    let (account, data) = fetch_nonce_data(ctx, pubkey).await?;
+
+    let mut table = new_table();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![Cell::new("Address"), Cell::new(pubkey.to_string())])
+        .add_row(vec![
+            Cell::new("Lamports"),
+            Cell::new(format!("{}", account.lamports)),
+        ])
+        .add_row(vec![
+            Cell::new("Balance (SOL)"),
+            Cell::new(format!("{:.6}", lamports_to_sol(account.lamports))),
+        ])
+        .add_row(vec![
+            Cell::new("Owner"),
+            Cell::new(account.owner.to_string()),
+        ])
+        .add_row(vec![
+            Cell::new("Executable"),
+            Cell::new(format!("{}", account.executable)),
+        ])
+        .add_row(vec![
+            Cell::new("Rent Epoch"),
+            Cell::new(format!("{}", account.rent_epoch)),
+        ])
+        .add_row(vec![
+            Cell::new("Nonce blockhash"),
+            Cell::new(data.blockhash().to_string()),
+        ])
+        .add_row(vec![
+            Cell::new("Authority"),
+            Cell::new(data.authority.to_string()),
+        ]);
+
+    let value = json!({
+        "address": pubkey.to_string(),
+        "lamports": account.lamports,
+        "owner": account.owner.to_string(),
+        "nonce": data.blockhash().to_string(),
+        "authority": data.authority.to_string(),
+        "lamports_per_signature": data.fee_calculator.lamports_per_signature,
+    });
+
+    print_output(ctx, &value, || {
+        println!("\n{}", style("NONCE ACCOUNT INFO").green().bold());
+        println!("{table}");
+    })
+}
+
+async fn process_advance_nonce(ctx: &ScillaContext, pubkey: &Pubkey) -> anyhow::Result<()> {
+    let (_, data) = fetch_nonce_data(ctx, pubkey).await?;
+    ensure_nonce_authority(ctx, &data)?;
+
+    let instruction = advance_nonce_account(pubkey, ctx.pubkey());
+
+    let signature = build_and_send_tx(ctx, &[instruction], &[ctx.signer()]).await?;
+
+    let (_, data) = fetch_nonce_data(ctx, pubkey).await?;
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "nonce_account": pubkey.to_string(),
+            "nonce": data.blockhash().to_string(),
+        }),
+        || {
+            println!(
+                "\n{}\n{}\n{}",
+                style("Nonce Advanced Successfully!").green().bold(),
+                style(format!("New Nonce: {}", data.blockhash())).yellow(),
+                style(format!("Signature: {signature}")).cyan()
+            );
+        },
+    )?;
+
+    Ok(())
+}
+
+async fn process_withdraw_nonce(
+    ctx: &ScillaContext,
+    pubkey: &Pubkey,
+    recipient: &Pubkey,
+    lamports: u64,
+) -> anyhow::Result<()> {
+    let (account, data) = fetch_nonce_data(ctx, pubkey).await?;
+    ensure_nonce_authority(ctx, &data)?;
+
+    if lamports > account.lamports {
+        bail!(
+            "Insufficient balance. Have {} SOL, trying to withdraw {} SOL",
+            lamports_to_sol(account.lamports),
+            lamports_to_sol(lamports)
+        );
+    }
+
+    let instruction = withdraw_nonce_account(pubkey, ctx.pubkey(), recipient, lamports);
+
+    let signature = build_and_send_tx(ctx, &[instruction], &[ctx.signer()]).await?;
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "nonce_account": pubkey.to_string(),
+            "recipient": recipient.to_string(),
+            "lamports": lamports,
+            "sol": lamports_to_sol(lamports),
+        }),
+        || {
+            println!(
+                "\n{}\n{}\n{}\n{}",
+                style("Nonce Withdrawal Successful!").green().bold(),
+                style(format!("To Recipient: {recipient}")).yellow(),
+                style(format!("Amount: {} SOL", lamports_to_sol(lamports))).cyan(),
+                style(format!("Signature: {signature}")).cyan()
+            );
+        },
+    )?;
+
+    Ok(())
+}
+
+async fn process_authorize_nonce(
+    ctx: &ScillaContext,
+    pubkey: &Pubkey,
+    new_authority: &Pubkey,
+) -> anyhow::Result<()> {
+    let (_, data) = fetch_nonce_data(ctx, pubkey).await?;
+    ensure_nonce_authority(ctx, &data)?;
+
+    let instruction = authorize_nonce_account(pubkey, ctx.pubkey(), new_authority);
+
+    let signature = build_and_send_tx(ctx, &[instruction], &[ctx.signer()]).await?;
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "nonce_account": pubkey.to_string(),
+            "old_authority": data.authority.to_string(),
+            "new_authority": new_authority.to_string(),
+        }),
+        || {
+            println!(
+                "\n{}\n{}\n{}\n{}",
+                style("Nonce Authority Updated Successfully!")
+                    .green()
+                    .bold(),
+                style(format!("Old Authority: {}", data.authority)).yellow(),
+                style(format!("New Authority: {new_authority}")).yellow(),
+                style(format!("Signature: {signature}")).cyan()
+            );
+        },
+    )?;
+
+    Ok(())
+}
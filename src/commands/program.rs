@@ -0,0 +1,307 @@
+use {
+    crate::{
+        commands::CommandExec,
+        config::expand_tilde,
+        constants::PROGRAM_WRITE_CONCURRENCY,
+        context::ScillaContext,
+        error::ScillaResult,
+        misc::helpers::{
+            OptionalInput, account_exists, build_and_send_tx, ensure_sufficient_balance,
+            lamports_to_sol, max_write_chunk_size, read_keypair_from_path,
+        },
+        notice,
+        prompt::{prompt_confirm, prompt_data},
+        ui::{new_table, print_tx_output, show_spinner, show_spinner_with_status},
+    },
+    anyhow::{Context, bail},
+    clap::ValueEnum,
+    comfy_table::{Cell, presets::UTF8_FULL},
+    console::style,
+    futures::{StreamExt, TryStreamExt, stream},
+    indicatif::ProgressBar,
+    serde_json::json,
+    solana_keypair::{Keypair, Signer},
+    solana_loader_v3_interface::{
+        get_program_data_address, instruction as loader_instruction, state::UpgradeableLoaderState,
+    },
+    solana_pubkey::Pubkey,
+    std::{
+        fmt, fs,
+        sync::atomic::{AtomicUsize, Ordering},
+    },
+    tokio::try_join,
+};
+
+/// Commands for on-chain programs
+#[derive(Debug, Clone, ValueEnum)]
+pub enum ProgramCommand {
+    Deploy,
+    #[value(skip)]
+    GoBack,
+}
+
+impl ProgramCommand {
+    pub fn spinner_msg(&self) -> &'static str {
+        match self {
+            ProgramCommand::Deploy => "Deploying program…",
+            ProgramCommand::GoBack => "Going back…",
+        }
+    }
+}
+
+impl fmt::Display for ProgramCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let command = match self {
+            ProgramCommand::Deploy => "Deploy program",
+            ProgramCommand::GoBack => "Go back",
+        };
+        write!(f, "{command}")
+    }
+}
+
+impl ProgramCommand {
+    pub async fn process_command(&self, ctx: &ScillaContext) -> ScillaResult<()> {
+        match self {
+            ProgramCommand::Deploy => {
+                let program_path: String = prompt_data("Enter path to the program .so file:")?;
+                let program_data = fs::read(expand_tilde(&program_path))
+                    .with_context(|| format!("Failed to read {program_path}"))?;
+                if program_data.is_empty() {
+                    bail!("{program_path} is empty");
+                }
+
+                let program_keypair_path: OptionalInput<String> = prompt_data(
+                    "Enter program keypair path (leave empty to generate a new program id):",
+                )?;
+                let program_keypair = match program_keypair_path.value() {
+                    Some(path) => read_keypair_from_path(expand_tilde(&path))?,
+                    None => Keypair::new(),
+                };
+
+                let plan = show_spinner(
+                    "Calculating deploy cost…",
+                    plan_deploy(ctx, &program_keypair.pubkey(), program_data.len()),
+                )
+                .await?;
+
+                if !confirm_deploy(&program_keypair.pubkey(), program_data.len(), &plan)? {
+                    notice!("{}", style("Deploy cancelled.").yellow());
+                    return Ok(CommandExec::Process(()));
+                }
+
+                show_spinner_with_status(self.spinner_msg(), |spinner| {
+                    process_deploy(ctx, &program_keypair, &program_data, &plan, spinner)
+                })
+                .await?;
+            }
+            ProgramCommand::GoBack => return Ok(CommandExec::GoBack),
+        }
+
+        Ok(CommandExec::Process(()))
+    }
+}
+
+/// Rent the deploy has to fund. The buffer's rent is refunded once the
+/// program is deployed from it.
+struct DeployPlan {
+    buffer_lamports: u64,
+    program_lamports: u64,
+    programdata_lamports: u64,
+}
+
+async fn plan_deploy(
+    ctx: &ScillaContext,
+    program_id: &Pubkey,
+    program_len: usize,
+) -> anyhow::Result<DeployPlan> {
+    if account_exists(ctx, program_id).await? {
+        bail!("{program_id} already exists. Use another program keypair");
+    }
+
+    let rpc = ctx.rpc();
+    let (buffer_lamports, program_lamports, programdata_lamports) = try_join!(
+        rpc.get_minimum_balance_for_rent_exemption(UpgradeableLoaderState::size_of_buffer(
+            program_len
+        )),
+        rpc.get_minimum_balance_for_rent_exemption(UpgradeableLoaderState::size_of_program()),
+        rpc.get_minimum_balance_for_rent_exemption(UpgradeableLoaderState::size_of_programdata(
+            program_len
+        )),
+    )?;
+
+    // The buffer is created first and the program data only at deploy time,
+    // while the buffer still holds its rent
+    let instructions = loader_instruction::create_buffer(
+        ctx.pubkey(),
+        &Pubkey::new_unique(),
+        ctx.pubkey(),
+        buffer_lamports,
+        program_len,
+    )?;
+    ensure_sufficient_balance(
+        ctx,
+        &instructions,
+        &[
+            ("buffer rent", buffer_lamports),
+            ("program rent", program_lamports),
+            ("program data rent", programdata_lamports),
+        ],
+    )
+    .await?;
+
+    Ok(DeployPlan {
+        buffer_lamports,
+        program_lamports,
+        programdata_lamports,
+    })
+}
+
+fn confirm_deploy(
+    program_id: &Pubkey,
+    program_len: usize,
+    plan: &DeployPlan,
+) -> anyhow::Result<bool> {
+    let mut table = new_table();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![Cell::new("Program Id"), Cell::new(program_id)])
+        .add_row(vec![
+            Cell::new("Program Size (bytes)"),
+            Cell::new(program_len),
+        ])
+        .add_row(vec![
+            Cell::new("Program Rent (SOL)"),
+            Cell::new(lamports_to_sol(
+                plan.program_lamports + plan.programdata_lamports,
+            )),
+        ])
+        .add_row(vec![
+            Cell::new("Buffer Rent, refunded (SOL)"),
+            Cell::new(lamports_to_sol(plan.buffer_lamports)),
+        ]);
+
+    notice!("\n{}", style("REVIEW DEPLOY").green().bold());
+    notice!("{table}");
+
+    prompt_confirm("Deploy this program?")
+}
+
+async fn process_deploy(
+    ctx: &ScillaContext,
+    program_keypair: &Keypair,
+    program_data: &[u8],
+    plan: &DeployPlan,
+    spinner: ProgressBar,
+) -> anyhow::Result<()> {
+    let buffer_keypair = Keypair::new();
+    let buffer = buffer_keypair.pubkey();
+
+    spinner.set_message("Creating buffer account…");
+    let instructions = loader_instruction::create_buffer(
+        ctx.pubkey(),
+        &buffer,
+        ctx.pubkey(),
+        plan.buffer_lamports,
+        program_data.len(),
+    )?;
+    build_and_send_tx(ctx, &instructions, &[ctx.signer(), &buffer_keypair]).await?;
+
+    let recover_hint = || {
+        format!(
+            "Buffer {buffer} holds {} SOL of rent. Close it with `solana program close {buffer}` \
+             to get it back",
+            lamports_to_sol(plan.buffer_lamports)
+        )
+    };
+
+    write_program_data(ctx, &buffer, program_data, &spinner)
+        .await
+        .with_context(recover_hint)?;
+
+    spinner.set_message("Deploying from buffer…");
+    #[allow(deprecated)]
+    let instructions = loader_instruction::deploy_with_max_program_len(
+        ctx.pubkey(),
+        &program_keypair.pubkey(),
+        &buffer,
+        ctx.pubkey(),
+        plan.program_lamports,
+        program_data.len(),
+    )?;
+    let signature = build_and_send_tx(ctx, &instructions, &[ctx.signer(), program_keypair])
+        .await
+        .with_context(recover_hint)?;
+
+    let program_id = program_keypair.pubkey();
+    let programdata = get_program_data_address(&program_id);
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "program_id": program_id.to_string(),
+            "program_data": programdata.to_string(),
+            "upgrade_authority": ctx.pubkey().to_string(),
+            "size": program_data.len(),
+        }),
+        || {
+            println!(
+                "\n{} {}",
+                style("Program deployed!").green().bold(),
+                style(program_id).cyan()
+            );
+            println!(
+                "{}",
+                style(format!(
+                    "Program data: {programdata}\nUpgrade authority: {}",
+                    ctx.pubkey()
+                ))
+                .dim()
+            );
+        },
+    )
+}
+
+/// Writes the program into the buffer in transaction-sized chunks, a few at a
+/// time
+async fn write_program_data(
+    ctx: &ScillaContext,
+    buffer: &Pubkey,
+    program_data: &[u8],
+    spinner: &ProgressBar,
+) -> anyhow::Result<()> {
+    let chunk_size = max_write_chunk_size(
+        ctx.pubkey(),
+        buffer,
+        ctx.priority_fee_micro_lamports(),
+        ctx.compute_unit_limit(),
+    )?;
+    let total = program_data.len().div_ceil(chunk_size);
+    let written = AtomicUsize::new(0);
+    spinner.set_message(format!("Writing program data: 0/{total} chunks…"));
+
+    stream::iter(program_data.chunks(chunk_size).enumerate())
+        .map(|(index, chunk)| {
+            let written = &written;
+            async move {
+                let instruction = loader_instruction::write(
+                    buffer,
+                    ctx.pubkey(),
+                    (index * chunk_size) as u32,
+                    chunk.to_vec(),
+                );
+                build_and_send_tx(ctx, &[instruction], &[ctx.signer()]).await?;
+
+                let done = written.fetch_add(1, Ordering::Relaxed) + 1;
+                spinner.set_message(format!("Writing program data: {done}/{total} chunks…"));
+                anyhow::Ok(())
+            }
+        })
+        .buffer_unordered(PROGRAM_WRITE_CONCURRENCY)
+        .try_collect::<()>()
+        .await
+}
@@ -1,41 +1,94 @@
 use {
     crate::{
         commands::CommandExec,
-        constants::{ACTIVE_STAKE_EPOCH_BOUND, DEFAULT_EPOCH_LIMIT, STAKE_HISTORY_SYSVAR_ADDR},
-        context::ScillaContext,
+        config::expand_tilde,
+        constants::{
+            ACTIVE_STAKE_EPOCH_BOUND, DEFAULT_EPOCH_LIMIT, DEFAULT_HISTORY_PAGE_SIZE,
+            DEFAULT_REWARD_EPOCHS, HIGH_COMMISSION_THRESHOLD, MAX_SIGNATURES_PER_REQUEST,
+            MERGE_STAKE_ACCOUNTS_PER_TX, NEW_WARMUP_COOLDOWN_RATE_EPOCH,
+            STAKE_AUTHORIZED_STAKER_OFFSET, STAKE_AUTHORIZED_WITHDRAWER_OFFSET,
+            STAKE_HISTORY_SYSVAR_ADDR, VALIDATOR_SELECT_PAGE_SIZE,
+        },
+        context::{OutputFormat, ScillaContext},
         error::ScillaResult,
         misc::helpers::{
-            SolAmount, bincode_deserialize, bincode_deserialize_with_limit, build_and_send_tx,
-            fetch_account_with_epoch, lamports_to_sol, read_keypair_from_path, sol_to_lamports,
+            ExportFormat, OptionalInput, SolAmount, WithdrawAmount, annualized_yield,
+            average_slot_time_ms, bincode_deserialize, bincode_deserialize_with_limit,
+            build_and_send_tx, compound_epoch_rate, confirm_fee, confirm_keypair_overwrite,
+            ensure_sufficient_balance, epochs_per_year, fetch_account_with_epoch, fetch_accounts,
+            format_block_time, format_duration, format_sol_exact, lamports_to_sol,
+            read_keypair_from_path, save_keypair, simulate_then_send, slots_to_duration,
+            slots_until_epoch, to_csv,
+        },
+        notice,
+        prompt::{
+            PubkeyCheck, prompt_confirm, prompt_data, prompt_pubkey, prompt_select, prompt_yes_no,
         },
-        prompt::prompt_data,
-        ui::show_spinner,
+        ui::{new_table, print_output, print_tx_output, show_spinner, show_spinner_with_status},
     },
-    anyhow::bail,
-    comfy_table::{Cell, Table, presets::UTF8_FULL},
+    anyhow::{Context, anyhow, bail},
+    clap::ValueEnum,
+    comfy_table::{Cell, Color, presets::UTF8_FULL},
     console::style,
-    solana_keypair::Signer,
+    indicatif::ProgressBar,
+    inquire::{Confirm, Select},
+    serde::Serialize,
+    serde_json::json,
+    solana_account::Account,
+    solana_account_decoder_client_types::UiAccountEncoding,
+    solana_commitment_config::{CommitmentConfig, CommitmentLevel},
+    solana_epoch_info::EpochInfo,
+    solana_instruction::Instruction,
+    solana_keypair::{Keypair, Signer},
     solana_pubkey::Pubkey,
+    solana_rpc_client::rpc_client::GetConfirmedSignaturesForAddress2Config,
+    solana_rpc_client_api::{
+        config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+        filter::{Memcmp, RpcFilterType},
+        response::{RpcConfirmedTransactionStatusWithSignature, RpcInflationReward},
+    },
+    solana_signature::Signature,
     solana_stake_interface::{
-        instruction::{self, deactivate_stake, merge, withdraw},
+        instruction::{
+            self, LockupArgs, authorize, deactivate_stake, delegate_stake, merge, set_lockup,
+            withdraw,
+        },
         program::id as stake_program_id,
         stake_history::{StakeHistory, StakeHistoryEntry},
-        state::StakeStateV2,
+        state::{Authorized, Lockup, StakeActivationStatus, StakeAuthorize, StakeStateV2},
+    },
+    solana_system_interface::instruction::transfer,
+    solana_vote_program::vote_state::VoteStateV4,
+    std::{
+        collections::{BTreeSet, HashMap},
+        fmt, fs,
+        ops::Range,
+        path::{Path, PathBuf},
+        str::FromStr,
     },
-    std::{fmt, path::PathBuf},
 };
 
 /// Commands related to staking operations
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, ValueEnum)]
 pub enum StakeCommand {
     Create,
     Delegate,
+    StakeToValidator,
     Deactivate,
+    DeactivatePartial,
     Withdraw,
     Merge,
+    MergeAll,
     Split,
+    Authorize,
+    SetLockup,
     Show,
+    Rewards,
+    ExportRewards,
+    EstimateApy,
+    List,
     History,
+    #[value(skip)]
     GoBack,
 }
 
@@ -44,11 +97,20 @@ impl StakeCommand {
         match self {
             StakeCommand::Create => "Creating new stake account…",
             StakeCommand::Delegate => "Delegating stake to validator…",
+            StakeCommand::StakeToValidator => "Creating and delegating stake account…",
             StakeCommand::Deactivate => "Deactivating stake (cooldown starting)…",
+            StakeCommand::DeactivatePartial => "Splitting off and deactivating stake…",
             StakeCommand::Withdraw => "Withdrawing SOL from deactivated stake…",
             StakeCommand::Merge => "Merging stake accounts…",
+            StakeCommand::MergeAll => "Merging all mergeable stake accounts…",
             StakeCommand::Split => "Splitting stake into multiple accounts…",
+            StakeCommand::Authorize => "Updating stake authority…",
+            StakeCommand::SetLockup => "Setting stake account lockup…",
             StakeCommand::Show => "Fetching stake account details…",
+            StakeCommand::Rewards => "Fetching inflation rewards…",
+            StakeCommand::ExportRewards => "Exporting stake rewards…",
+            StakeCommand::EstimateApy => "Estimating validator APY…",
+            StakeCommand::List => "Finding your stake accounts…",
             StakeCommand::History => "Fetching stake account history…",
             StakeCommand::GoBack => "Going back…",
         }
@@ -60,11 +122,20 @@ impl fmt::Display for StakeCommand {
         let command = match self {
             StakeCommand::Create => "Create stake account",
             StakeCommand::Delegate => "Delegate stake",
+            StakeCommand::StakeToValidator => "Stake SOL with a validator",
             StakeCommand::Deactivate => "Deactivate stake",
+            StakeCommand::DeactivatePartial => "Deactivate part of a stake",
             StakeCommand::Withdraw => "Withdraw stake",
             StakeCommand::Merge => "Merge stake accounts",
+            StakeCommand::MergeAll => "Merge all my stake accounts",
             StakeCommand::Split => "Split stake account",
+            StakeCommand::Authorize => "Change stake authority",
+            StakeCommand::SetLockup => "Set stake lockup",
             StakeCommand::Show => "Show stake",
+            StakeCommand::Rewards => "View stake rewards",
+            StakeCommand::ExportRewards => "Export stake rewards (CSV)",
+            StakeCommand::EstimateApy => "Estimate validator APY",
+            StakeCommand::List => "List my stake accounts",
             StakeCommand::History => "View stake history",
             StakeCommand::GoBack => "Go back",
         };
@@ -75,34 +146,216 @@ impl fmt::Display for StakeCommand {
 impl StakeCommand {
     pub async fn process_command(&self, ctx: &ScillaContext) -> ScillaResult<()> {
         match self {
-            StakeCommand::Create => todo!(),
-            StakeCommand::Delegate => todo!(),
+            StakeCommand::Create => {
+                let amount: SolAmount = prompt_data("Enter Amount to Stake (SOL):")?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_create_stake_account(ctx, amount.to_lamports()),
+                )
+                .await?;
+            }
+            StakeCommand::Delegate => {
+                let stake_pubkey = prompt_pubkey(
+                    ctx,
+                    "Enter Stake Account Pubkey to Delegate:",
+                    PubkeyCheck::Existing,
+                )
+                .await?;
+                let Some(vote_pubkey) = prompt_validator(ctx).await? else {
+                    notice!("{}", style("Delegation cancelled.").yellow());
+                    return Ok(CommandExec::Process(()));
+                };
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_delegate_stake(ctx, &stake_pubkey, &vote_pubkey),
+                )
+                .await?;
+            }
+            StakeCommand::StakeToValidator => {
+                let amount: SolAmount = prompt_data("Enter Amount to Stake (SOL):")?;
+                let Some(vote_pubkey) = prompt_validator(ctx).await? else {
+                    notice!("{}", style("Staking cancelled.").yellow());
+                    return Ok(CommandExec::Process(()));
+                };
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_stake_to_validator(ctx, amount.to_lamports(), &vote_pubkey),
+                )
+                .await?;
+            }
             StakeCommand::Deactivate => {
-                let stake_pubkey: Pubkey =
-                    prompt_data("Enter Stake Account Pubkey to Deactivate:")?;
+                let stake_pubkey = prompt_pubkey(
+                    ctx,
+                    "Enter Stake Account Pubkey to Deactivate:",
+                    PubkeyCheck::Existing,
+                )
+                .await?;
+
+                let plan = show_spinner(
+                    "Checking stake account…",
+                    plan_deactivate_stake(ctx, &stake_pubkey),
+                )
+                .await?;
+
+                let summary = [
+                    ("Stake Account", stake_pubkey.to_string()),
+                    (
+                        "Delegated Stake",
+                        format!("{} SOL", lamports_to_sol(plan.delegated_lamports)),
+                    ),
+                    ("Validator Vote Account", plan.voter.to_string()),
+                    ("Stake Authority", ctx.pubkey().to_string()),
+                ];
+                if !confirm_stake_action(ctx, &summary, "Deactivate this stake?")? {
+                    notice!("{}", style("Deactivation cancelled.").yellow());
+                    return Ok(CommandExec::Process(()));
+                }
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_deactivate_stake_account(ctx, &stake_pubkey, &plan),
+                )
+                .await?;
+            }
+            StakeCommand::DeactivatePartial => {
+                let stake_pubkey = prompt_pubkey(
+                    ctx,
+                    "Enter Stake Account Pubkey to Partially Deactivate:",
+                    PubkeyCheck::Existing,
+                )
+                .await?;
+                let amount: SolAmount = prompt_data("Enter Amount (SOL) to Deactivate:")?;
+                let keypair_path: OptionalInput<String> = prompt_data(
+                    "Save the new stake account keypair to (leave empty to keep it in memory \
+                     only):",
+                )?;
+                let keypair_path = keypair_path.value().map(|path| expand_tilde(&path));
+
+                if let Some(path) = &keypair_path
+                    && !confirm_keypair_overwrite(path)?
+                {
+                    notice!("{}", style("Deactivation cancelled.").yellow());
+                    return Ok(CommandExec::Process(()));
+                }
+
+                let plan = show_spinner(
+                    "Checking stake account…",
+                    plan_deactivate_stake(ctx, &stake_pubkey),
+                )
+                .await?;
+                let amount_lamports = amount.to_lamports();
+                if amount_lamports >= plan.delegated_lamports {
+                    bail!(
+                        "{} SOL is not less than the {} SOL delegated. Use Deactivate stake to \
+                         deactivate all of it",
+                        amount.value(),
+                        lamports_to_sol(plan.delegated_lamports)
+                    );
+                }
+                show_spinner(
+                    "Checking minimum delegation…",
+                    check_partial_deactivation(ctx, plan.delegated_lamports, amount_lamports),
+                )
+                .await?;
+
+                let summary = [
+                    ("Stake Account", stake_pubkey.to_string()),
+                    (
+                        "Delegated Stake",
+                        format!("{} SOL", lamports_to_sol(plan.delegated_lamports)),
+                    ),
+                    ("Deactivating", format!("{} SOL", amount.value())),
+                    (
+                        "Staying Active",
+                        format!(
+                            "{} SOL",
+                            lamports_to_sol(plan.delegated_lamports - amount_lamports)
+                        ),
+                    ),
+                    ("Validator Vote Account", plan.voter.to_string()),
+                    ("Stake Authority", ctx.pubkey().to_string()),
+                ];
+                if !confirm_stake_action(ctx, &summary, "Split off and deactivate this amount?")? {
+                    notice!("{}", style("Deactivation cancelled.").yellow());
+                    return Ok(CommandExec::Process(()));
+                }
+
                 show_spinner(
                     self.spinner_msg(),
-                    process_deactivate_stake_account(ctx, &stake_pubkey),
+                    process_deactivate_partial(
+                        ctx,
+                        &stake_pubkey,
+                        amount_lamports,
+                        &plan,
+                        keypair_path.as_deref(),
+                    ),
                 )
                 .await?;
             }
             StakeCommand::Withdraw => {
-                let stake_pubkey: Pubkey =
-                    prompt_data("Enter Stake Account Pubkey to Withdraw from:")?;
-                let recipient: Pubkey = prompt_data("Enter Recipient Address:")?;
-                let amount: SolAmount = prompt_data("Enter Amount to Withdraw (SOL):")?;
+                let stake_pubkey = prompt_pubkey(
+                    ctx,
+                    "Enter Stake Account Pubkey to Withdraw from:",
+                    PubkeyCheck::Existing,
+                )
+                .await?;
+                let recipient =
+                    prompt_pubkey(ctx, "Enter Recipient Address:", PubkeyCheck::Wallet).await?;
+                let amount: WithdrawAmount = prompt_data(
+                    "Enter Amount to Withdraw (SOL, or `all` / empty for the full balance):",
+                )?;
+
+                let plan = show_spinner(
+                    "Checking stake account…",
+                    plan_withdraw_stake(ctx, &stake_pubkey, amount),
+                )
+                .await?;
+
+                if plan.closes_account {
+                    notice!(
+                        "{}",
+                        style(
+                            "⚠ Withdrawing the full balance drops the account below rent \
+                             exemption and closes it."
+                        )
+                        .yellow()
+                        .bold()
+                    );
+                }
+
+                let summary = [
+                    ("Stake Account", stake_pubkey.to_string()),
+                    ("Recipient", recipient.to_string()),
+                    (
+                        "Amount",
+                        format!("{} SOL", lamports_to_sol(plan.amount_lamports)),
+                    ),
+                    ("Withdraw Authority", ctx.pubkey().to_string()),
+                ];
+                if !confirm_stake_action(ctx, &summary, "Withdraw from this stake account?")? {
+                    notice!("{}", style("Withdrawal cancelled.").yellow());
+                    return Ok(CommandExec::Process(()));
+                }
 
                 show_spinner(
                     self.spinner_msg(),
-                    process_withdraw_stake(ctx, &stake_pubkey, &recipient, amount.value()),
+                    process_withdraw_stake(ctx, &stake_pubkey, &recipient, &plan),
                 )
                 .await?;
             }
             StakeCommand::Merge => {
-                let destination_stake_account_pubkey: Pubkey =
-                    prompt_data("Enter Stake Account Pubkey: ")?;
-                let source_stake_account_pubkey: Pubkey =
-                    prompt_data("Enter Source Stake Account Pubkey: ")?;
+                let destination_stake_account_pubkey =
+                    prompt_pubkey(ctx, "Enter Stake Account Pubkey: ", PubkeyCheck::Existing)
+                        .await?;
+                let source_stake_account_pubkey = prompt_pubkey(
+                    ctx,
+                    "Enter Source Stake Account Pubkey: ",
+                    PubkeyCheck::Existing,
+                )
+                .await?;
                 let stake_authority_keypair_path: PathBuf =
                     prompt_data("Enter Stake Authority Keypair Path: ")?;
 
@@ -117,29 +370,233 @@ impl StakeCommand {
                 )
                 .await?;
             }
+            StakeCommand::MergeAll => {
+                let groups =
+                    show_spinner("Finding mergeable stake accounts…", find_merge_groups(ctx))
+                        .await?;
+
+                if groups.is_empty() {
+                    notice!(
+                        "\n{}",
+                        style("None of your stake accounts can be merged.").yellow()
+                    );
+                    return Ok(CommandExec::Process(()));
+                }
+
+                let mut table = new_table();
+                table.load_preset(UTF8_FULL).set_header(vec![
+                    Cell::new("Destination").add_attribute(comfy_table::Attribute::Bold),
+                    Cell::new("Validator Vote Account").add_attribute(comfy_table::Attribute::Bold),
+                    Cell::new("Status").add_attribute(comfy_table::Attribute::Bold),
+                    Cell::new("Accounts").add_attribute(comfy_table::Attribute::Bold),
+                    Cell::new("Total (SOL)").add_attribute(comfy_table::Attribute::Bold),
+                ]);
+                for group in &groups {
+                    table.add_row(vec![
+                        Cell::new(group.destination),
+                        Cell::new(
+                            group
+                                .kind
+                                .voter()
+                                .map_or("-".to_string(), |voter| voter.to_string()),
+                        ),
+                        Cell::new(group.kind.label()),
+                        Cell::new(group.sources.len() + 1),
+                        Cell::new(lamports_to_sol(group.lamports)),
+                    ]);
+                }
+                notice!("\n{}", style("MERGE PLAN").green().bold());
+                notice!("{}", table);
+
+                let merged: usize = groups.iter().map(|group| group.sources.len() + 1).sum();
+                if !prompt_confirm(&format!(
+                    "Merge {merged} stake accounts into {}?",
+                    groups.len()
+                ))? {
+                    notice!("{}", style("Nothing was merged.").yellow());
+                    return Ok(CommandExec::Process(()));
+                }
+
+                show_spinner(self.spinner_msg(), process_merge_all(ctx, &groups)).await?;
+            }
             StakeCommand::Split => {
-                let stake_account_pubkey: Pubkey = prompt_data("Enter Stake Account Pubkey: ")?;
-                let split_stake_account_pubkey: Pubkey =
-                    prompt_data("Enter Split Stake Account Pubkey: ")?;
+                let stake_account_pubkey =
+                    prompt_pubkey(ctx, "Enter Stake Account Pubkey: ", PubkeyCheck::Existing)
+                        .await?;
                 let stake_authority_keypair_path: PathBuf =
                     prompt_data("Enter Stake Authority Keypair Path: ")?;
-                let amount_to_split: f64 = prompt_data("Enter Stake Amount (SOL) to Split: ")?;
+                let amount: SolAmount = prompt_data("Enter Stake Amount (SOL) to Split: ")?;
+                let keypair_path: OptionalInput<String> = prompt_data(
+                    "Save the new stake account keypair to (leave empty to keep it in memory \
+                     only):",
+                )?;
+                let keypair_path = keypair_path.value().map(|path| expand_tilde(&path));
+
+                if let Some(path) = &keypair_path
+                    && !confirm_keypair_overwrite(path)?
+                {
+                    notice!("{}", style("Split cancelled.").yellow());
+                    return Ok(CommandExec::Process(()));
+                }
 
                 show_spinner(
                     self.spinner_msg(),
                     process_split_stake(
                         ctx,
                         &stake_account_pubkey,
-                        &split_stake_account_pubkey,
                         &stake_authority_keypair_path,
-                        amount_to_split,
+                        amount.to_lamports(),
+                        keypair_path.as_deref(),
                     ),
                 )
                 .await?;
             }
-            StakeCommand::Show => todo!(),
+            StakeCommand::Authorize => {
+                let stake_pubkey =
+                    prompt_pubkey(ctx, "Enter Stake Account Pubkey:", PubkeyCheck::Existing)
+                        .await?;
+                let authority_choice = prompt_select(Select::new(
+                    "Authority to change:",
+                    vec!["Staker", "Withdrawer"],
+                ))?;
+                let stake_authorize = match authority_choice {
+                    "Withdrawer" => StakeAuthorize::Withdrawer,
+                    _ => StakeAuthorize::Staker,
+                };
+                let new_authority =
+                    prompt_pubkey(ctx, "Enter New Authority Pubkey:", PubkeyCheck::Wallet).await?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_authorize_stake(ctx, &stake_pubkey, stake_authorize, &new_authority),
+                )
+                .await?;
+            }
+            StakeCommand::SetLockup => {
+                let stake_pubkey =
+                    prompt_pubkey(ctx, "Enter Stake Account Pubkey:", PubkeyCheck::Existing)
+                        .await?;
+                let unix_timestamp: OptionalInput<i64> =
+                    prompt_data("Enter Lockup Unix Timestamp (leave empty to skip):")?;
+                let epoch: OptionalInput<u64> =
+                    prompt_data("Enter Lockup Epoch (leave empty to skip):")?;
+                let custodian: OptionalInput<Pubkey> =
+                    prompt_data("Enter New Custodian Pubkey (leave empty to skip):")?;
+
+                let lockup = LockupArgs {
+                    unix_timestamp: unix_timestamp.value(),
+                    epoch: epoch.value(),
+                    custodian: custodian.value(),
+                };
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_set_lockup(ctx, &stake_pubkey, &lockup),
+                )
+                .await?;
+            }
+            StakeCommand::Show => {
+                let stake_pubkey =
+                    prompt_pubkey(ctx, "Enter Stake Account Pubkey:", PubkeyCheck::Existing)
+                        .await?;
+                show_spinner(
+                    self.spinner_msg(),
+                    process_show_stake_account(ctx, &stake_pubkey),
+                )
+                .await?;
+            }
+            StakeCommand::Rewards => {
+                let stake_pubkey: Pubkey = prompt_data("Enter Stake Account Pubkey:")?;
+                let epochs: OptionalInput<u64> = prompt_data(&format!(
+                    "Enter number of past epochs to show (default {DEFAULT_REWARD_EPOCHS}):"
+                ))?;
+                let epochs = epochs.value().unwrap_or(DEFAULT_REWARD_EPOCHS);
+
+                show_spinner_with_status(self.spinner_msg(), |spinner| {
+                    process_stake_rewards(ctx, &stake_pubkey, epochs, spinner)
+                })
+                .await?;
+            }
+            StakeCommand::ExportRewards => {
+                let stake_pubkey: Pubkey = prompt_data("Enter Stake Account Pubkey:")?;
+                let first_epoch: u64 = prompt_data("Enter first epoch to export:")?;
+                let last_epoch: OptionalInput<u64> = prompt_data(
+                    "Enter last epoch to export (leave empty for the last completed epoch):",
+                )?;
+                let path: PathBuf = prompt_data("Enter output file path:")?;
+
+                show_spinner_with_status(self.spinner_msg(), |spinner| {
+                    process_export_stake_rewards(
+                        ctx,
+                        &stake_pubkey,
+                        first_epoch,
+                        last_epoch.value(),
+                        &path,
+                        spinner,
+                    )
+                })
+                .await?;
+            }
+            StakeCommand::EstimateApy => {
+                let first =
+                    prompt_pubkey(ctx, "Enter Vote Account Pubkey:", PubkeyCheck::Existing).await?;
+                let second: OptionalInput<Pubkey> =
+                    prompt_data("Enter a second Vote Account to compare (leave empty to skip):")?;
+                let epochs: OptionalInput<u64> = prompt_data(&format!(
+                    "Enter number of past epochs to sample (default {DEFAULT_REWARD_EPOCHS}):"
+                ))?;
+                let epochs = epochs.value().unwrap_or(DEFAULT_REWARD_EPOCHS);
+
+                let mut vote_pubkeys = vec![first];
+                vote_pubkeys.extend(second.value());
+
+                show_spinner_with_status(self.spinner_msg(), |spinner| {
+                    process_estimate_apy(ctx, &vote_pubkeys, epochs, spinner)
+                })
+                .await?;
+            }
+            StakeCommand::List => {
+                let authority_choice = prompt_select(Select::new(
+                    "Find stake accounts where my wallet is the:",
+                    vec!["Withdrawer", "Staker"],
+                ))?;
+                let stake_authorize = match authority_choice {
+                    "Staker" => StakeAuthorize::Staker,
+                    _ => StakeAuthorize::Withdrawer,
+                };
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_list_stake_accounts(ctx, stake_authorize),
+                )
+                .await?;
+            }
             StakeCommand::History => {
-                show_spinner(self.spinner_msg(), process_stake_history(ctx)).await?;
+                let stake_pubkey: OptionalInput<Pubkey> = prompt_data(
+                    "Enter Stake Account Pubkey (leave empty for cluster-wide stake history):",
+                )?;
+
+                match stake_pubkey.value() {
+                    Some(stake_pubkey) => {
+                        let limit = prompt_history_limit(DEFAULT_HISTORY_PAGE_SIZE)?;
+                        let until: OptionalInput<Signature> = prompt_data(
+                            "Stop at signature (leave empty to page back to the first \
+                             transaction):",
+                        )?;
+
+                        let entries =
+                            browse_stake_account_history(ctx, &stake_pubkey, limit, until.value())
+                                .await?;
+
+                        if ctx.output_format() == OutputFormat::Text && !entries.is_empty() {
+                            prompt_export_signatures(&entries)?;
+                        }
+                    }
+                    None => {
+                        let limit = prompt_history_limit(DEFAULT_EPOCH_LIMIT)?;
+                        show_spinner(self.spinner_msg(), process_stake_history(ctx, limit)).await?;
+                    }
+                }
             }
 
             StakeCommand::GoBack => return Ok(CommandExec::GoBack),
@@ -149,9 +606,272 @@ impl StakeCommand {
     }
 }
 
-async fn process_deactivate_stake_account(
+async fn process_create_stake_account(
+    ctx: &ScillaContext,
+    amount_lamports: u64,
+) -> anyhow::Result<()> {
+    let (rent_exempt_reserve, total_lamports) =
+        new_stake_account_lamports(ctx, amount_lamports).await?;
+
+    let stake_keypair = Keypair::new();
+    let stake_pubkey = stake_keypair.pubkey();
+    let authorized = Authorized::auto(ctx.pubkey());
+
+    let instructions = instruction::create_account(
+        ctx.pubkey(),
+        &stake_pubkey,
+        &authorized,
+        &Lockup::default(),
+        total_lamports,
+    );
+
+    ensure_sufficient_balance(
+        ctx,
+        &instructions,
+        &[
+            ("stake", amount_lamports),
+            ("rent exemption", rent_exempt_reserve),
+        ],
+    )
+    .await?;
+
+    confirm_fee(ctx, &instructions).await?;
+
+    let signature = build_and_send_tx(ctx, &instructions, &[ctx.signer(), &stake_keypair]).await?;
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "stake_account": stake_pubkey.to_string(),
+            "lamports": amount_lamports,
+            "rent_exempt_reserve": rent_exempt_reserve,
+        }),
+        || {
+            println!(
+                "\n{}\n{}\n{}\n{}",
+                style("Stake Account Created Successfully!").green().bold(),
+                style(format!("Stake Account: {stake_pubkey}")).yellow(),
+                style(format!(
+                    "Amount: {} SOL (+ {} SOL rent exemption)",
+                    lamports_to_sol(amount_lamports),
+                    lamports_to_sol(rent_exempt_reserve)
+                ))
+                .cyan(),
+                style(format!("Signature: {signature}")).cyan()
+            );
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Checks `amount_lamports` meets the minimum delegation and returns the rent
+/// exempt reserve and the total a new stake account has to be funded with
+async fn new_stake_account_lamports(
+    ctx: &ScillaContext,
+    amount_lamports: u64,
+) -> anyhow::Result<(u64, u64)> {
+    let rpc = ctx.rpc();
+    let (stake_minimum_delegation, rent_exempt_reserve) = tokio::try_join!(
+        rpc.get_stake_minimum_delegation(),
+        rpc.get_minimum_balance_for_rent_exemption(StakeStateV2::size_of()),
+    )?;
+
+    if amount_lamports < stake_minimum_delegation {
+        bail!(
+            "Stake amount {:.9} SOL is below the minimum delegation of {:.9} SOL",
+            lamports_to_sol(amount_lamports),
+            lamports_to_sol(stake_minimum_delegation)
+        );
+    }
+
+    let total_lamports = amount_lamports
+        .checked_add(rent_exempt_reserve)
+        .ok_or_else(|| anyhow!("Stake amount overflows when adding rent exemption"))?;
+
+    Ok((rent_exempt_reserve, total_lamports))
+}
+
+/// Creates a stake account with the wallet as both authorities and delegates
+/// it in the same transaction
+async fn process_stake_to_validator(
+    ctx: &ScillaContext,
+    amount_lamports: u64,
+    vote_pubkey: &Pubkey,
+) -> anyhow::Result<()> {
+    let (rent_exempt_reserve, total_lamports) =
+        new_stake_account_lamports(ctx, amount_lamports).await?;
+
+    let stake_keypair = Keypair::new();
+    let stake_pubkey = stake_keypair.pubkey();
+
+    let mut instructions = instruction::create_account(
+        ctx.pubkey(),
+        &stake_pubkey,
+        &Authorized::auto(ctx.pubkey()),
+        &Lockup::default(),
+        total_lamports,
+    );
+    instructions.push(delegate_stake(&stake_pubkey, ctx.pubkey(), vote_pubkey));
+
+    ensure_sufficient_balance(
+        ctx,
+        &instructions,
+        &[
+            ("stake", amount_lamports),
+            ("rent exemption", rent_exempt_reserve),
+        ],
+    )
+    .await?;
+
+    confirm_fee(ctx, &instructions).await?;
+
+    let signature = build_and_send_tx(ctx, &instructions, &[ctx.signer(), &stake_keypair]).await?;
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "stake_account": stake_pubkey.to_string(),
+            "vote_account": vote_pubkey.to_string(),
+            "lamports": amount_lamports,
+            "rent_exempt_reserve": rent_exempt_reserve,
+        }),
+        || {
+            println!(
+                "\n{} {}\n{}\n{}\n{}",
+                style("Stake Delegated Successfully!").green().bold(),
+                style("(Activation will take effect at the next epoch boundary)").yellow(),
+                style(format!("Stake Account: {stake_pubkey}")).yellow(),
+                style(format!(
+                    "Amount: {} SOL to {vote_pubkey}",
+                    lamports_to_sol(amount_lamports)
+                ))
+                .yellow(),
+                style(format!("Signature: {signature}")).cyan()
+            );
+        },
+    )
+}
+
+/// Asks for a validator's vote account, offering a searchable list when left
+/// empty. Returns `None` when a high commission warning was declined.
+async fn prompt_validator(ctx: &ScillaContext) -> anyhow::Result<Option<Pubkey>> {
+    let vote_pubkey: OptionalInput<Pubkey> =
+        prompt_data("Enter Validator Vote Account Pubkey (leave empty to pick from a list):")?;
+    let vote_pubkey = match vote_pubkey.value() {
+        Some(vote_pubkey) => vote_pubkey,
+        None => {
+            let validators =
+                show_spinner("Fetching validators…", fetch_validator_choices(ctx)).await?;
+            prompt_select(
+                Select::new("Validator (type to search):", validators)
+                    .with_page_size(VALIDATOR_SELECT_PAGE_SIZE),
+            )?
+            .vote_pubkey
+        }
+    };
+
+    let commission = show_spinner(
+        "Fetching validator vote account…",
+        fetch_vote_commission(ctx, &vote_pubkey),
+    )
+    .await?;
+
+    if commission > HIGH_COMMISSION_THRESHOLD {
+        notice!(
+            "{}",
+            style(format!(
+                "⚠ Validator commission is {commission}%, above the {HIGH_COMMISSION_THRESHOLD}% \
+                 threshold"
+            ))
+            .yellow()
+            .bold()
+        );
+
+        let proceed = prompt_confirm("Delegate to this validator anyway?")?;
+
+        if !proceed {
+            return Ok(None);
+        }
+    }
+
+    Ok(Some(vote_pubkey))
+}
+
+/// A validator as listed in the delegation picker
+struct ValidatorChoice {
+    vote_pubkey: Pubkey,
+    identity: String,
+    commission: u8,
+    activated_stake: u64,
+}
+
+impl fmt::Display for ValidatorChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}  vote {}  {}% commission  {:.0} SOL staked",
+            self.identity,
+            self.vote_pubkey,
+            self.commission,
+            lamports_to_sol(self.activated_stake)
+        )
+    }
+}
+
+/// Validators that are currently voting, largest stake first
+async fn fetch_validator_choices(ctx: &ScillaContext) -> anyhow::Result<Vec<ValidatorChoice>> {
+    let mut validators = ctx
+        .rpc()
+        .get_vote_accounts()
+        .await?
+        .current
+        .into_iter()
+        .map(|validator| {
+            Ok(ValidatorChoice {
+                vote_pubkey: validator.vote_pubkey.parse()?,
+                identity: validator.node_pubkey,
+                commission: validator.commission,
+                activated_stake: validator.activated_stake,
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    if validators.is_empty() {
+        bail!("No active validators found on this cluster");
+    }
+
+    validators.sort_by_key(|validator| std::cmp::Reverse(validator.activated_stake));
+    Ok(validators)
+}
+
+async fn fetch_vote_commission(ctx: &ScillaContext, vote_pubkey: &Pubkey) -> anyhow::Result<u8> {
+    let vote_account = ctx
+        .rpc()
+        .get_account(vote_pubkey)
+        .await
+        .map_err(|_| anyhow!("{vote_pubkey} account does not exist"))?;
+
+    vote_commission(vote_pubkey, &vote_account)
+}
+
+fn vote_commission(vote_pubkey: &Pubkey, vote_account: &Account) -> anyhow::Result<u8> {
+    if vote_account.owner != solana_vote_program::id() {
+        bail!("{vote_pubkey} is not a vote account");
+    }
+
+    let vote_state = VoteStateV4::deserialize(&vote_account.data, vote_pubkey)
+        .map_err(|_| anyhow!("Account data could not be deserialized to vote state"))?;
+
+    Ok((vote_state.inflation_rewards_commission_bps / 100) as u8)
+}
+
+async fn process_delegate_stake(
     ctx: &ScillaContext,
     stake_pubkey: &Pubkey,
+    vote_pubkey: &Pubkey,
 ) -> anyhow::Result<()> {
     let account = ctx.rpc().get_account(stake_pubkey).await?;
 
@@ -161,6 +881,97 @@ async fn process_deactivate_stake_account(
 
     let stake_state: StakeStateV2 = bincode_deserialize(&account.data, "stake account data")?;
 
+    match stake_state {
+        StakeStateV2::Initialized(meta) => {
+            if &meta.authorized.staker != ctx.pubkey() {
+                bail!(
+                    "You are not the authorized staker. Authorized staker: {}",
+                    meta.authorized.staker
+                );
+            }
+        }
+        StakeStateV2::Stake(_, stake, _) => {
+            bail!(
+                "Stake account is already delegated to {}",
+                stake.delegation.voter_pubkey
+            );
+        }
+        _ => {
+            bail!("Stake account is not in a valid state for delegation");
+        }
+    }
+
+    let instruction = delegate_stake(stake_pubkey, ctx.pubkey(), vote_pubkey);
+
+    confirm_fee(ctx, std::slice::from_ref(&instruction)).await?;
+
+    let signature = build_and_send_tx(ctx, &[instruction], &[ctx.signer()]).await?;
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "stake_account": stake_pubkey.to_string(),
+            "vote_account": vote_pubkey.to_string(),
+        }),
+        || {
+            println!(
+                "\n{} {}\n{}\n{}\n{}",
+                style("Stake Delegated Successfully!").green().bold(),
+                style("(Activation will take effect at the next epoch boundary)").yellow(),
+                style(format!("Stake Account: {stake_pubkey}")).yellow(),
+                style(format!("Vote Account: {vote_pubkey}")).yellow(),
+                style(format!("Signature: {signature}")).cyan()
+            );
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Prints what a stake operation is about to do and asks to go ahead. The
+/// summary is left out of JSON output, where `--yes` skips the question.
+fn confirm_stake_action(
+    ctx: &ScillaContext,
+    summary: &[(&str, String)],
+    question: &str,
+) -> anyhow::Result<bool> {
+    if ctx.output_format() == OutputFormat::Text {
+        let mut table = new_table();
+        table.load_preset(UTF8_FULL).set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ]);
+        for (field, value) in summary {
+            table.add_row(vec![Cell::new(field), Cell::new(value)]);
+        }
+
+        notice!("\n{}", style("REVIEW").yellow().bold());
+        notice!("{}", table);
+    }
+
+    prompt_confirm(question)
+}
+
+/// A deactivation that passed the on-chain checks
+struct DeactivatePlan {
+    epoch_info: EpochInfo,
+    delegated_lamports: u64,
+    voter: Pubkey,
+}
+
+async fn plan_deactivate_stake(
+    ctx: &ScillaContext,
+    stake_pubkey: &Pubkey,
+) -> anyhow::Result<DeactivatePlan> {
+    let (account, epoch_info) = fetch_account_with_epoch(ctx, stake_pubkey).await?;
+
+    if account.owner != stake_program_id() {
+        bail!("Account is not owned by the stake program");
+    }
+
+    let stake_state: StakeStateV2 = bincode_deserialize(&account.data, "stake account data")?;
+
     match stake_state {
         StakeStateV2::Stake(meta, stake, _) => {
             if stake.delegation.deactivation_epoch != ACTIVE_STAKE_EPOCH_BOUND {
@@ -176,6 +987,12 @@ async fn process_deactivate_stake_account(
                     meta.authorized.staker
                 );
             }
+
+            Ok(DeactivatePlan {
+                epoch_info,
+                delegated_lamports: stake.delegation.stake,
+                voter: stake.delegation.voter_pubkey,
+            })
         }
         StakeStateV2::Initialized(_) => {
             bail!("Stake account is initialized but not delegated");
@@ -184,32 +1001,151 @@ async fn process_deactivate_stake_account(
             bail!("Stake account is not in a valid state for deactivation");
         }
     }
+}
 
+async fn process_deactivate_stake_account(
+    ctx: &ScillaContext,
+    stake_pubkey: &Pubkey,
+    plan: &DeactivatePlan,
+) -> anyhow::Result<()> {
+    let epoch_info = &plan.epoch_info;
     let authorized_pubkey = ctx.pubkey();
     let instruction = deactivate_stake(stake_pubkey, authorized_pubkey);
 
-    let signature = build_and_send_tx(ctx, &[instruction], &[ctx.keypair()]).await?;
+    let signature = build_and_send_tx(ctx, &[instruction], &[ctx.signer()]).await?;
 
-    println!(
-        "\n{} {}\n{}\n{}",
-        style("Stake Deactivated Successfully!").green().bold(),
-        style("(Cooldown will take 1-2 epochs ≈ 2-4 days)").yellow(),
-        style(format!("Stake Account: {stake_pubkey}")).yellow(),
-        style(format!("Signature: {signature}")).cyan()
+    // Cooldown ends when the epoch after the deactivation epoch starts, unless
+    // the cluster-wide cooldown limit spreads it over more epochs
+    let withdrawable_epoch = epoch_info.epoch + 1;
+    let slot_time_ms = average_slot_time_ms(ctx).await;
+    let eta = slots_to_duration(
+        slots_until_epoch(epoch_info, withdrawable_epoch),
+        slot_time_ms,
     );
 
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "stake_account": stake_pubkey.to_string(),
+            "deactivation_epoch": epoch_info.epoch,
+            "withdrawable_epoch": withdrawable_epoch,
+            "withdrawable_in_secs": eta.as_secs(),
+        }),
+        || {
+            println!(
+                "\n{}\n{}\n{}\n{}\n{}",
+                style("Stake Deactivated Successfully!").green().bold(),
+                style(format!("Stake Account: {stake_pubkey}")).yellow(),
+                style(format!("Deactivation Epoch: {}", epoch_info.epoch)).cyan(),
+                style(format!(
+                    "Withdrawable From: {}",
+                    describe_epoch_eta(epoch_info, withdrawable_epoch, slot_time_ms)
+                ))
+                .cyan(),
+                style(format!("Signature: {signature}")).cyan()
+            );
+        },
+    )?;
+
     Ok(())
 }
 
-async fn process_withdraw_stake(
+/// Splits `lamports` off into a new stake account and deactivates only that
+/// account in the same transaction, leaving the rest delegated
+async fn process_deactivate_partial(
     ctx: &ScillaContext,
     stake_pubkey: &Pubkey,
-    recipient: &Pubkey,
-    amount_sol: f64,
+    lamports: u64,
+    plan: &DeactivatePlan,
+    keypair_path: Option<&Path>,
 ) -> anyhow::Result<()> {
-    let amount_lamports = sol_to_lamports(amount_sol);
+    let epoch_info = &plan.epoch_info;
+    let authorized_pubkey = ctx.pubkey();
+    let split_stake_keypair = Keypair::new();
+    let split_stake_pubkey = split_stake_keypair.pubkey();
+
+    let mut instructions = split_stake_instructions(
+        ctx,
+        stake_pubkey,
+        authorized_pubkey,
+        lamports,
+        &split_stake_pubkey,
+    )
+    .await?;
+    instructions.push(deactivate_stake(&split_stake_pubkey, authorized_pubkey));
+
+    if let Some(path) = keypair_path {
+        save_keypair(&split_stake_keypair, path)?;
+    }
+
+    let signature =
+        build_and_send_tx(ctx, &instructions, &[ctx.signer(), &split_stake_keypair]).await?;
+
+    let withdrawable_epoch = epoch_info.epoch + 1;
+    let slot_time_ms = average_slot_time_ms(ctx).await;
+    let eta = slots_to_duration(
+        slots_until_epoch(epoch_info, withdrawable_epoch),
+        slot_time_ms,
+    );
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "stake_account": stake_pubkey.to_string(),
+            "deactivated_stake_account": split_stake_pubkey.to_string(),
+            "deactivated_lamports": lamports,
+            "keypair_path": keypair_path.map(|path| path.display().to_string()),
+            "deactivation_epoch": epoch_info.epoch,
+            "withdrawable_epoch": withdrawable_epoch,
+            "withdrawable_in_secs": eta.as_secs(),
+        }),
+        || {
+            println!(
+                "\n{}\n{}\n{}\n{}\n{}\n{}",
+                style("Stake Partially Deactivated Successfully!")
+                    .green()
+                    .bold(),
+                style(format!("Still Active: {stake_pubkey}")).yellow(),
+                style(format!(
+                    "Deactivating: {} SOL in {split_stake_pubkey}",
+                    lamports_to_sol(lamports)
+                ))
+                .yellow(),
+                style(format!("Deactivation Epoch: {}", epoch_info.epoch)).cyan(),
+                style(format!(
+                    "Withdrawable From: {}",
+                    describe_epoch_eta(epoch_info, withdrawable_epoch, slot_time_ms)
+                ))
+                .cyan(),
+                style(format!("Signature: {signature}")).cyan()
+            );
+            if let Some(path) = keypair_path {
+                println!(
+                    "{}",
+                    style(format!("Keypair saved to: {}", path.display())).cyan()
+                );
+            }
+        },
+    )?;
+
+    Ok(())
+}
+
+/// A withdrawal that passed the on-chain checks
+struct WithdrawPlan {
+    amount_lamports: u64,
+    closes_account: bool,
+}
 
+async fn plan_withdraw_stake(
+    ctx: &ScillaContext,
+    stake_pubkey: &Pubkey,
+    amount: WithdrawAmount,
+) -> anyhow::Result<WithdrawPlan> {
     let (account, epoch_info) = fetch_account_with_epoch(ctx, stake_pubkey).await?;
+    let amount_lamports = amount.to_lamports(account.lamports);
 
     if account.owner != stake_program_id() {
         bail!("Account is not owned by the stake program");
@@ -264,10 +1200,27 @@ async fn process_withdraw_stake(
         bail!(
             "Insufficient balance. Have {:.6} SOL, trying to withdraw {:.6} SOL",
             lamports_to_sol(account.lamports),
-            amount_sol
+            lamports_to_sol(amount_lamports)
         );
     }
 
+    Ok(WithdrawPlan {
+        amount_lamports,
+        closes_account: amount_lamports == account.lamports,
+    })
+}
+
+async fn process_withdraw_stake(
+    ctx: &ScillaContext,
+    stake_pubkey: &Pubkey,
+    recipient: &Pubkey,
+    plan: &WithdrawPlan,
+) -> anyhow::Result<()> {
+    let WithdrawPlan {
+        amount_lamports,
+        closes_account,
+    } = *plan;
+    let amount_sol = lamports_to_sol(amount_lamports);
     let withdrawer_pubkey = ctx.pubkey();
 
     let instruction = withdraw(
@@ -278,16 +1231,32 @@ async fn process_withdraw_stake(
         None,
     );
 
-    let signature = build_and_send_tx(ctx, &[instruction], &[ctx.keypair()]).await?;
-
-    println!(
-        "\n{} {}\n{}\n{}\n{}",
-        style("Stake Withdrawn Successfully!").green().bold(),
-        style(format!("From Stake Account: {stake_pubkey}")).yellow(),
-        style(format!("To Recipient: {recipient}")).yellow(),
-        style(format!("Amount: {amount_sol} SOL")).cyan(),
-        style(format!("Signature: {signature}")).cyan()
-    );
+    let signature = simulate_then_send(ctx, &[instruction], &[ctx.signer()]).await?;
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "stake_account": stake_pubkey.to_string(),
+            "recipient": recipient.to_string(),
+            "lamports": amount_lamports,
+            "sol": amount_sol,
+            "closed": closes_account,
+        }),
+        || {
+            println!(
+                "\n{} {}\n{}\n{}\n{}",
+                style("Stake Withdrawn Successfully!").green().bold(),
+                style(format!("From Stake Account: {stake_pubkey}")).yellow(),
+                style(format!("To Recipient: {recipient}")).yellow(),
+                style(format!("Amount: {amount_sol} SOL")).cyan(),
+                style(format!("Signature: {signature}")).cyan()
+            );
+            if closes_account {
+                println!("{}", style("Stake account closed.").dim());
+            }
+        },
+    )?;
 
     Ok(())
 }
@@ -391,102 +1360,1555 @@ async fn process_merge_stake(
         &stake_authority_pubkey,
     );
 
-    let signature =
-        build_and_send_tx(ctx, &ixs, &[ctx.keypair(), &stake_authority_keypair]).await?;
-
-    println!(
-        "{}\n{}\n{}\n{}\n{}\n{}",
-        style("Stake Merged successfully!").yellow().bold(),
-        style(format!(
-            "Destination Stake Account: {}",
-            destination_stake_account_pubkey
-        ))
-        .yellow(),
-        style(format!(
-            "Source Stake Account: {}",
-            source_stake_account_pubkey
-        ))
-        .yellow(),
-        style(format!("Stake Authority: {}", stake_authority_pubkey)).yellow(),
-        style(format!(
-            "After Merge: {} SOL",
-            lamports_to_sol(destination_stake_account.lamports)
-        ))
-        .cyan(),
-        style(format!("Signature: {}", signature)).green()
-    );
+    confirm_fee(ctx, &ixs).await?;
+
+    let signature = build_and_send_tx(ctx, &ixs, &[ctx.signer(), &stake_authority_keypair]).await?;
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "destination_stake_account": destination_stake_account_pubkey.to_string(),
+            "source_stake_account": source_stake_account_pubkey.to_string(),
+            "lamports_after_merge": destination_stake_account.lamports,
+        }),
+        || {
+            println!(
+                "{}\n{}\n{}\n{}\n{}\n{}",
+                style("Stake Merged successfully!").yellow().bold(),
+                style(format!(
+                    "Destination Stake Account: {}",
+                    destination_stake_account_pubkey
+                ))
+                .yellow(),
+                style(format!(
+                    "Source Stake Account: {}",
+                    source_stake_account_pubkey
+                ))
+                .yellow(),
+                style(format!("Stake Authority: {}", stake_authority_pubkey)).yellow(),
+                style(format!(
+                    "After Merge: {} SOL",
+                    lamports_to_sol(destination_stake_account.lamports)
+                ))
+                .cyan(),
+                style(format!("Signature: {}", signature)).green()
+            );
+        },
+    )?;
 
     Ok(())
 }
 
-async fn process_split_stake(
-    ctx: &ScillaContext,
-    stake_account_pubkey: &Pubkey,
-    split_stake_account_pubkey: &Pubkey,
-    stake_authority_keypair_path: &PathBuf,
-    amount_to_split: f64,
-) -> anyhow::Result<()> {
-    let stake_authority_keypair = read_keypair_from_path(stake_authority_keypair_path)?;
-    let stake_authority_pubkey = stake_authority_keypair.pubkey();
-    let lamports: u64 = sol_to_lamports(amount_to_split);
-
-    if stake_account_pubkey == split_stake_account_pubkey {
-        bail!(
-            "Existing Stake Account {} and New Split Stake Account {} must not be the same",
-            stake_account_pubkey,
-            split_stake_account_pubkey
+/// What the stake program requires of two delegations before it merges them
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MergeKind {
+    Inactive,
+    Activating {
+        voter: Pubkey,
+        activation_epoch: u64,
+    },
+    Active {
+        voter: Pubkey,
+    },
+}
+
+impl MergeKind {
+    /// `None` while stake is deactivating or only partly warmed up, which
+    /// the stake program refuses to merge
+    fn of(stake_state: &StakeStateV2, epoch: u64, stake_history: &StakeHistory) -> Option<Self> {
+        let stake = match stake_state {
+            StakeStateV2::Initialized(_) => return Some(Self::Inactive),
+            StakeStateV2::Stake(_, stake, _) => stake,
+            StakeStateV2::Uninitialized | StakeStateV2::RewardsPool => return None,
+        };
+        let delegation = &stake.delegation;
+        let status = delegation.stake_activating_and_deactivating(
+            epoch,
+            stake_history,
+            NEW_WARMUP_COOLDOWN_RATE_EPOCH,
         );
+
+        match (status.effective, status.activating, status.deactivating) {
+            (0, 0, 0) => Some(Self::Inactive),
+            (0, _, 0) => Some(Self::Activating {
+                voter: delegation.voter_pubkey,
+                activation_epoch: delegation.activation_epoch,
+            }),
+            (_, 0, 0) if delegation.deactivation_epoch == ACTIVE_STAKE_EPOCH_BOUND => {
+                Some(Self::Active {
+                    voter: delegation.voter_pubkey,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn voter(&self) -> Option<Pubkey> {
+        match self {
+            Self::Inactive => None,
+            Self::Activating { voter, .. } | Self::Active { voter } => Some(*voter),
+        }
     }
 
-    let stake_minimum_delegation = ctx.rpc().get_stake_minimum_delegation().await?;
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Inactive => "Inactive",
+            Self::Activating { .. } => "Activating",
+            Self::Active { .. } => "Active",
+        }
+    }
+}
+
+/// Stake accounts that can all be folded into the largest of them
+struct MergeGroup {
+    kind: MergeKind,
+    withdrawer: Pubkey,
+    lockup: Lockup,
+    destination: Pubkey,
+    sources: Vec<Pubkey>,
+    lamports: u64,
+}
+
+/// Groups the stake accounts the wallet is staker of by validator, state,
+/// withdrawer and lockup, keeping only groups with something to merge
+async fn find_merge_groups(ctx: &ScillaContext) -> anyhow::Result<Vec<MergeGroup>> {
+    let (accounts, epoch_info, stake_history) = tokio::try_join!(
+        fetch_authorized_stake_accounts(ctx, STAKE_AUTHORIZED_STAKER_OFFSET),
+        ctx.epoch_info(),
+        fetch_stake_history(ctx),
+    )?;
+    Ok(group_for_merge(accounts, epoch_info.epoch, &stake_history))
+}
+
+fn group_for_merge(
+    mut accounts: Vec<(Pubkey, u64, StakeStateV2)>,
+    epoch: u64,
+    stake_history: &StakeHistory,
+) -> Vec<MergeGroup> {
+    // Largest first, so each group merges into its biggest account
+    accounts.sort_by_key(|(_, lamports, _)| std::cmp::Reverse(*lamports));
+
+    let mut groups: Vec<MergeGroup> = Vec::new();
+    for (address, lamports, stake_state) in accounts {
+        let Some(meta) = stake_state.meta() else {
+            continue;
+        };
+        let Some(kind) = MergeKind::of(&stake_state, epoch, stake_history) else {
+            continue;
+        };
 
-    if lamports < stake_minimum_delegation {
+        match groups.iter_mut().find(|group| {
+            group.kind == kind
+                && group.withdrawer == meta.authorized.withdrawer
+                && group.lockup == meta.lockup
+        }) {
+            Some(group) => {
+                group.sources.push(address);
+                group.lamports += lamports;
+            }
+            None => groups.push(MergeGroup {
+                kind,
+                withdrawer: meta.authorized.withdrawer,
+                lockup: meta.lockup,
+                destination: address,
+                sources: Vec::new(),
+                lamports,
+            }),
+        }
+    }
+
+    groups.retain(|group| !group.sources.is_empty());
+    groups
+}
+
+async fn process_merge_all(ctx: &ScillaContext, groups: &[MergeGroup]) -> anyhow::Result<()> {
+    let mut signatures = Vec::new();
+    for group in groups {
+        for chunk in group.sources.chunks(MERGE_STAKE_ACCOUNTS_PER_TX) {
+            let instructions: Vec<_> = chunk
+                .iter()
+                .flat_map(|source| merge(&group.destination, source, ctx.pubkey()))
+                .collect();
+            signatures.push(build_and_send_tx(ctx, &instructions, &[ctx.signer()]).await?);
+        }
+    }
+
+    let merged: usize = groups.iter().map(|group| group.sources.len()).sum();
+    let value = json!({
+        "groups": groups
+            .iter()
+            .map(|group| {
+                json!({
+                    "destination": group.destination.to_string(),
+                    "vote_account": group.kind.voter().map(|voter| voter.to_string()),
+                    "status": group.kind.label(),
+                    "withdrawer": group.withdrawer.to_string(),
+                    "merged": group
+                        .sources
+                        .iter()
+                        .map(|source| source.to_string())
+                        .collect::<Vec<_>>(),
+                    "lamports": group.lamports,
+                })
+            })
+            .collect::<Vec<_>>(),
+        "signatures": signatures
+            .iter()
+            .map(|signature| signature.to_string())
+            .collect::<Vec<_>>(),
+    });
+
+    print_output(ctx, &value, || {
+        println!(
+            "\n{}",
+            style(format!(
+                "Merged {} stake accounts into {}",
+                merged + groups.len(),
+                groups.len()
+            ))
+            .green()
+            .bold()
+        );
+        for group in groups {
+            println!(
+                "{}",
+                style(format!(
+                    "{}: {} SOL",
+                    group.destination,
+                    lamports_to_sol(group.lamports)
+                ))
+                .yellow()
+            );
+        }
+        for signature in &signatures {
+            println!("{}", style(format!("Signature: {signature}")).cyan());
+        }
+    })
+}
+
+/// Bails unless both the split-off `lamports` and the stake left delegated
+/// meet the minimum delegation, which the split would otherwise fail on
+async fn check_partial_deactivation(
+    ctx: &ScillaContext,
+    delegated_lamports: u64,
+    lamports: u64,
+) -> anyhow::Result<()> {
+    new_stake_account_lamports(ctx, lamports).await?;
+
+    let minimum_delegation = ctx.rpc().get_stake_minimum_delegation().await?;
+    let remaining = delegated_lamports.saturating_sub(lamports);
+    if remaining < minimum_delegation {
         bail!(
-            "Need at least {} lamports for minimum stake delegation, but you provided {}",
-            stake_minimum_delegation,
-            lamports
+            "Deactivating {} SOL would leave {} SOL delegated, below the minimum delegation of {} \
+             SOL. Deactivate less, or all of it with Deactivate stake",
+            lamports_to_sol(lamports),
+            lamports_to_sol(remaining),
+            lamports_to_sol(minimum_delegation)
         );
     }
 
-    let ix = instruction::split(
+    Ok(())
+}
+
+/// Instructions that split `lamports` into the new account `split_pubkey`,
+/// which has to sign. Active stake only splits into an account that already
+/// holds its rent-exempt reserve, so the wallet funds that first.
+async fn split_stake_instructions(
+    ctx: &ScillaContext,
+    stake_pubkey: &Pubkey,
+    authorized_pubkey: &Pubkey,
+    lamports: u64,
+    split_pubkey: &Pubkey,
+) -> anyhow::Result<Vec<Instruction>> {
+    let (rent_exempt_reserve, _) = new_stake_account_lamports(ctx, lamports).await?;
+
+    let mut instructions = vec![transfer(ctx.pubkey(), split_pubkey, rent_exempt_reserve)];
+    instructions.extend(instruction::split(
+        stake_pubkey,
+        authorized_pubkey,
+        lamports,
+        split_pubkey,
+    ));
+    Ok(instructions)
+}
+
+/// Splits into a freshly generated stake account, which has to sign its own
+/// allocation. Its keypair is written to `keypair_path` before anything is
+/// sent, so the secret is never lost to a failed write.
+async fn process_split_stake(
+    ctx: &ScillaContext,
+    stake_account_pubkey: &Pubkey,
+    stake_authority_keypair_path: &PathBuf,
+    lamports: u64,
+    keypair_path: Option<&Path>,
+) -> anyhow::Result<()> {
+    let stake_authority_keypair = read_keypair_from_path(stake_authority_keypair_path)?;
+    let stake_authority_pubkey = stake_authority_keypair.pubkey();
+    let split_stake_keypair = Keypair::new();
+    let split_stake_account_pubkey = &split_stake_keypair.pubkey();
+
+    let ix = split_stake_instructions(
+        ctx,
         stake_account_pubkey,
         &stake_authority_pubkey,
         lamports,
         split_stake_account_pubkey,
-    );
+    )
+    .await?;
 
-    let signature = build_and_send_tx(ctx, &ix, &[ctx.keypair(), &stake_authority_keypair]).await?;
+    confirm_fee(ctx, &ix).await?;
 
-    println!(
-        "{}\n{}\n{}\n{}\n{}",
-        style("Split Stake successfully!").yellow().bold(),
-        style(format!("Stake Account: {}", stake_account_pubkey)).yellow(),
-        style(format!(
-            "Split Stake Account: {}",
-            split_stake_account_pubkey
-        ))
-        .yellow(),
-        style(format!("Stake Authority: {}", stake_authority_pubkey)).yellow(),
-        style(format!("Signature: {}", signature)).green()
+    if let Some(path) = keypair_path {
+        save_keypair(&split_stake_keypair, path)?;
+    }
+
+    let signature = build_and_send_tx(
+        ctx,
+        &ix,
+        &[ctx.signer(), &stake_authority_keypair, &split_stake_keypair],
+    )
+    .await?;
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "stake_account": stake_account_pubkey.to_string(),
+            "split_stake_account": split_stake_account_pubkey.to_string(),
+            "keypair_path": keypair_path.map(|path| path.display().to_string()),
+        }),
+        || {
+            match keypair_path {
+                Some(path) => println!(
+                    "{}",
+                    style(format!("Split stake keypair saved to: {}", path.display())).cyan()
+                ),
+                None => println!(
+                    "{}",
+                    style(
+                        "The split stake keypair was not saved. The stake authority still \
+                         controls the account."
+                    )
+                    .dim()
+                ),
+            }
+            println!(
+                "{}\n{}\n{}\n{}\n{}",
+                style("Split Stake successfully!").yellow().bold(),
+                style(format!("Stake Account: {}", stake_account_pubkey)).yellow(),
+                style(format!(
+                    "Split Stake Account: {}",
+                    split_stake_account_pubkey
+                ))
+                .yellow(),
+                style(format!("Stake Authority: {}", stake_authority_pubkey)).yellow(),
+                style(format!("Signature: {}", signature)).green()
+            );
+        },
+    )?;
+
+    Ok(())
+}
+
+async fn process_authorize_stake(
+    ctx: &ScillaContext,
+    stake_pubkey: &Pubkey,
+    stake_authorize: StakeAuthorize,
+    new_authority: &Pubkey,
+) -> anyhow::Result<()> {
+    let account = ctx.rpc().get_account(stake_pubkey).await?;
+
+    if account.owner != stake_program_id() {
+        bail!("Account is not owned by the stake program");
+    }
+
+    let stake_state: StakeStateV2 = bincode_deserialize(&account.data, "stake account data")?;
+
+    let meta = match stake_state {
+        StakeStateV2::Initialized(meta) | StakeStateV2::Stake(meta, _, _) => meta,
+        _ => bail!("Stake account is not in a valid state for changing authorities"),
+    };
+
+    let (authority_label, old_authority) = match stake_authorize {
+        StakeAuthorize::Staker => ("Staker", meta.authorized.staker),
+        StakeAuthorize::Withdrawer => ("Withdrawer", meta.authorized.withdrawer),
+    };
+
+    if &old_authority != ctx.pubkey() {
+        bail!(
+            "You are not the authorized {authority_label}. Authorized {authority_label}: \
+             {old_authority}"
+        );
+    }
+
+    if &old_authority == new_authority {
+        bail!("{new_authority} is already the authorized {authority_label}");
+    }
+
+    let instruction = authorize(
+        stake_pubkey,
+        ctx.pubkey(),
+        new_authority,
+        stake_authorize,
+        None,
     );
 
+    confirm_fee(ctx, std::slice::from_ref(&instruction)).await?;
+
+    let signature = build_and_send_tx(ctx, &[instruction], &[ctx.signer()]).await?;
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "stake_account": stake_pubkey.to_string(),
+            "authority_type": authority_label,
+            "old_authority": old_authority.to_string(),
+            "new_authority": new_authority.to_string(),
+        }),
+        || {
+            println!(
+                "\n{}\n{}\n{}\n{}\n{}",
+                style(format!(
+                    "Stake {authority_label} Authority Updated Successfully!"
+                ))
+                .green()
+                .bold(),
+                style(format!("Stake Account: {stake_pubkey}")).yellow(),
+                style(format!("Old {authority_label}: {old_authority}")).yellow(),
+                style(format!("New {authority_label}: {new_authority}")).yellow(),
+                style(format!("Signature: {signature}")).cyan()
+            );
+        },
+    )?;
+
+    Ok(())
+}
+
+async fn process_set_lockup(
+    ctx: &ScillaContext,
+    stake_pubkey: &Pubkey,
+    lockup: &LockupArgs,
+) -> anyhow::Result<()> {
+    if lockup.unix_timestamp.is_none() && lockup.epoch.is_none() && lockup.custodian.is_none() {
+        bail!("Provide at least one of unix timestamp, epoch or custodian to set a lockup");
+    }
+
+    let account = ctx.rpc().get_account(stake_pubkey).await?;
+
+    if account.owner != stake_program_id() {
+        bail!("Account is not owned by the stake program");
+    }
+
+    let stake_state: StakeStateV2 = bincode_deserialize(&account.data, "stake account data")?;
+
+    let meta = match stake_state {
+        StakeStateV2::Initialized(meta) | StakeStateV2::Stake(meta, _, _) => meta,
+        _ => bail!("Stake account is not in a valid state for setting a lockup"),
+    };
+
+    if &meta.authorized.withdrawer != ctx.pubkey() && &meta.lockup.custodian != ctx.pubkey() {
+        bail!(
+            "You are neither the authorized withdrawer ({}) nor the lockup custodian ({})",
+            meta.authorized.withdrawer,
+            meta.lockup.custodian
+        );
+    }
+
+    let instruction = set_lockup(stake_pubkey, lockup, ctx.pubkey());
+
+    confirm_fee(ctx, std::slice::from_ref(&instruction)).await?;
+
+    let signature = build_and_send_tx(ctx, &[instruction], &[ctx.signer()]).await?;
+
+    let unix_timestamp = lockup.unix_timestamp.unwrap_or(meta.lockup.unix_timestamp);
+    let epoch = lockup.epoch.unwrap_or(meta.lockup.epoch);
+    let custodian = lockup.custodian.unwrap_or(meta.lockup.custodian);
+
+    let mut table = new_table();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![
+            Cell::new("Unix Timestamp"),
+            Cell::new(unix_timestamp.to_string()),
+        ])
+        .add_row(vec![Cell::new("Epoch"), Cell::new(epoch.to_string())])
+        .add_row(vec![
+            Cell::new("Custodian"),
+            Cell::new(custodian.to_string()),
+        ]);
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "stake_account": stake_pubkey.to_string(),
+            "unix_timestamp": unix_timestamp,
+            "epoch": epoch,
+            "custodian": custodian.to_string(),
+        }),
+        || {
+            println!(
+                "\n{}\n{}\n{}",
+                style("Stake Lockup Updated Successfully!").green().bold(),
+                style(format!("Stake Account: {stake_pubkey}")).yellow(),
+                style(format!("Signature: {signature}")).cyan()
+            );
+            println!("\n{}", style("STAKE LOCKUP").green().bold());
+            println!("{}", table);
+        },
+    )?;
+
     Ok(())
 }
 
-async fn process_stake_history(ctx: &ScillaContext) -> anyhow::Result<()> {
+async fn fetch_stake_history(ctx: &ScillaContext) -> anyhow::Result<StakeHistory> {
     let stake_history_sysvar = Pubkey::from_str_const(STAKE_HISTORY_SYSVAR_ADDR);
 
     let account = ctx.rpc().get_account(&stake_history_sysvar).await?;
 
-    let stake_history: StakeHistory =
-        bincode_deserialize_with_limit(account.data.len() as u64, &account.data, "stake history")?;
+    bincode_deserialize_with_limit(account.data.len() as u64, &account.data, "stake history")
+}
 
-    if stake_history.is_empty() {
-        println!("\n{}", style("No stake history available").yellow());
+/// Describes when `target_epoch` starts, e.g. `epoch 512 (in ~1d 2h 3m,
+/// around 2026-10-17 14:05 UTC)`
+fn describe_epoch_eta(epoch_info: &EpochInfo, target_epoch: u64, slot_time_ms: f64) -> String {
+    let remaining = slots_to_duration(slots_until_epoch(epoch_info, target_epoch), slot_time_ms);
+    if remaining.is_zero() {
+        return format!("epoch {target_epoch} (reached)");
+    }
+
+    let eta = chrono::Utc::now() + chrono::Duration::from_std(remaining).unwrap_or_default();
+    format!(
+        "epoch {target_epoch} (in ~{}, around {})",
+        format_duration(remaining),
+        eta.format("%Y-%m-%d %H:%M UTC")
+    )
+}
+
+/// A one-line description of a stake account's delegation, or `None` for
+/// accounts that aren't stake accounts
+pub fn stake_state_summary(account: &Account) -> Option<String> {
+    if account.owner != stake_program_id() {
+        return None;
+    }
+
+    let summary = match bincode_deserialize(&account.data, "stake account data").ok()? {
+        StakeStateV2::Stake(_, stake, _) => {
+            let delegation = stake.delegation;
+            if delegation.deactivation_epoch == ACTIVE_STAKE_EPOCH_BOUND {
+                format!(
+                    "{} SOL delegated to {}",
+                    lamports_to_sol(delegation.stake),
+                    delegation.voter_pubkey
+                )
+            } else {
+                format!(
+                    "{} SOL deactivating since epoch {}",
+                    lamports_to_sol(delegation.stake),
+                    delegation.deactivation_epoch
+                )
+            }
+        }
+        StakeStateV2::Initialized(_) => "Initialized, not delegated".to_string(),
+        StakeStateV2::Uninitialized => "Uninitialized".to_string(),
+        StakeStateV2::RewardsPool => "Rewards pool".to_string(),
+    };
+
+    Some(summary)
+}
+
+fn activation_state_label(status: &StakeActivationStatus, deactivation_epoch: u64) -> &'static str {
+    if status.deactivating > 0 {
+        "Deactivating"
+    } else if status.activating > 0 {
+        "Activating"
+    } else if status.effective > 0 && deactivation_epoch == ACTIVE_STAKE_EPOCH_BOUND {
+        "Active"
+    } else {
+        "Inactive"
+    }
+}
+
+async fn process_show_stake_account(
+    ctx: &ScillaContext,
+    stake_pubkey: &Pubkey,
+) -> anyhow::Result<()> {
+    let (account, epoch_info) = fetch_account_with_epoch(ctx, stake_pubkey).await?;
+
+    if account.owner != stake_program_id() {
+        bail!("Account is not owned by the stake program");
+    }
+
+    let stake_state: StakeStateV2 = bincode_deserialize(&account.data, "stake account data")?;
+
+    let (state_label, meta, stake) = match &stake_state {
+        StakeStateV2::Initialized(meta) => ("Initialized", meta, None),
+        StakeStateV2::Stake(meta, stake, _) => ("Delegated", meta, Some(stake)),
+        StakeStateV2::Uninitialized => bail!("Stake account is uninitialized"),
+        StakeStateV2::RewardsPool => bail!("Account is a stake rewards pool"),
+    };
+
+    let mut value = json!({
+        "address": stake_pubkey.to_string(),
+        "state": state_label,
+        "lamports": account.lamports,
+        "rent_exempt_reserve": meta.rent_exempt_reserve,
+        "authorized_staker": meta.authorized.staker.to_string(),
+        "authorized_withdrawer": meta.authorized.withdrawer.to_string(),
+        "lockup": {
+            "unix_timestamp": meta.lockup.unix_timestamp,
+            "epoch": meta.lockup.epoch,
+            "custodian": meta.lockup.custodian.to_string(),
+        },
+    });
+
+    let mut table = new_table();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(vec![
+            Cell::new("Field").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Value").add_attribute(comfy_table::Attribute::Bold),
+        ])
+        .add_row(vec![
+            Cell::new("Address"),
+            Cell::new(stake_pubkey.to_string()),
+        ])
+        .add_row(vec![Cell::new("State"), Cell::new(state_label)])
+        .add_row(vec![
+            Cell::new("Balance (SOL)"),
+            Cell::new(lamports_to_sol(account.lamports)),
+        ])
+        .add_row(vec![
+            Cell::new("Rent Exempt Reserve (SOL)"),
+            Cell::new(lamports_to_sol(meta.rent_exempt_reserve)),
+        ]);
+
+    if let Some(stake) = stake {
+        let delegation = &stake.delegation;
+        let stake_history = fetch_stake_history(ctx).await?;
+        let status = delegation.stake_activating_and_deactivating(
+            epoch_info.epoch,
+            &stake_history,
+            NEW_WARMUP_COOLDOWN_RATE_EPOCH,
+        );
+        let inactive_lamports = account
+            .lamports
+            .saturating_sub(status.effective)
+            .saturating_sub(meta.rent_exempt_reserve);
+
+        // Warmup and cooldown normally finish when the next epoch starts; very
+        // large changes can be spread over more epochs by the cluster limit
+        let completion = if status.deactivating > 0 {
+            Some((
+                "Fully Inactive (est.)",
+                (delegation.deactivation_epoch + 1).max(epoch_info.epoch + 1),
+            ))
+        } else if status.activating > 0 {
+            Some((
+                "Fully Active (est.)",
+                (delegation.activation_epoch + 1).max(epoch_info.epoch + 1),
+            ))
+        } else {
+            None
+        };
+        let slot_time_ms = match completion {
+            Some(_) => average_slot_time_ms(ctx).await,
+            None => 0.0,
+        };
+
+        value["delegation"] = json!({
+            "vote_account": delegation.voter_pubkey.to_string(),
+            "stake": delegation.stake,
+            "activation_epoch": delegation.activation_epoch,
+            "deactivation_epoch": (delegation.deactivation_epoch != ACTIVE_STAKE_EPOCH_BOUND)
+                .then_some(delegation.deactivation_epoch),
+            "status": activation_state_label(&status, delegation.deactivation_epoch),
+            "active_stake": status.effective,
+            "inactive_stake": inactive_lamports,
+            "current_epoch": epoch_info.epoch,
+            "transition_complete_epoch": completion.map(|(_, epoch)| epoch),
+            "transition_complete_in_secs": completion.map(|(_, epoch)| {
+                slots_to_duration(slots_until_epoch(&epoch_info, epoch), slot_time_ms).as_secs()
+            }),
+        });
+
+        let deactivation_epoch = if delegation.deactivation_epoch == ACTIVE_STAKE_EPOCH_BOUND {
+            "~".to_string()
+        } else {
+            delegation.deactivation_epoch.to_string()
+        };
+
+        table
+            .add_row(vec![
+                Cell::new("Delegated Vote Account"),
+                Cell::new(delegation.voter_pubkey.to_string()),
+            ])
+            .add_row(vec![
+                Cell::new("Delegated Stake (SOL)"),
+                Cell::new(lamports_to_sol(delegation.stake)),
+            ])
+            .add_row(vec![
+                Cell::new("Activation Epoch"),
+                Cell::new(delegation.activation_epoch.to_string()),
+            ])
+            .add_row(vec![
+                Cell::new("Deactivation Epoch"),
+                Cell::new(deactivation_epoch),
+            ])
+            .add_row(vec![
+                Cell::new("Activation Status"),
+                Cell::new(activation_state_label(
+                    &status,
+                    delegation.deactivation_epoch,
+                )),
+            ])
+            .add_row(vec![
+                Cell::new("Current Epoch"),
+                Cell::new(epoch_info.epoch.to_string()),
+            ])
+            .add_row(vec![
+                Cell::new("Active Stake (SOL)"),
+                Cell::new(lamports_to_sol(status.effective)),
+            ])
+            .add_row(vec![
+                Cell::new("Inactive Stake (SOL)"),
+                Cell::new(lamports_to_sol(inactive_lamports)),
+            ]);
+
+        if let Some((label, epoch)) = completion {
+            table.add_row(vec![
+                Cell::new(label),
+                Cell::new(describe_epoch_eta(&epoch_info, epoch, slot_time_ms)),
+            ]);
+        }
+    }
+
+    table
+        .add_row(vec![
+            Cell::new("Authorized Staker"),
+            Cell::new(meta.authorized.staker.to_string()),
+        ])
+        .add_row(vec![
+            Cell::new("Authorized Withdrawer"),
+            Cell::new(meta.authorized.withdrawer.to_string()),
+        ])
+        .add_row(vec![
+            Cell::new("Lockup Unix Timestamp"),
+            Cell::new(meta.lockup.unix_timestamp.to_string()),
+        ])
+        .add_row(vec![
+            Cell::new("Lockup Epoch"),
+            Cell::new(meta.lockup.epoch.to_string()),
+        ])
+        .add_row(vec![
+            Cell::new("Lockup Custodian"),
+            Cell::new(meta.lockup.custodian.to_string()),
+        ]);
+
+    print_output(ctx, &value, || {
+        println!("\n{}", style("STAKE ACCOUNT INFO").green().bold());
+        println!("{}", table);
+    })
+}
+
+/// Inflation reward paid to an account in one epoch, `None` when nothing was
+/// paid
+struct EpochReward {
+    epoch: u64,
+    reward: Option<RpcInflationReward>,
+    apy: Option<f64>,
+}
+
+/// The last `count` epochs that have paid out their rewards. Rewards for an
+/// epoch are paid out at the start of the next one, so the current epoch is
+/// never included.
+fn completed_epochs(epoch_info: &EpochInfo, count: u64) -> Range<u64> {
+    epoch_info.epoch.saturating_sub(count)..epoch_info.epoch
+}
+
+/// Looks up the rewards for `epochs`, most recent first. Each epoch needs its
+/// own request, so progress is reported on the spinner.
+async fn fetch_epoch_rewards(
+    ctx: &ScillaContext,
+    address: &Pubkey,
+    epochs: impl DoubleEndedIterator<Item = u64>,
+    spinner: &ProgressBar,
+) -> anyhow::Result<Vec<EpochReward>> {
+    let epoch_info = ctx.epoch_info().await?;
+    let epochs_per_year =
+        epochs_per_year(epoch_info.slots_in_epoch, average_slot_time_ms(ctx).await);
+
+    let mut rewards = Vec::new();
+    for epoch in epochs.rev() {
+        spinner.set_message(format!("Fetching rewards for epoch {epoch}…"));
+
+        let reward = ctx
+            .rpc()
+            .get_inflation_reward(&[*address], Some(epoch))
+            .await?
+            .into_iter()
+            .next()
+            .flatten();
+        let apy = reward.as_ref().and_then(|reward| {
+            annualized_yield(reward.amount, reward.post_balance, epochs_per_year)
+        });
+
+        rewards.push(EpochReward { epoch, reward, apy });
+    }
+
+    Ok(rewards)
+}
+
+/// Writes one CSV row per epoch in the range, oldest first. Epochs where the
+/// account earned nothing are kept as zero-reward rows so the ledger has no
+/// holes. The date is the block time of the slot the reward was paid in, or of
+/// the first slot of the following epoch when there was no reward.
+async fn process_export_stake_rewards(
+    ctx: &ScillaContext,
+    stake_pubkey: &Pubkey,
+    first_epoch: u64,
+    last_epoch: Option<u64>,
+    path: &Path,
+    spinner: ProgressBar,
+) -> anyhow::Result<()> {
+    let epoch_info = ctx.epoch_info().await?;
+    let epoch_schedule = ctx.rpc().get_epoch_schedule().await?;
+    let last_completed = completed_epochs(&epoch_info, 1)
+        .next_back()
+        .ok_or_else(|| anyhow!("No epoch has completed yet, so there are no rewards to export"))?;
+    let last_epoch = last_epoch.unwrap_or(last_completed);
+    if last_epoch > last_completed {
+        bail!(
+            "Epoch {last_epoch} has not paid rewards yet. The last completed epoch is \
+             {last_completed}"
+        );
+    }
+    if first_epoch > last_epoch {
+        bail!("First epoch {first_epoch} is after last epoch {last_epoch}");
+    }
+
+    let mut rewards =
+        fetch_epoch_rewards(ctx, stake_pubkey, first_epoch..=last_epoch, &spinner).await?;
+    rewards.reverse();
+
+    let mut rows = Vec::with_capacity(rewards.len());
+    for entry in &rewards {
+        spinner.set_message(format!("Fetching payout date for epoch {}…", entry.epoch));
+        let slot = entry.reward.as_ref().map_or_else(
+            || epoch_schedule.get_first_slot_in_epoch(entry.epoch + 1),
+            |reward| reward.effective_slot,
+        );
+        // Older blocks may have been pruned by the node, which only costs the date
+        let date = ctx
+            .rpc()
+            .get_block_time(slot)
+            .await
+            .ok()
+            .and_then(chrono::DateTime::<chrono::Utc>::from_timestamp_secs)
+            .map_or(String::new(), |dt| {
+                dt.format("%Y-%m-%d %H:%M:%S").to_string()
+            });
+
+        rows.push(vec![
+            entry.epoch.to_string(),
+            date,
+            format_sol_exact(entry.reward.as_ref().map_or(0, |reward| reward.amount)),
+            entry.reward.as_ref().map_or(String::new(), |reward| {
+                format_sol_exact(reward.post_balance)
+            }),
+            entry.apy.map_or(String::new(), |apy| format!("{apy:.4}")),
+        ]);
+    }
+
+    let csv = to_csv(
+        &[
+            "epoch",
+            "date_utc",
+            "reward_sol",
+            "post_balance_sol",
+            "apy_pct",
+        ],
+        rows,
+    );
+    fs::write(path, csv).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    let rewarded: Vec<_> = rewards
+        .iter()
+        .filter_map(|entry| entry.reward.as_ref())
+        .collect();
+    let total: u64 = rewarded.iter().map(|reward| reward.amount).sum();
+
+    print_output(
+        ctx,
+        &json!({
+            "stake_account": stake_pubkey.to_string(),
+            "path": path.display().to_string(),
+            "first_epoch": first_epoch,
+            "last_epoch": last_epoch,
+            "epochs": rewards.len(),
+            "epochs_with_rewards": rewarded.len(),
+            "total_reward_lamports": total,
+        }),
+        || {
+            println!(
+                "\n{}\n{}",
+                style(format!(
+                    "✓ Exported epochs {first_epoch}–{last_epoch} to {}",
+                    path.display()
+                ))
+                .green()
+                .bold(),
+                style(format!(
+                    "{} of {} epochs paid rewards, {} SOL in total",
+                    rewarded.len(),
+                    rewards.len(),
+                    format_sol_exact(total)
+                ))
+                .cyan()
+            );
+        },
+    )
+}
+
+/// Yield a delegator could expect from one validator, from its recent rewards
+struct ApyEstimate {
+    vote_pubkey: Pubkey,
+    identity: String,
+    commission: u8,
+    activated_stake: u64,
+    epochs_sampled: usize,
+    gross_apy: f64,
+    net_apy: f64,
+}
+
+/// A vote account is only paid its commission share, so the full reward for
+/// an epoch is that share scaled back up by the commission. Dividing by the
+/// validator's active stake gives the rate its delegators earned before
+/// commission. A 0% validator has no share to scale, so it falls back to the
+/// cluster-wide validator inflation spread over all active stake.
+async fn process_estimate_apy(
+    ctx: &ScillaContext,
+    vote_pubkeys: &[Pubkey],
+    epochs: u64,
+    spinner: ProgressBar,
+) -> anyhow::Result<()> {
+    let vote_accounts = ctx.rpc().get_vote_accounts().await?;
+    let vote_accounts: Vec<_> = vote_accounts
+        .current
+        .into_iter()
+        .chain(vote_accounts.delinquent)
+        .collect();
+    let total_stake: u64 = vote_accounts
+        .iter()
+        .map(|validator| validator.activated_stake)
+        .sum();
+
+    let epoch_info = ctx.epoch_info().await?;
+    let epochs_per_year =
+        epochs_per_year(epoch_info.slots_in_epoch, average_slot_time_ms(ctx).await);
+    let completed = completed_epochs(&epoch_info, epochs);
+
+    let mut cluster_epoch_rate = None;
+    let mut estimates = Vec::new();
+    for vote_pubkey in vote_pubkeys {
+        let validator = vote_accounts
+            .iter()
+            .find(|validator| validator.vote_pubkey == vote_pubkey.to_string())
+            .ok_or_else(|| anyhow!("{vote_pubkey} is not a vote account on this cluster"))?;
+        if validator.activated_stake == 0 {
+            bail!("{vote_pubkey} has no active stake to estimate a yield from");
+        }
+
+        let rewards = fetch_epoch_rewards(ctx, vote_pubkey, completed.clone(), &spinner).await?;
+        let epoch_rates: Vec<f64> = rewards
+            .iter()
+            .filter_map(|entry| entry.reward.as_ref())
+            .filter_map(|reward| {
+                let commission = reward.commission.filter(|commission| *commission > 0)?;
+                let full_reward = reward.amount as f64 * 100.0 / f64::from(commission);
+                Some(full_reward / validator.activated_stake as f64)
+            })
+            .collect();
+
+        let gross_epoch_rate = if epoch_rates.is_empty() {
+            match cluster_epoch_rate {
+                Some(rate) => rate,
+                None => {
+                    spinner.set_message("Fetching cluster inflation…");
+                    let (supply, inflation) =
+                        tokio::try_join!(ctx.rpc().supply(), ctx.rpc().get_inflation_rate())?;
+                    let rate = inflation.validator * supply.value.total as f64
+                        / total_stake.max(1) as f64
+                        / epochs_per_year;
+                    *cluster_epoch_rate.insert(rate)
+                }
+            }
+        } else {
+            epoch_rates.iter().sum::<f64>() / epoch_rates.len() as f64
+        };
+        let net_epoch_rate = gross_epoch_rate * (1.0 - f64::from(validator.commission) / 100.0);
+
+        estimates.push(ApyEstimate {
+            vote_pubkey: *vote_pubkey,
+            identity: validator.node_pubkey.clone(),
+            commission: validator.commission,
+            activated_stake: validator.activated_stake,
+            epochs_sampled: epoch_rates.len(),
+            gross_apy: compound_epoch_rate(gross_epoch_rate, epochs_per_year),
+            net_apy: compound_epoch_rate(net_epoch_rate, epochs_per_year),
+        });
+    }
+
+    let value: Vec<_> = estimates
+        .iter()
+        .map(|estimate| {
+            json!({
+                "vote_account": estimate.vote_pubkey.to_string(),
+                "identity": estimate.identity,
+                "commission": estimate.commission,
+                "active_stake": estimate.activated_stake,
+                "epochs_sampled": estimate.epochs_sampled,
+                "gross_apy": estimate.gross_apy,
+                "commission_drag": estimate.gross_apy - estimate.net_apy,
+                "net_apy": estimate.net_apy,
+            })
+        })
+        .collect();
+
+    let best_net_apy = estimates
+        .iter()
+        .map(|estimate| estimate.net_apy)
+        .fold(f64::MIN, f64::max);
+    let row = |label: &str, cell: &dyn Fn(&ApyEstimate) -> String| {
+        let mut row = vec![Cell::new(label)];
+        row.extend(estimates.iter().map(|estimate| Cell::new(cell(estimate))));
+        row
+    };
+
+    let mut header = vec![Cell::new("Field").add_attribute(comfy_table::Attribute::Bold)];
+    header.extend((1..=estimates.len()).map(|index| {
+        Cell::new(format!("Validator {index}")).add_attribute(comfy_table::Attribute::Bold)
+    }));
+
+    let mut table = new_table();
+    table
+        .load_preset(UTF8_FULL)
+        .set_header(header)
+        .add_row(row("Vote Account", &|estimate| {
+            estimate.vote_pubkey.to_string()
+        }))
+        .add_row(row("Identity", &|estimate| estimate.identity.clone()))
+        .add_row(row("Active Stake (SOL)", &|estimate| {
+            format!("{:.0}", lamports_to_sol(estimate.activated_stake))
+        }))
+        .add_row(row("Commission", &|estimate| {
+            format!("{}%", estimate.commission)
+        }))
+        .add_row(row("Epochs Sampled", &|estimate| {
+            if estimate.epochs_sampled == 0 {
+                "cluster average".to_string()
+            } else {
+                estimate.epochs_sampled.to_string()
+            }
+        }))
+        .add_row(row("Gross APY", &|estimate| {
+            format!("{:.2}%", estimate.gross_apy)
+        }))
+        .add_row(row("Commission Drag", &|estimate| {
+            format!("-{:.2}%", estimate.gross_apy - estimate.net_apy)
+        }));
+
+    let mut net_row = vec![Cell::new("Net APY")];
+    net_row.extend(estimates.iter().map(|estimate| {
+        let cell = Cell::new(format!("{:.2}%", estimate.net_apy));
+        if estimates.len() > 1 && estimate.net_apy == best_net_apy {
+            cell.fg(Color::Green)
+                .add_attribute(comfy_table::Attribute::Bold)
+        } else {
+            cell
+        }
+    }));
+    table.add_row(net_row);
+
+    print_output(ctx, &value, || {
+        println!("\n{}", style("VALIDATOR APY ESTIMATE").green().bold());
+        println!("{}", table);
+        println!(
+            "{}",
+            style(
+                "Estimates assume the current stake and commission hold for the year and exclude \
+                 MEV and block rewards."
+            )
+            .dim()
+        );
+    })
+}
+
+async fn process_stake_rewards(
+    ctx: &ScillaContext,
+    stake_pubkey: &Pubkey,
+    epochs: u64,
+    spinner: ProgressBar,
+) -> anyhow::Result<()> {
+    let epoch_info = ctx.epoch_info().await?;
+    let rewards = fetch_epoch_rewards(
+        ctx,
+        stake_pubkey,
+        completed_epochs(&epoch_info, epochs),
+        &spinner,
+    )
+    .await?;
+
+    let value: Vec<_> = rewards
+        .iter()
+        .map(|entry| {
+            json!({
+                "epoch": entry.epoch,
+                "reward_lamports": entry.reward.as_ref().map(|reward| reward.amount),
+                "post_balance": entry.reward.as_ref().map(|reward| reward.post_balance),
+                "commission": entry.reward.as_ref().and_then(|reward| reward.commission),
+                "apy": entry.apy,
+            })
+        })
+        .collect();
+
+    let dash = || "-".to_string();
+    let mut table = new_table();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Epoch").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Reward (SOL)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Post Balance (SOL)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("APY").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for entry in &rewards {
+        table.add_row(vec![
+            Cell::new(entry.epoch),
+            Cell::new(
+                entry
+                    .reward
+                    .as_ref()
+                    .map_or_else(dash, |reward| lamports_to_sol(reward.amount).to_string()),
+            ),
+            Cell::new(entry.reward.as_ref().map_or_else(dash, |reward| {
+                lamports_to_sol(reward.post_balance).to_string()
+            })),
+            Cell::new(entry.apy.map_or_else(dash, |apy| format!("{apy:.2}%"))),
+        ]);
+    }
+
+    let total: u64 = rewards
+        .iter()
+        .filter_map(|entry| entry.reward.as_ref())
+        .map(|reward| reward.amount)
+        .sum();
+
+    print_output(ctx, &value, || {
+        println!("\n{}", style("STAKE REWARDS").green().bold());
+        println!("{}", table);
+        println!(
+            "{}",
+            style(format!(
+                "Total over {} epochs: {} SOL",
+                rewards.len(),
+                lamports_to_sol(total)
+            ))
+            .cyan()
+        );
+    })
+}
+
+async fn process_list_stake_accounts(
+    ctx: &ScillaContext,
+    stake_authorize: StakeAuthorize,
+) -> anyhow::Result<()> {
+    let (authority_label, offset) = match stake_authorize {
+        StakeAuthorize::Staker => ("staker", STAKE_AUTHORIZED_STAKER_OFFSET),
+        StakeAuthorize::Withdrawer => ("withdrawer", STAKE_AUTHORIZED_WITHDRAWER_OFFSET),
+    };
+
+    let (mut accounts, epoch_info, stake_history) = tokio::try_join!(
+        fetch_authorized_stake_accounts(ctx, offset),
+        ctx.epoch_info(),
+        fetch_stake_history(ctx),
+    )?;
+    accounts.sort_by_key(|(_, lamports, _)| std::cmp::Reverse(*lamports));
+
+    let mut rows = Vec::with_capacity(accounts.len());
+
+    for (address, lamports, stake_state) in &accounts {
+        let (vote_account, status) = match stake_state {
+            StakeStateV2::Stake(_, stake, _) => {
+                let delegation = &stake.delegation;
+                let status = delegation.stake_activating_and_deactivating(
+                    epoch_info.epoch,
+                    &stake_history,
+                    NEW_WARMUP_COOLDOWN_RATE_EPOCH,
+                );
+                (
+                    Some(delegation.voter_pubkey),
+                    activation_state_label(&status, delegation.deactivation_epoch),
+                )
+            }
+            StakeStateV2::Initialized(_) => (None, "Initialized"),
+            StakeStateV2::Uninitialized | StakeStateV2::RewardsPool => continue,
+        };
+
+        rows.push((address, *lamports, vote_account, status));
+    }
+
+    // Validators are looked up in bulk, each one once
+    let validators: Vec<Pubkey> = rows
+        .iter()
+        .filter_map(|(_, _, vote_account, _)| *vote_account)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let commissions: HashMap<Pubkey, u8> = validators
+        .iter()
+        .zip(fetch_accounts(ctx, &validators).await?)
+        .filter_map(|(vote_pubkey, account)| {
+            Some((*vote_pubkey, vote_commission(vote_pubkey, &account?).ok()?))
+        })
+        .collect();
+
+    let mut table = new_table();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("Stake Account").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Balance (SOL)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Validator Vote Account").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Commission").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Status").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    let mut value = Vec::with_capacity(rows.len());
+
+    for (address, lamports, vote_account, status) in rows {
+        let commission = vote_account.and_then(|vote| commissions.get(&vote).copied());
+
+        value.push(json!({
+            "address": address.to_string(),
+            "lamports": lamports,
+            "vote_account": vote_account.map(|vote| vote.to_string()),
+            "commission": commission,
+            "status": status,
+        }));
+
+        table.add_row(vec![
+            Cell::new(address.to_string()),
+            Cell::new(lamports_to_sol(lamports)),
+            Cell::new(vote_account.map_or("-".to_string(), |vote| vote.to_string())),
+            Cell::new(commission.map_or("-".to_string(), |commission| format!("{commission}%"))),
+            Cell::new(status),
+        ]);
+    }
+
+    print_output(ctx, &value, || {
+        if value.is_empty() {
+            println!(
+                "\n{}",
+                style(format!(
+                    "No stake accounts found with {} as {authority_label}",
+                    ctx.pubkey()
+                ))
+                .yellow()
+            );
+            return;
+        }
+
+        let total: u64 = accounts.iter().map(|(_, lamports, _)| lamports).sum();
+        println!("\n{}", style("MY STAKE ACCOUNTS").green().bold());
+        println!("{}", table);
+        println!(
+            "{}",
+            style(format!(
+                "{} accounts with you as {authority_label}, {} SOL total",
+                value.len(),
+                lamports_to_sol(total)
+            ))
+            .cyan()
+        );
+    })
+}
+
+/// Stake accounts with the wallet as the authority stored at `offset`,
+/// decoded, as `(address, lamports, state)`
+async fn fetch_authorized_stake_accounts(
+    ctx: &ScillaContext,
+    offset: usize,
+) -> anyhow::Result<Vec<(Pubkey, u64, StakeStateV2)>> {
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            offset,
+            &ctx.pubkey().to_bytes(),
+        ))]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(ctx.rpc().commitment()),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+
+    ctx.rpc()
+        .get_program_ui_accounts_with_config(&stake_program_id(), config)
+        .await?
+        .into_iter()
+        .map(|(address, account)| {
+            let data = account
+                .data
+                .decode()
+                .ok_or_else(|| anyhow!("Failed to decode stake account {address}"))?;
+            let stake_state: StakeStateV2 = bincode_deserialize(&data, "stake account data")?;
+            Ok((address, account.lamports, stake_state))
+        })
+        .collect()
+}
+
+fn prompt_history_limit(default: usize) -> anyhow::Result<usize> {
+    let limit: OptionalInput<usize> = prompt_data(&format!(
+        "Enter number of entries per page (default {default}):"
+    ))?;
+    let limit = limit.value().unwrap_or(default);
+
+    if limit == 0 || limit > MAX_SIGNATURES_PER_REQUEST {
+        bail!("Entries per page must be between 1 and {MAX_SIGNATURES_PER_REQUEST}");
+    }
+    Ok(limit)
+}
+
+/// Shows the stake account's transactions newest first, one page at a time,
+/// asking before fetching each older page. Returns every entry fetched.
+async fn browse_stake_account_history(
+    ctx: &ScillaContext,
+    stake_pubkey: &Pubkey,
+    limit: usize,
+    until: Option<Signature>,
+) -> anyhow::Result<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+    let mut entries = Vec::new();
+    let mut before = None;
+
+    loop {
+        let page = show_spinner(
+            "Fetching stake account history…",
+            fetch_signature_page(ctx, stake_pubkey, before, until, limit),
+        )
+        .await?;
+
+        print_signature_page(ctx, stake_pubkey, &page, entries.len())?;
+
+        let is_last_page = page.len() < limit;
+        before = page
+            .last()
+            .map(|entry| Signature::from_str(&entry.signature))
+            .transpose()?;
+        entries.extend(page);
+
+        // A JSON document per page would not be parseable as a whole
+        if is_last_page || ctx.output_format() == OutputFormat::Json {
+            break;
+        }
+
+        let next_page = prompt_yes_no(Confirm::new("Show next page?").with_default(false))?;
+        if !next_page {
+            break;
+        }
+    }
+
+    Ok(entries)
+}
+
+/// A stake account history entry as written to an export file
+#[derive(Debug, Serialize)]
+struct SignatureRecord {
+    slot: u64,
+    signature: String,
+    status: String,
+    block_time: Option<i64>,
+}
+
+/// Offers to save the fetched history for bookkeeping
+fn prompt_export_signatures(
+    entries: &[RpcConfirmedTransactionStatusWithSignature],
+) -> anyhow::Result<()> {
+    let export = prompt_yes_no(
+        Confirm::new(&format!(
+            "Export the {} fetched entries to a file?",
+            entries.len()
+        ))
+        .with_default(false),
+    )?;
+    if !export {
         return Ok(());
     }
 
-    let mut table = Table::new();
+    let format = prompt_select(Select::new(
+        "Select export format:",
+        vec![ExportFormat::Csv, ExportFormat::Json],
+    ))?;
+    let path: PathBuf = prompt_data("Enter output file path:")?;
+
+    let records: Vec<SignatureRecord> = entries
+        .iter()
+        .map(|entry| SignatureRecord {
+            slot: entry.slot,
+            signature: entry.signature.clone(),
+            status: entry
+                .err
+                .as_ref()
+                .map_or("Success".to_string(), |err| format!("Failed: {err}")),
+            block_time: entry.block_time,
+        })
+        .collect();
+
+    let contents = match format {
+        ExportFormat::Json => serde_json::to_string_pretty(&records)?,
+        ExportFormat::Csv => to_csv(
+            &["slot", "signature", "status", "block_time"],
+            records.iter().map(|record| {
+                vec![
+                    record.slot.to_string(),
+                    record.signature.clone(),
+                    record.status.clone(),
+                    record
+                        .block_time
+                        .map_or(String::new(), |block_time| block_time.to_string()),
+                ]
+            }),
+        ),
+    };
+
+    fs::write(&path, contents).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    notice!(
+        "{}",
+        style(format!(
+            "✓ Exported {} entries to {}",
+            entries.len(),
+            path.display()
+        ))
+        .green()
+    );
+    Ok(())
+}
+
+async fn fetch_signature_page(
+    ctx: &ScillaContext,
+    address: &Pubkey,
+    before: Option<Signature>,
+    until: Option<Signature>,
+    limit: usize,
+) -> anyhow::Result<Vec<RpcConfirmedTransactionStatusWithSignature>> {
+    // getSignaturesForAddress does not accept processed commitment
+    let commitment = match ctx.rpc().commitment().commitment {
+        CommitmentLevel::Processed => CommitmentConfig::confirmed(),
+        _ => ctx.rpc().commitment(),
+    };
+
+    let page = ctx
+        .rpc()
+        .get_signatures_for_address_with_config(
+            address,
+            GetConfirmedSignaturesForAddress2Config {
+                before,
+                until,
+                limit: Some(limit),
+                commitment: Some(commitment),
+            },
+        )
+        .await?;
+
+    Ok(page)
+}
+
+fn print_signature_page(
+    ctx: &ScillaContext,
+    address: &Pubkey,
+    page: &[RpcConfirmedTransactionStatusWithSignature],
+    offset: usize,
+) -> anyhow::Result<()> {
+    let block_time = |entry: &RpcConfirmedTransactionStatusWithSignature| {
+        entry
+            .block_time
+            .map(|block_time| format_block_time(block_time, ctx.timestamps()))
+    };
+
+    let value: Vec<_> = page
+        .iter()
+        .map(|entry| {
+            json!({
+                "signature": entry.signature,
+                "slot": entry.slot,
+                "err": entry.err,
+                "block_time": entry.block_time,
+                "datetime": block_time(entry),
+            })
+        })
+        .collect();
+
+    print_output(ctx, &value, || {
+        if page.is_empty() {
+            println!(
+                "\n{}",
+                style(format!("No more transactions found for {address}")).yellow()
+            );
+            return;
+        }
+
+        let mut table = new_table();
+        table.load_preset(UTF8_FULL).set_header(vec![
+            Cell::new("#").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Slot").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Signature").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Status").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Block Time").add_attribute(comfy_table::Attribute::Bold),
+        ]);
+
+        for (idx, entry) in page.iter().enumerate() {
+            let status = match &entry.err {
+                None => style("Success").green().to_string(),
+                Some(err) => style(format!("Failed: {err}")).red().to_string(),
+            };
+
+            table.add_row(vec![
+                Cell::new(offset + idx + 1),
+                Cell::new(entry.slot),
+                Cell::new(&entry.signature),
+                Cell::new(status),
+                Cell::new(block_time(entry).unwrap_or_else(|| "-".to_string())),
+            ]);
+        }
+
+        println!(
+            "\n{}",
+            style(format!(
+                "STAKE ACCOUNT HISTORY ({}-{})",
+                offset + 1,
+                offset + page.len()
+            ))
+            .green()
+            .bold()
+        );
+        println!("{}", table);
+    })
+}
+
+async fn process_stake_history(ctx: &ScillaContext, limit: usize) -> anyhow::Result<()> {
+    let stake_history = fetch_stake_history(ctx).await?;
+
+    if stake_history.is_empty() {
+        return print_output(ctx, &json!([]), || {
+            println!("\n{}", style("No stake history available").yellow())
+        });
+    }
+
+    let mut table = new_table();
     table.load_preset(UTF8_FULL).set_header(vec![
         Cell::new("Epoch").add_attribute(comfy_table::Attribute::Bold),
         Cell::new("Effective Stake").add_attribute(comfy_table::Attribute::Bold),
@@ -494,13 +2916,22 @@ async fn process_stake_history(ctx: &ScillaContext) -> anyhow::Result<()> {
         Cell::new("Deactivating Stake").add_attribute(comfy_table::Attribute::Bold),
     ]);
 
-    for (epoch, entry) in stake_history.iter().take(DEFAULT_EPOCH_LIMIT) {
+    let mut value = Vec::new();
+
+    for (epoch, entry) in stake_history.iter().take(limit) {
         let StakeHistoryEntry {
             effective,
             activating,
             deactivating,
         } = entry;
 
+        value.push(json!({
+            "epoch": epoch,
+            "effective": effective,
+            "activating": activating,
+            "deactivating": deactivating,
+        }));
+
         table.add_row(vec![
             Cell::new(epoch.to_string()),
             Cell::new(lamports_to_sol(*effective)),
@@ -509,8 +2940,192 @@ async fn process_stake_history(ctx: &ScillaContext) -> anyhow::Result<()> {
         ]);
     }
 
-    println!("\n{}", style("CLUSTER STAKE HISTORY").green().bold());
-    println!("{}", table);
+    print_output(ctx, &value, || {
+        println!("\n{}", style("CLUSTER STAKE HISTORY").green().bold());
+        println!("{}", table);
+    })
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        solana_stake_interface::{
+            stake_flags::StakeFlags,
+            state::{Delegation, Meta, Stake},
+        },
+    };
+
+    const EPOCH: u64 = 100;
+
+    fn meta(withdrawer: Pubkey, lockup: Lockup) -> Meta {
+        Meta {
+            authorized: Authorized {
+                staker: Pubkey::new_from_array([1; 32]),
+                withdrawer,
+            },
+            lockup,
+            ..Meta::default()
+        }
+    }
+
+    fn delegated(
+        voter: Pubkey,
+        activation_epoch: u64,
+        deactivation_epoch: u64,
+        withdrawer: Pubkey,
+    ) -> StakeStateV2 {
+        let delegation = Delegation {
+            deactivation_epoch,
+            ..Delegation::new(&voter, 1_000_000_000, activation_epoch)
+        };
+        StakeStateV2::Stake(
+            meta(withdrawer, Lockup::default()),
+            Stake {
+                delegation,
+                credits_observed: 0,
+            },
+            StakeFlags::empty(),
+        )
+    }
+
+    fn active(voter: Pubkey, withdrawer: Pubkey) -> StakeStateV2 {
+        delegated(voter, EPOCH - 10, ACTIVE_STAKE_EPOCH_BOUND, withdrawer)
+    }
+
+    fn kind_of(stake_state: &StakeStateV2) -> Option<MergeKind> {
+        MergeKind::of(stake_state, EPOCH, &StakeHistory::default())
+    }
+
+    #[test]
+    fn test_completed_epochs_leave_out_current_epoch() {
+        let epoch_info = |epoch| EpochInfo {
+            epoch,
+            slot_index: 0,
+            slots_in_epoch: 432_000,
+            absolute_slot: 0,
+            block_height: 0,
+            transaction_count: None,
+        };
+
+        assert_eq!(completed_epochs(&epoch_info(EPOCH), 5), EPOCH - 5..EPOCH);
+        assert_eq!(completed_epochs(&epoch_info(3), 5), 0..3);
+        assert_eq!(completed_epochs(&epoch_info(0), 1).next_back(), None);
+    }
+
+    #[test]
+    fn test_merge_kind_of_delegation_states() {
+        let voter = Pubkey::new_unique();
+        let withdrawer = Pubkey::new_unique();
+
+        assert_eq!(
+            kind_of(&StakeStateV2::Initialized(meta(
+                withdrawer,
+                Lockup::default()
+            ))),
+            Some(MergeKind::Inactive)
+        );
+        assert_eq!(
+            kind_of(&delegated(
+                voter,
+                EPOCH,
+                ACTIVE_STAKE_EPOCH_BOUND,
+                withdrawer
+            )),
+            Some(MergeKind::Activating {
+                voter,
+                activation_epoch: EPOCH,
+            })
+        );
+        assert_eq!(
+            kind_of(&active(voter, withdrawer)),
+            Some(MergeKind::Active { voter })
+        );
+        // Deactivating stake cannot be merged until it has cooled down
+        assert_eq!(
+            kind_of(&delegated(voter, EPOCH - 10, EPOCH, withdrawer)),
+            None
+        );
+        assert_eq!(
+            kind_of(&delegated(voter, EPOCH - 10, EPOCH - 5, withdrawer)),
+            Some(MergeKind::Inactive)
+        );
+        assert_eq!(kind_of(&StakeStateV2::Uninitialized), None);
+    }
+
+    #[test]
+    fn test_group_for_merge_keeps_kinds_apart() {
+        let voter = Pubkey::new_unique();
+        let withdrawer = Pubkey::new_unique();
+        let accounts = vec![
+            (Pubkey::new_unique(), 5, active(voter, withdrawer)),
+            (
+                Pubkey::new_unique(),
+                4,
+                delegated(voter, EPOCH, ACTIVE_STAKE_EPOCH_BOUND, withdrawer),
+            ),
+            (
+                Pubkey::new_unique(),
+                3,
+                delegated(voter, EPOCH - 10, EPOCH, withdrawer),
+            ),
+            (
+                Pubkey::new_unique(),
+                2,
+                delegated(voter, EPOCH - 10, EPOCH, withdrawer),
+            ),
+        ];
+
+        let groups = group_for_merge(accounts, EPOCH, &StakeHistory::default());
+
+        assert!(groups.is_empty());
+    }
+
+    #[test]
+    fn test_group_for_merge_splits_mismatched_authorities() {
+        let voter = Pubkey::new_unique();
+        let withdrawer = Pubkey::new_unique();
+        let other_withdrawer = Pubkey::new_unique();
+        let mut locked = active(voter, withdrawer);
+        if let StakeStateV2::Stake(meta, _, _) = &mut locked {
+            meta.lockup.epoch = EPOCH + 10;
+        }
+        let matching = Pubkey::new_unique();
+        let accounts = vec![
+            (Pubkey::new_unique(), 5, active(voter, withdrawer)),
+            (Pubkey::new_unique(), 4, active(voter, other_withdrawer)),
+            (Pubkey::new_unique(), 3, locked),
+            (matching, 2, active(voter, withdrawer)),
+        ];
+
+        let groups = group_for_merge(accounts, EPOCH, &StakeHistory::default());
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].withdrawer, withdrawer);
+        assert_eq!(groups[0].lockup, Lockup::default());
+        assert_eq!(groups[0].sources, vec![matching]);
+        assert_eq!(groups[0].lamports, 7);
+    }
+
+    #[test]
+    fn test_group_for_merge_picks_largest_destination() {
+        let voter = Pubkey::new_unique();
+        let withdrawer = Pubkey::new_unique();
+        let small = Pubkey::new_unique();
+        let largest = Pubkey::new_unique();
+        let medium = Pubkey::new_unique();
+        let accounts = vec![
+            (small, 1, active(voter, withdrawer)),
+            (largest, 9, active(voter, withdrawer)),
+            (medium, 4, active(voter, withdrawer)),
+        ];
+
+        let groups = group_for_merge(accounts, EPOCH, &StakeHistory::default());
+
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].kind, MergeKind::Active { voter });
+        assert_eq!(groups[0].destination, largest);
+        assert_eq!(groups[0].sources, vec![medium, small]);
+        assert_eq!(groups[0].lamports, 14);
+    }
 }
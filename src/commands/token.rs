@@ -0,0 +1,525 @@
+use {
+    crate::{
+        commands::CommandExec,
+        constants::{CLOSE_TOKEN_ACCOUNTS_PER_TX, KNOWN_TOKEN_MINTS},
+        context::ScillaContext,
+        error::ScillaResult,
+        misc::helpers::{account_exists, build_and_send_tx, lamports_to_sol, parse_token_amount},
+        notice,
+        prompt::{PubkeyCheck, prompt_confirm, prompt_data, prompt_pubkey},
+        ui::{new_table, print_output, print_tx_output, show_spinner},
+    },
+    anyhow::{anyhow, bail},
+    clap::ValueEnum,
+    comfy_table::{Cell, presets::UTF8_FULL},
+    console::style,
+    serde::Deserialize,
+    serde_json::json,
+    solana_account_decoder_client_types::{UiAccountData, token::UiTokenAmount},
+    solana_pubkey::Pubkey,
+    solana_rpc_client_api::{request::TokenAccountsFilter, response::RpcKeyedAccount},
+    spl_associated_token_account_interface::{
+        address::get_associated_token_address_with_program_id,
+        instruction::create_associated_token_account_idempotent,
+    },
+    spl_token_2022_interface::{
+        extension::{ExtensionType, StateWithExtensions},
+        instruction::{close_account, transfer_checked},
+        state::{Account as TokenAccount, Mint},
+    },
+    std::fmt,
+    tokio::try_join,
+};
+
+/// Commands related to SPL tokens
+#[derive(Debug, Clone, ValueEnum)]
+pub enum TokenCommand {
+    Balances,
+    Transfer,
+    CloseEmpty,
+    #[value(skip)]
+    GoBack,
+}
+
+impl TokenCommand {
+    pub fn spinner_msg(&self) -> &'static str {
+        match self {
+            TokenCommand::Balances => "Fetching token balances…",
+            TokenCommand::Transfer => "Sending tokens…",
+            TokenCommand::CloseEmpty => "Closing empty token accounts…",
+            TokenCommand::GoBack => "Going back…",
+        }
+    }
+}
+
+impl fmt::Display for TokenCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let command = match self {
+            TokenCommand::Balances => "Token balances",
+            TokenCommand::Transfer => "Transfer tokens",
+            TokenCommand::CloseEmpty => "Close empty token accounts",
+            TokenCommand::GoBack => "Go back",
+        };
+        write!(f, "{command}")
+    }
+}
+
+impl TokenCommand {
+    pub async fn process_command(&self, ctx: &ScillaContext) -> ScillaResult<()> {
+        match self {
+            TokenCommand::Balances => {
+                show_spinner(self.spinner_msg(), fetch_token_balances(ctx)).await?;
+            }
+            TokenCommand::Transfer => {
+                let mint =
+                    prompt_pubkey(ctx, "Enter Token Mint Address:", PubkeyCheck::Existing).await?;
+                let recipient =
+                    prompt_pubkey(ctx, "Enter Recipient Wallet Address:", PubkeyCheck::Wallet)
+                        .await?;
+                let amount: String = prompt_data("Enter Amount to Transfer:")?;
+
+                let plan = show_spinner(
+                    "Looking up token accounts…",
+                    plan_token_transfer(ctx, &mint, &recipient),
+                )
+                .await?;
+                let amount = parse_token_amount(&amount, plan.decimals)?;
+
+                if let Some(rent) = plan.destination_rent {
+                    notice!(
+                        "{}",
+                        style(format!(
+                            "⚠ {recipient} has no token account for this mint yet. Creating {} \
+                             costs about {} SOL in rent, paid by you.",
+                            plan.destination,
+                            lamports_to_sol(rent)
+                        ))
+                        .yellow()
+                        .bold()
+                    );
+
+                    let proceed = prompt_confirm("Create the recipient's token account?")?;
+
+                    if !proceed {
+                        notice!("{}", style("Token transfer cancelled.").yellow());
+                        return Ok(CommandExec::Process(()));
+                    }
+                }
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_token_transfer(ctx, &plan, amount),
+                )
+                .await?;
+            }
+            TokenCommand::CloseEmpty => {
+                let accounts = show_spinner(
+                    "Finding empty token accounts…",
+                    find_empty_token_accounts(ctx),
+                )
+                .await?;
+
+                if accounts.is_empty() {
+                    notice!("\n{}", style("No empty token accounts to close.").yellow());
+                    return Ok(CommandExec::Process(()));
+                }
+
+                let reclaimable: u64 = accounts.iter().map(|account| account.lamports).sum();
+                let mut table = new_table();
+                table.load_preset(UTF8_FULL).set_header(vec![
+                    Cell::new("Symbol").add_attribute(comfy_table::Attribute::Bold),
+                    Cell::new("Mint").add_attribute(comfy_table::Attribute::Bold),
+                    Cell::new("Token Account").add_attribute(comfy_table::Attribute::Bold),
+                    Cell::new("Rent (SOL)").add_attribute(comfy_table::Attribute::Bold),
+                ]);
+                for account in &accounts {
+                    table.add_row(vec![
+                        Cell::new(token_symbol(&account.mint).unwrap_or("-")),
+                        Cell::new(&account.mint),
+                        Cell::new(account.address),
+                        Cell::new(lamports_to_sol(account.lamports)),
+                    ]);
+                }
+                notice!("\n{}", style("EMPTY TOKEN ACCOUNTS").green().bold());
+                notice!("{}", table);
+
+                if !prompt_confirm(&format!(
+                    "Close {} token accounts and reclaim {} SOL?",
+                    accounts.len(),
+                    lamports_to_sol(reclaimable)
+                ))? {
+                    notice!("{}", style("Nothing was closed.").yellow());
+                    return Ok(CommandExec::Process(()));
+                }
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_close_token_accounts(ctx, &accounts),
+                )
+                .await?;
+            }
+            TokenCommand::GoBack => return Ok(CommandExec::GoBack),
+        }
+
+        Ok(CommandExec::Process(()))
+    }
+}
+
+/// The `info` section of a `jsonParsed` token account
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ParsedTokenAccount {
+    mint: String,
+    token_amount: UiTokenAmount,
+    state: String,
+    #[serde(default)]
+    close_authority: Option<String>,
+}
+
+struct TokenBalance {
+    address: String,
+    mint: String,
+    amount: UiTokenAmount,
+    /// Rent held by the account, returned when it is closed
+    lamports: u64,
+    token_program: String,
+    frozen: bool,
+    close_authority: Option<String>,
+}
+
+fn token_symbol(mint: &str) -> Option<&'static str> {
+    KNOWN_TOKEN_MINTS
+        .iter()
+        .find(|(known_mint, _)| *known_mint == mint)
+        .map(|(_, symbol)| *symbol)
+}
+
+fn parse_token_account(keyed: RpcKeyedAccount) -> anyhow::Result<TokenBalance> {
+    let UiAccountData::Json(parsed) = keyed.account.data else {
+        return Err(anyhow!(
+            "Token account {} was not returned as parsed JSON",
+            keyed.pubkey
+        ));
+    };
+
+    let info: ParsedTokenAccount = serde_json::from_value(parsed.parsed["info"].clone())
+        .map_err(|e| anyhow!("Failed to parse token account {}: {e}", keyed.pubkey))?;
+
+    Ok(TokenBalance {
+        address: keyed.pubkey,
+        mint: info.mint,
+        amount: info.token_amount,
+        lamports: keyed.account.lamports,
+        token_program: keyed.account.owner,
+        frozen: info.state == "frozen",
+        close_authority: info.close_authority,
+    })
+}
+
+/// Every token account the wallet owns under both token programs
+async fn fetch_owned_token_accounts(ctx: &ScillaContext) -> anyhow::Result<Vec<TokenBalance>> {
+    let (token_accounts, token_2022_accounts) = try_join!(
+        ctx.rpc().get_token_accounts_by_owner(
+            ctx.pubkey(),
+            TokenAccountsFilter::ProgramId(spl_token_interface::id()),
+        ),
+        ctx.rpc().get_token_accounts_by_owner(
+            ctx.pubkey(),
+            TokenAccountsFilter::ProgramId(spl_token_2022_interface::id()),
+        ),
+    )?;
+
+    token_accounts
+        .into_iter()
+        .chain(token_2022_accounts)
+        .map(parse_token_account)
+        .collect()
+}
+
+async fn fetch_token_balances(ctx: &ScillaContext) -> anyhow::Result<()> {
+    let mut balances = fetch_owned_token_accounts(ctx).await?;
+    balances.sort_by(|a, b| a.mint.cmp(&b.mint).then(a.address.cmp(&b.address)));
+
+    let value: Vec<_> = balances
+        .iter()
+        .map(|balance| {
+            json!({
+                "mint": balance.mint,
+                "symbol": token_symbol(&balance.mint),
+                "token_account": balance.address,
+                "amount": balance.amount.amount,
+                "ui_amount": balance.amount.ui_amount_string,
+                "decimals": balance.amount.decimals,
+            })
+        })
+        .collect();
+
+    print_output(ctx, &value, || {
+        if balances.is_empty() {
+            println!(
+                "\n{}",
+                style(format!("No token accounts found for {}", ctx.pubkey())).yellow()
+            );
+            return;
+        }
+
+        let mut table = new_table();
+        table.load_preset(UTF8_FULL).set_header(vec![
+            Cell::new("Symbol").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Mint").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Token Account").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Balance").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Decimals").add_attribute(comfy_table::Attribute::Bold),
+        ]);
+
+        for balance in &balances {
+            table.add_row(vec![
+                Cell::new(token_symbol(&balance.mint).unwrap_or("-")),
+                Cell::new(&balance.mint),
+                Cell::new(&balance.address),
+                Cell::new(&balance.amount.ui_amount_string),
+                Cell::new(balance.amount.decimals),
+            ]);
+        }
+
+        println!("\n{}", style("TOKEN BALANCES").green().bold());
+        println!("{}", table);
+    })
+}
+
+/// A zero-balance token account the wallet is able to close
+struct EmptyTokenAccount {
+    address: Pubkey,
+    mint: String,
+    token_program: Pubkey,
+    lamports: u64,
+}
+
+/// Zero-balance accounts that can be closed by the wallet. Frozen accounts and
+/// ones with a different close authority are left out, since closing them
+/// would fail.
+async fn find_empty_token_accounts(ctx: &ScillaContext) -> anyhow::Result<Vec<EmptyTokenAccount>> {
+    let wallet = ctx.pubkey().to_string();
+    let mut accounts = fetch_owned_token_accounts(ctx)
+        .await?
+        .into_iter()
+        .filter(|balance| balance.amount.amount == "0" && !balance.frozen)
+        .filter(|balance| {
+            balance
+                .close_authority
+                .as_ref()
+                .is_none_or(|authority| *authority == wallet)
+        })
+        .map(|balance| {
+            Ok(EmptyTokenAccount {
+                address: balance.address.parse()?,
+                mint: balance.mint,
+                token_program: balance.token_program.parse()?,
+                lamports: balance.lamports,
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    accounts.sort_by(|a, b| a.mint.cmp(&b.mint).then(a.address.cmp(&b.address)));
+
+    Ok(accounts)
+}
+
+async fn process_close_token_accounts(
+    ctx: &ScillaContext,
+    accounts: &[EmptyTokenAccount],
+) -> anyhow::Result<()> {
+    let mut signatures = Vec::new();
+    for chunk in accounts.chunks(CLOSE_TOKEN_ACCOUNTS_PER_TX) {
+        let instructions = chunk
+            .iter()
+            .map(|account| {
+                close_account(
+                    &account.token_program,
+                    &account.address,
+                    ctx.pubkey(),
+                    ctx.pubkey(),
+                    &[],
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        signatures.push(build_and_send_tx(ctx, &instructions, &[ctx.signer()]).await?);
+    }
+
+    let reclaimed: u64 = accounts.iter().map(|account| account.lamports).sum();
+    let value = json!({
+        "closed": accounts
+            .iter()
+            .map(|account| account.address.to_string())
+            .collect::<Vec<_>>(),
+        "reclaimed_lamports": reclaimed,
+        "signatures": signatures
+            .iter()
+            .map(|signature| signature.to_string())
+            .collect::<Vec<_>>(),
+    });
+
+    print_output(ctx, &value, || {
+        println!(
+            "\n{}\n{}",
+            style(format!("Closed {} token accounts", accounts.len()))
+                .green()
+                .bold(),
+            style(format!("Reclaimed: {} SOL", lamports_to_sol(reclaimed))).cyan()
+        );
+        for signature in &signatures {
+            println!("{}", style(format!("Signature: {signature}")).cyan());
+        }
+    })
+}
+
+/// Accounts involved in a token transfer, resolved before asking for
+/// confirmation
+struct TokenTransferPlan {
+    mint: Pubkey,
+    recipient: Pubkey,
+    token_program: Pubkey,
+    decimals: u8,
+    source: Pubkey,
+    destination: Pubkey,
+    /// Rent for creating the destination account, `None` when it exists
+    destination_rent: Option<u64>,
+}
+
+async fn plan_token_transfer(
+    ctx: &ScillaContext,
+    mint: &Pubkey,
+    recipient: &Pubkey,
+) -> anyhow::Result<TokenTransferPlan> {
+    let mint_account = ctx.rpc().get_account(mint).await?;
+    let token_program = mint_account.owner;
+
+    if token_program != spl_token_interface::id() && token_program != spl_token_2022_interface::id()
+    {
+        bail!("{mint} is not an SPL token mint (owned by {token_program})");
+    }
+
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint_account.data)
+        .map_err(|e| anyhow!("Failed to decode mint {mint}: {e}"))?
+        .base
+        .decimals;
+
+    let source = get_associated_token_address_with_program_id(ctx.pubkey(), mint, &token_program);
+    let destination = get_associated_token_address_with_program_id(recipient, mint, &token_program);
+
+    if source == destination {
+        bail!("Recipient cannot be your own wallet");
+    }
+
+    let destination_rent = if account_exists(ctx, &destination).await? {
+        None
+    } else {
+        // Token-2022 associated token accounts always carry ImmutableOwner
+        let extensions: &[ExtensionType] = if token_program == spl_token_2022_interface::id() {
+            &[ExtensionType::ImmutableOwner]
+        } else {
+            &[]
+        };
+        let account_len = ExtensionType::try_calculate_account_len::<TokenAccount>(extensions)?;
+        Some(
+            ctx.rpc()
+                .get_minimum_balance_for_rent_exemption(account_len)
+                .await?,
+        )
+    };
+
+    Ok(TokenTransferPlan {
+        mint: *mint,
+        recipient: *recipient,
+        token_program,
+        decimals,
+        source,
+        destination,
+        destination_rent,
+    })
+}
+
+async fn process_token_transfer(
+    ctx: &ScillaContext,
+    plan: &TokenTransferPlan,
+    amount: u64,
+) -> anyhow::Result<()> {
+    let source_balance = ctx
+        .rpc()
+        .get_token_account_balance(&plan.source)
+        .await
+        .map_err(|_| anyhow!("You have no token account for mint {}", plan.mint))?;
+
+    let available: u64 = source_balance.amount.parse()?;
+    if amount > available {
+        bail!(
+            "Insufficient token balance. Have {}, tried to send {}",
+            source_balance.ui_amount_string,
+            format_token_amount(amount, plan.decimals)
+        );
+    }
+
+    let mut instructions = Vec::with_capacity(2);
+    if plan.destination_rent.is_some() {
+        instructions.push(create_associated_token_account_idempotent(
+            ctx.pubkey(),
+            &plan.recipient,
+            &plan.mint,
+            &plan.token_program,
+        ));
+    }
+    instructions.push(transfer_checked(
+        &plan.token_program,
+        &plan.source,
+        &plan.mint,
+        &plan.destination,
+        ctx.pubkey(),
+        &[],
+        amount,
+        plan.decimals,
+    )?);
+
+    let signature = build_and_send_tx(ctx, &instructions, &[ctx.signer()]).await?;
+    let ui_amount = format_token_amount(amount, plan.decimals);
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "mint": plan.mint.to_string(),
+            "recipient": plan.recipient.to_string(),
+            "source_token_account": plan.source.to_string(),
+            "destination_token_account": plan.destination.to_string(),
+            "created_destination": plan.destination_rent.is_some(),
+            "amount": amount.to_string(),
+            "ui_amount": ui_amount,
+            "decimals": plan.decimals,
+        }),
+        || {
+            let symbol = token_symbol(&plan.mint.to_string()).unwrap_or("tokens");
+            println!(
+                "\n{}\n{}\n{}\n{}\n{}\n{}",
+                style("Token Transfer Successful!").green().bold(),
+                style(format!("To: {}", plan.recipient)).yellow(),
+                style(format!("Amount: {ui_amount} {symbol}")).cyan(),
+                style(format!("Source Token Account: {}", plan.source)).cyan(),
+                style(format!("Destination Token Account: {}", plan.destination)).cyan(),
+                style(format!("Signature: {signature}")).cyan()
+            )
+        },
+    )
+}
+
+/// Renders base units as a decimal amount, e.g. `12500000` with 6 decimals as
+/// `12.5`
+fn format_token_amount(amount: u64, decimals: u8) -> String {
+    let divisor = 10u128.pow(decimals as u32);
+    let whole = amount as u128 / divisor;
+    let fraction = amount as u128 % divisor;
+
+    if fraction == 0 {
+        return whole.to_string();
+    }
+
+    let fraction = format!("{fraction:0>width$}", width = decimals as usize);
+    format!("{whole}.{}", fraction.trim_end_matches('0'))
+}
@@ -1,28 +1,45 @@
 use {
     crate::{
-        commands::CommandExec,
+        commands::{CommandExec, nonce::fetch_nonce_data},
         context::ScillaContext,
         error::ScillaResult,
-        misc::helpers::{bincode_deserialize, decode_base58, decode_base64},
-        prompt::prompt_data,
-        ui::show_spinner,
+        misc::helpers::{
+            DurableNonce, OptionalInput, SolAmount, bincode_deserialize, build_offline_tx,
+            decode_base58, decode_base64, encode_tx_base64, format_block_time, missing_signers,
+            send_and_confirm,
+        },
+        notice,
+        prompt::{PubkeyCheck, prompt_data, prompt_pubkey, prompt_select},
+        ui::{new_table, print_output, print_tx_output, show_spinner},
     },
-    comfy_table::{Cell, Table, presets::UTF8_FULL},
+    anyhow::{Context, bail},
+    clap::ValueEnum,
+    comfy_table::{Cell, presets::UTF8_FULL},
     console::style,
     inquire::Select,
-    solana_rpc_client_api::config::RpcTransactionConfig,
+    serde_json::{Value, json},
+    solana_commitment_config::{CommitmentConfig, CommitmentLevel},
+    solana_hash::Hash,
+    solana_pubkey::Pubkey,
+    solana_rpc_client_api::{config::RpcTransactionConfig, request::RpcRequest},
     solana_signature::Signature,
-    solana_transaction::versioned::VersionedTransaction,
-    solana_transaction_status::{EncodedTransaction, UiMessage, UiTransactionEncoding},
+    solana_system_interface::instruction::transfer,
+    solana_transaction::{Transaction, versioned::VersionedTransaction},
+    solana_transaction_status::{
+        EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction, UiInstruction, UiMessage,
+        UiParsedInstruction, UiTransaction, UiTransactionEncoding,
+    },
     std::fmt,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, ValueEnum)]
 pub enum TransactionCommand {
     CheckConfirmation,
     FetchStatus,
     FetchTransaction,
     SendTransaction,
+    SignOffline,
+    Broadcast,
 }
 
 impl TransactionCommand {
@@ -32,6 +49,8 @@ impl TransactionCommand {
             Self::FetchStatus => "Fetching transaction status…",
             Self::FetchTransaction => "Fetching full transaction data…",
             Self::SendTransaction => "Sending transaction…",
+            Self::SignOffline => "Fetching blockhash…",
+            Self::Broadcast => "Broadcasting transaction…",
         }
     }
 }
@@ -43,6 +62,8 @@ impl fmt::Display for TransactionCommand {
             Self::FetchStatus => "Fetch Transaction Status",
             Self::FetchTransaction => "Fetch Transaction",
             Self::SendTransaction => "Send Transaction",
+            Self::SignOffline => "Sign Transfer Offline",
+            Self::Broadcast => "Broadcast Signed Transaction",
         })
     }
 }
@@ -75,18 +96,17 @@ impl TransactionCommand {
                 .await?;
             }
             TransactionCommand::SendTransaction => {
-                println!(
+                notice!(
                     "{}",
                     style("Note: Only VersionedTransaction format is supported")
                         .yellow()
                         .dim()
                 );
 
-                let encoding = Select::new(
+                let encoding = prompt_select(Select::new(
                     "Select encoding format:",
                     vec![UiTransactionEncoding::Base64, UiTransactionEncoding::Base58],
-                )
-                .prompt()?;
+                ))?;
 
                 let encoded_tx: String = prompt_data("Enter encoded transaction:")?;
 
@@ -96,6 +116,43 @@ impl TransactionCommand {
                 )
                 .await?;
             }
+            TransactionCommand::SignOffline => {
+                let recipient =
+                    prompt_pubkey(ctx, "Enter Recipient Address:", PubkeyCheck::Wallet).await?;
+                let amount: SolAmount = prompt_data("Enter Amount to Transfer (SOL):")?;
+                let fee_payer: OptionalInput<Pubkey> =
+                    prompt_data("Enter Fee Payer (leave empty for your wallet):")?;
+                let nonce_account: OptionalInput<Pubkey> =
+                    prompt_data("Enter Nonce Account (leave empty to use a recent blockhash):")?;
+                let blockhash: OptionalInput<Hash> = prompt_data(
+                    "Enter Blockhash or Nonce Value (leave empty to fetch it from the RPC):",
+                )?;
+
+                let nonce_account = nonce_account.value();
+                let blockhash = match blockhash.value() {
+                    Some(blockhash) => blockhash,
+                    None => {
+                        show_spinner(
+                            self.spinner_msg(),
+                            fetch_offline_blockhash(ctx, nonce_account.as_ref()),
+                        )
+                        .await?
+                    }
+                };
+
+                process_sign_offline(
+                    ctx,
+                    &recipient,
+                    amount.to_lamports(),
+                    &fee_payer.value().unwrap_or(*ctx.pubkey()),
+                    nonce_account,
+                    blockhash,
+                )?;
+            }
+            TransactionCommand::Broadcast => {
+                let encoded_tx: String = prompt_data("Enter Base64 encoded transaction:")?;
+                show_spinner(self.spinner_msg(), process_broadcast(ctx, &encoded_tx)).await?;
+            }
         }
 
         Ok(CommandExec::Process(()))
@@ -114,7 +171,7 @@ async fn process_check_confirmation(
         style("Not Confirmed").yellow()
     };
 
-    let mut table = Table::new();
+    let mut table = new_table();
     table
         .load_preset(UTF8_FULL)
         .set_header(vec![
@@ -130,10 +187,12 @@ async fn process_check_confirmation(
             Cell::new(status_styled.to_string()),
         ]);
 
-    println!("\n{}", style("TRANSACTION CONFIRMATION").green().bold());
-    println!("{}", table);
+    let value = json!({ "signature": signature.to_string(), "confirmed": confirmed });
 
-    Ok(())
+    print_output(ctx, &value, || {
+        println!("\n{}", style("TRANSACTION CONFIRMATION").green().bold());
+        println!("{}", table);
+    })
 }
 
 async fn process_fetch_transaction_status(
@@ -146,7 +205,7 @@ async fn process_fetch_transaction_status(
         anyhow::bail!("Transaction not found");
     };
 
-    let mut table = Table::new();
+    let mut table = new_table();
     table
         .load_preset(UTF8_FULL)
         .set_header(vec![
@@ -172,29 +231,78 @@ async fn process_fetch_transaction_status(
             }),
         ]);
 
-    println!("\n{}", style("TRANSACTION STATUS").green().bold());
-    println!("{}", table);
-
-    Ok(())
+    let value = json!({
+        "signature": signature.to_string(),
+        "slot": tx_status.slot,
+        "confirmation_status": tx_status.confirmation_status(),
+        "err": tx_status.err,
+    });
+
+    print_output(ctx, &value, || {
+        println!("\n{}", style("TRANSACTION STATUS").green().bold());
+        println!("{}", table);
+    })
 }
 
 async fn process_fetch_transaction(
     ctx: &ScillaContext,
     signature: &Signature,
 ) -> anyhow::Result<()> {
-    let tx = ctx
+    // getTransaction does not accept processed commitment
+    let commitment = match ctx.rpc().commitment().commitment {
+        CommitmentLevel::Processed => CommitmentConfig::confirmed(),
+        _ => ctx.rpc().commitment(),
+    };
+
+    let config = RpcTransactionConfig {
+        encoding: Some(UiTransactionEncoding::JsonParsed),
+        commitment: Some(commitment),
+        max_supported_transaction_version: Some(0),
+    };
+
+    // Fetched as an Option so a missing transaction is not reported as a
+    // deserialization error
+    let tx: Option<EncodedConfirmedTransactionWithStatusMeta> = ctx
         .rpc()
-        .get_transaction_with_config(
-            signature,
-            RpcTransactionConfig {
-                encoding: Some(UiTransactionEncoding::JsonParsed),
-                commitment: Some(ctx.rpc().commitment()),
-                max_supported_transaction_version: Some(0),
-            },
+        .send(
+            RpcRequest::GetTransaction,
+            json!([signature.to_string(), config]),
         )
         .await?;
 
-    let mut table = Table::new();
+    let Some(tx) = tx else {
+        let statuses = ctx.rpc().get_signature_statuses(&[*signature]).await?;
+        match statuses.value.into_iter().next().flatten() {
+            Some(status) => anyhow::bail!(
+                "Transaction landed in slot {} but is not {:?} yet (currently {:?}). Try again in \
+                 a few seconds",
+                status.slot,
+                commitment.commitment,
+                status.confirmation_status()
+            ),
+            None => anyhow::bail!(
+                "Transaction not found. It may not have landed, or it is older than the history \
+                 this RPC node keeps"
+            ),
+        }
+    };
+
+    let EncodedTransaction::Json(ui_tx) = &tx.transaction.transaction else {
+        anyhow::bail!("Transaction encoding is not JSON");
+    };
+
+    print_output(ctx, &tx, || {
+        print_transaction_details(ctx, signature, &tx, ui_tx)
+    })
+}
+
+fn print_transaction_details(
+    ctx: &ScillaContext,
+    signature: &Signature,
+    tx: &EncodedConfirmedTransactionWithStatusMeta,
+    ui_tx: &UiTransaction,
+) {
+    let mut table = new_table();
     table
         .load_preset(UTF8_FULL)
         .set_header(vec![
@@ -210,7 +318,7 @@ async fn process_fetch_transaction(
     if let Some(block_time) = tx.block_time {
         table.add_row(vec![
             Cell::new("Block Time"),
-            Cell::new(block_time.to_string()),
+            Cell::new(format_block_time(block_time, ctx.timestamps())),
         ]);
     }
 
@@ -219,6 +327,12 @@ async fn process_fetch_transaction(
             Cell::new("Fee (lamports)"),
             Cell::new(meta.fee.to_string()),
         ]);
+        if let Some(compute_units) = Option::<&u64>::from(meta.compute_units_consumed.as_ref()) {
+            table.add_row(vec![
+                Cell::new("Compute Units"),
+                Cell::new(compute_units.to_string()),
+            ]);
+        }
         table.add_row(vec![
             Cell::new("Status"),
             Cell::new(if meta.err.is_none() {
@@ -229,18 +343,14 @@ async fn process_fetch_transaction(
         ]);
     }
 
-    println!("\n{}", style("TRANSACTION DETAILS").green().bold());
-    println!("{}", table);
-
-    let EncodedTransaction::Json(ui_tx) = &tx.transaction.transaction else {
-        anyhow::bail!("Transaction encoding is not JSON");
-    };
+    notice!("\n{}", style("TRANSACTION DETAILS").green().bold());
+    notice!("{}", table);
 
     match &ui_tx.message {
         UiMessage::Parsed(parsed_msg) => {
-            println!("\n{}", style("TRANSACTION MESSAGE").cyan().bold());
+            notice!("\n{}", style("TRANSACTION MESSAGE").cyan().bold());
 
-            let mut msg_table = Table::new();
+            let mut msg_table = new_table();
             msg_table
                 .load_preset(UTF8_FULL)
                 .set_header(vec![
@@ -256,11 +366,11 @@ async fn process_fetch_transaction(
                     Cell::new(parsed_msg.recent_blockhash.clone()),
                 ]);
 
-            println!("{}", msg_table);
+            notice!("{}", msg_table);
 
             if !parsed_msg.account_keys.is_empty() {
-                println!("\n{}", style("ACCOUNT KEYS").cyan().bold());
-                let mut accounts_table = Table::new();
+                notice!("\n{}", style("ACCOUNT KEYS").cyan().bold());
+                let mut accounts_table = new_table();
                 accounts_table.load_preset(UTF8_FULL).set_header(vec![
                     Cell::new("Index").add_attribute(comfy_table::Attribute::Bold),
                     Cell::new("Pubkey").add_attribute(comfy_table::Attribute::Bold),
@@ -276,13 +386,13 @@ async fn process_fetch_transaction(
                         Cell::new(if account.writable { "✓" } else { "" }),
                     ]);
                 }
-                println!("{}", accounts_table);
+                notice!("{}", accounts_table);
             }
         }
         UiMessage::Raw(raw_msg) => {
-            println!("\n{}", style("TRANSACTION MESSAGE (Raw)").cyan().bold());
+            notice!("\n{}", style("TRANSACTION MESSAGE (Raw)").cyan().bold());
 
-            let mut msg_table = Table::new();
+            let mut msg_table = new_table();
             msg_table
                 .load_preset(UTF8_FULL)
                 .set_header(vec![
@@ -298,18 +408,134 @@ async fn process_fetch_transaction(
                     Cell::new(raw_msg.recent_blockhash.clone()),
                 ]);
 
-            println!("{}", msg_table);
+            notice!("{}", msg_table);
 
             if !raw_msg.account_keys.is_empty() {
-                println!("\n{}", style("ACCOUNT KEYS").cyan().bold());
+                notice!("\n{}", style("ACCOUNT KEYS").cyan().bold());
                 for (idx, key) in raw_msg.account_keys.iter().enumerate() {
-                    println!("  {}. {}", idx, key);
+                    notice!("  {}. {}", idx, key);
                 }
             }
         }
     }
 
-    Ok(())
+    print_instructions(ui_tx);
+
+    let logs = tx
+        .transaction
+        .meta
+        .as_ref()
+        .and_then(|meta| Option::<&Vec<String>>::from(meta.log_messages.as_ref()));
+    if let Some(logs) = logs.filter(|logs| !logs.is_empty()) {
+        notice!("\n{}", style("LOG MESSAGES").cyan().bold());
+        for log in logs {
+            notice!("  {}", style(log).dim());
+        }
+    }
+}
+
+/// Prints one row per top-level instruction with the program that ran it and
+/// the accounts it touched
+fn print_instructions(ui_tx: &UiTransaction) {
+    let rows: Vec<(String, String, String)> = match &ui_tx.message {
+        UiMessage::Parsed(parsed_msg) => parsed_msg
+            .instructions
+            .iter()
+            .map(|instruction| match instruction {
+                UiInstruction::Parsed(UiParsedInstruction::Parsed(parsed)) => (
+                    format!("{} ({})", parsed.program, parsed.program_id),
+                    parsed.parsed["type"].as_str().unwrap_or("-").to_string(),
+                    parsed_instruction_fields(&parsed.parsed["info"]),
+                ),
+                UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(decoded)) => (
+                    decoded.program_id.clone(),
+                    "-".to_string(),
+                    decoded.accounts.join("\n"),
+                ),
+                UiInstruction::Compiled(compiled) => {
+                    let key = |idx: u8| {
+                        parsed_msg
+                            .account_keys
+                            .get(idx as usize)
+                            .map_or_else(|| format!("#{idx}"), |account| account.pubkey.clone())
+                    };
+                    (
+                        key(compiled.program_id_index),
+                        "-".to_string(),
+                        compiled
+                            .accounts
+                            .iter()
+                            .map(|idx| key(*idx))
+                            .collect::<Vec<_>>()
+                            .join("\n"),
+                    )
+                }
+            })
+            .collect(),
+        UiMessage::Raw(raw_msg) => raw_msg
+            .instructions
+            .iter()
+            .map(|compiled| {
+                let key = |idx: u8| {
+                    raw_msg
+                        .account_keys
+                        .get(idx as usize)
+                        .cloned()
+                        .unwrap_or_else(|| format!("#{idx}"))
+                };
+                (
+                    key(compiled.program_id_index),
+                    "-".to_string(),
+                    compiled
+                        .accounts
+                        .iter()
+                        .map(|idx| key(*idx))
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                )
+            })
+            .collect(),
+    };
+
+    if rows.is_empty() {
+        return;
+    }
+
+    let mut table = new_table();
+    table.load_preset(UTF8_FULL).set_header(vec![
+        Cell::new("#").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Program").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Type").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new("Accounts / Details").add_attribute(comfy_table::Attribute::Bold),
+    ]);
+
+    for (idx, (program, kind, accounts)) in rows.into_iter().enumerate() {
+        table.add_row(vec![
+            Cell::new(idx),
+            Cell::new(program),
+            Cell::new(kind),
+            Cell::new(accounts),
+        ]);
+    }
+
+    notice!("\n{}", style("INSTRUCTIONS").cyan().bold());
+    notice!("{}", table);
+}
+
+/// Renders the `info` object of a parsed instruction as `key: value` lines
+fn parsed_instruction_fields(info: &Value) -> String {
+    let Some(fields) = info.as_object() else {
+        return String::new();
+    };
+
+    fields
+        .iter()
+        .map(|(key, value)| match value {
+            Value::String(s) => format!("{key}: {s}"),
+            other => format!("{key}: {other}"),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
 async fn process_send_transaction(
@@ -328,11 +554,127 @@ async fn process_send_transaction(
 
     let signature = ctx.rpc().send_transaction(&tx).await?;
 
-    println!(
-        "\n{} {}",
-        style("Transaction sent successfully!").green().bold(),
-        style(signature.to_string()).cyan()
-    );
+    print_tx_output(ctx, &signature, json!({}), || {
+        println!(
+            "\n{} {}",
+            style("Transaction sent successfully!").green().bold(),
+            style(signature.to_string()).cyan()
+        );
+    })?;
+
+    Ok(())
+}
+
+/// The blockhash to sign an offline transaction over: the value stored in the
+/// nonce account when one is used, otherwise the latest blockhash
+async fn fetch_offline_blockhash(
+    ctx: &ScillaContext,
+    nonce_account: Option<&Pubkey>,
+) -> anyhow::Result<Hash> {
+    match nonce_account {
+        Some(nonce_account) => {
+            let (_, data) = fetch_nonce_data(ctx, nonce_account).await?;
+            Ok(data.blockhash())
+        }
+        None => ctx.latest_blockhash().await,
+    }
+}
+
+fn process_sign_offline(
+    ctx: &ScillaContext,
+    recipient: &Pubkey,
+    lamports: u64,
+    fee_payer: &Pubkey,
+    nonce_account: Option<Pubkey>,
+    blockhash: Hash,
+) -> anyhow::Result<()> {
+    let nonce = nonce_account.map(|account| DurableNonce {
+        account,
+        authority: *ctx.pubkey(),
+    });
+    let instruction = transfer(ctx.pubkey(), recipient, lamports);
+
+    let tx = build_offline_tx(
+        &[instruction],
+        fee_payer,
+        &[ctx.signer()],
+        blockhash,
+        nonce,
+        ctx.priority_fee_micro_lamports(),
+        ctx.compute_unit_limit(),
+    )?;
+    let encoded_tx = encode_tx_base64(&tx)?;
+    let missing = missing_signers(&tx);
+
+    let value = json!({
+        "transaction": encoded_tx,
+        "blockhash": blockhash.to_string(),
+        "nonce_account": nonce_account.map(|account| account.to_string()),
+        "missing_signers": missing.iter().map(|pubkey| pubkey.to_string()).collect::<Vec<_>>(),
+    });
+
+    print_output(ctx, &value, || {
+        println!("\n{}", style("SIGNED TRANSACTION (BASE64)").green().bold());
+        println!("{encoded_tx}");
+
+        if nonce_account.is_none() {
+            println!(
+                "\n{}",
+                style(
+                    "Signed over a recent blockhash, so it must be broadcast within about a \
+                     minute. Use a nonce account to keep it valid longer."
+                )
+                .yellow()
+            );
+        }
+
+        if missing.is_empty() {
+            println!(
+                "\n{}",
+                style(
+                    "Fully signed. Broadcast it with Transaction > Broadcast Signed Transaction."
+                )
+                .cyan()
+            );
+        } else {
+            println!("\n{}", style("Still needs signatures from:").yellow());
+            for pubkey in &missing {
+                println!("  {}", style(pubkey).cyan());
+            }
+        }
+    })
+}
+
+async fn process_broadcast(ctx: &ScillaContext, encoded_tx: &str) -> anyhow::Result<()> {
+    let mut tx: Transaction =
+        bincode_deserialize(&decode_base64(encoded_tx)?, "encoded transaction")?;
+
+    // Add our signature if the transaction still waits for it
+    if missing_signers(&tx).contains(ctx.pubkey()) {
+        let blockhash = tx.message.recent_blockhash;
+        tx.try_partial_sign(&[ctx.signer()], blockhash)?;
+    }
+
+    let missing = missing_signers(&tx);
+    if !missing.is_empty() {
+        let missing: Vec<_> = missing.iter().map(|pubkey| pubkey.to_string()).collect();
+        bail!(
+            "Transaction is missing signatures from: {}",
+            missing.join(", ")
+        );
+    }
+    tx.verify()
+        .context("Transaction has a signature that doesn't match its message")?;
+
+    let signature = send_and_confirm(ctx, &tx).await?;
+
+    print_tx_output(ctx, &signature, json!({}), || {
+        println!(
+            "\n{} {}",
+            style("Transaction broadcast successfully!").green().bold(),
+            style(signature.to_string()).cyan()
+        );
+    })?;
 
     Ok(())
 }
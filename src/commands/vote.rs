@@ -2,16 +2,22 @@ use {
     crate::{
         ScillaContext, ScillaResult,
         commands::CommandExec,
+        constants::RECENT_EPOCH_CREDITS_LIMIT,
         misc::helpers::{
-            Commission, SolAmount, build_and_send_tx, fetch_account_with_epoch, lamports_to_sol,
-            read_keypair_from_path,
+            Commission, OptionalInput, SolAmount, build_and_send_tx, confirm_fee,
+            ensure_sufficient_balance, fetch_account_with_epoch, format_block_time,
+            lamports_to_sol, read_keypair_from_path, simulate_then_send,
         },
-        prompt::prompt_data,
-        ui::show_spinner,
+        notice,
+        prompt::{PubkeyCheck, prompt_data, prompt_pubkey, prompt_select, prompt_yes_no},
+        ui::{new_table, print_output, print_tx_output, show_spinner},
     },
     anyhow::{anyhow, bail},
-    comfy_table::{Cell, Table, presets::UTF8_FULL},
+    clap::ValueEnum,
+    comfy_table::{Cell, presets::UTF8_FULL},
     console::style,
+    inquire::{Confirm, Select},
+    serde_json::json,
     solana_keypair::{Keypair, Signer},
     solana_pubkey::Pubkey,
     solana_rpc_client_api::config::RpcGetVoteAccountsConfig,
@@ -23,13 +29,16 @@ use {
 };
 
 /// Commands related to validator/vote account operations
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, ValueEnum)]
 pub enum VoteCommand {
     CreateVoteAccount,
-    AuthorizeVoter,
+    Authorize,
+    UpdateIdentity,
+    UpdateCommission,
     WithdrawFromVoteAccount,
     ShowVoteAccount,
     CloseVoteAccount,
+    #[value(skip)]
     GoBack,
 }
 
@@ -37,7 +46,9 @@ impl VoteCommand {
     pub fn spinner_msg(&self) -> &'static str {
         match self {
             VoteCommand::CreateVoteAccount => "Creating vote account…",
-            VoteCommand::AuthorizeVoter => "Authorizing voter…",
+            VoteCommand::Authorize => "Updating vote authority…",
+            VoteCommand::UpdateIdentity => "Updating validator identity…",
+            VoteCommand::UpdateCommission => "Updating vote account commission…",
             VoteCommand::WithdrawFromVoteAccount => "Withdrawing SOL from vote account…",
             VoteCommand::ShowVoteAccount => "Fetching vote account details…",
             VoteCommand::CloseVoteAccount => "Closing vote account…",
@@ -50,7 +61,9 @@ impl fmt::Display for VoteCommand {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let text = match self {
             VoteCommand::CreateVoteAccount => "Create vote account",
-            VoteCommand::AuthorizeVoter => "Authorize voter",
+            VoteCommand::Authorize => "Change vote authority",
+            VoteCommand::UpdateIdentity => "Update validator identity",
+            VoteCommand::UpdateCommission => "Update commission",
             VoteCommand::WithdrawFromVoteAccount => "Withdraw from vote account",
             VoteCommand::ShowVoteAccount => "Show vote account",
             VoteCommand::CloseVoteAccount => "Close vote account",
@@ -66,6 +79,8 @@ impl VoteCommand {
             VoteCommand::CreateVoteAccount => {
                 let account_keypair_path: PathBuf = prompt_data("Enter Account Keypair Path:")?;
                 let identity_keypair_path: PathBuf = prompt_data("Enter Identity Keypair Path:")?;
+                let vote_authority: OptionalInput<Pubkey> =
+                    prompt_data("Enter Vote Authority Address (leave empty to use identity):")?;
                 let withdraw_keypair_path: PathBuf = prompt_data("Enter Withdraw Keypair Path:")?;
                 let commission: Commission = prompt_data("Enter Commission 0-100 (default 0):")?;
 
@@ -81,38 +96,109 @@ impl VoteCommand {
                         ctx,
                         &account_keypair,
                         &identity_keypair,
+                        vote_authority.value(),
                         &withdraw_keypair,
                         commission.value(),
                     ),
                 )
                 .await?;
             }
-            VoteCommand::AuthorizeVoter => {
-                let vote_account_pubkey: Pubkey = prompt_data("Enter Vote Account Address:")?;
+            VoteCommand::Authorize => {
+                let vote_account_pubkey =
+                    prompt_pubkey(ctx, "Enter Vote Account Address:", PubkeyCheck::Existing)
+                        .await?;
+                let authority_choice = prompt_select(Select::new(
+                    "Authority to change:",
+                    vec!["Voter", "Withdrawer"],
+                ))?;
+                let vote_authorize = match authority_choice {
+                    "Withdrawer" => VoteAuthorize::Withdrawer,
+                    _ => VoteAuthorize::Voter,
+                };
                 let authorized_keypair_path: PathBuf =
-                    prompt_data("Enter Authorized Keypair Path:")?;
-                let new_authorized_pubkey: Pubkey = prompt_data("Enter New Authorized Address:")?;
+                    prompt_data("Enter Current Authority Keypair Path:")?;
+                let new_authorized_pubkey =
+                    prompt_pubkey(ctx, "Enter New Authorized Address:", PubkeyCheck::Wallet)
+                        .await?;
 
                 let authorized_keypair = read_keypair_from_path(&authorized_keypair_path)?;
 
                 show_spinner(
                     self.spinner_msg(),
-                    process_authorize_voter(
+                    process_authorize_vote(
                         ctx,
                         &vote_account_pubkey,
                         &authorized_keypair,
                         &new_authorized_pubkey,
+                        vote_authorize,
                     ),
                 )
                 .await?;
             }
-            VoteCommand::WithdrawFromVoteAccount => {
-                let vote_account_pubkey: Pubkey = prompt_data("Enter Vote Account Address:")?;
+            VoteCommand::UpdateIdentity => {
+                let vote_account_pubkey =
+                    prompt_pubkey(ctx, "Enter Vote Account Address:", PubkeyCheck::Existing)
+                        .await?;
+                let identity_keypair_path: PathBuf =
+                    prompt_data("Enter New Identity Keypair Path:")?;
                 let authorized_keypair_path: PathBuf =
                     prompt_data("Enter Authorized Withdraw Keypair Path:")?;
-                let recipient_address: Pubkey = prompt_data("Enter Recipient Address:")?;
 
-                let amount: SolAmount = prompt_data("Enter withdraw amount in SOL:")?;
+                let identity_keypair = read_keypair_from_path(&identity_keypair_path)?;
+                let authorized_keypair = read_keypair_from_path(&authorized_keypair_path)?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_update_validator_identity(
+                        ctx,
+                        &vote_account_pubkey,
+                        &identity_keypair,
+                        &authorized_keypair,
+                    ),
+                )
+                .await?;
+            }
+            VoteCommand::UpdateCommission => {
+                let vote_account_pubkey =
+                    prompt_pubkey(ctx, "Enter Vote Account Address:", PubkeyCheck::Existing)
+                        .await?;
+                let authorized_keypair_path: PathBuf =
+                    prompt_data("Enter Authorized Withdraw Keypair Path:")?;
+                let commission: Commission = prompt_data("Enter New Commission 0-100:")?;
+
+                let authorized_keypair = read_keypair_from_path(&authorized_keypair_path)?;
+
+                show_spinner(
+                    self.spinner_msg(),
+                    process_update_commission(
+                        ctx,
+                        &vote_account_pubkey,
+                        &authorized_keypair,
+                        commission.value(),
+                    ),
+                )
+                .await?;
+            }
+            VoteCommand::WithdrawFromVoteAccount => {
+                let vote_account_pubkey =
+                    prompt_pubkey(ctx, "Enter Vote Account Address:", PubkeyCheck::Existing)
+                        .await?;
+                let authorized_keypair_path: PathBuf =
+                    prompt_data("Enter Authorized Withdraw Keypair Path:")?;
+                let recipient_address =
+                    prompt_pubkey(ctx, "Enter Recipient Address:", PubkeyCheck::Wallet).await?;
+
+                let close_account = prompt_yes_no(
+                    Confirm::new("Withdraw the entire balance and close the vote account?")
+                        .with_default(false),
+                )?;
+
+                let amount = if close_account {
+                    None
+                } else {
+                    let amount: SolAmount = prompt_data("Enter withdraw amount in SOL:")?;
+                    Some(amount.to_lamports())
+                };
                 let authorized_keypair = read_keypair_from_path(&authorized_keypair_path)?;
 
                 show_spinner(
@@ -122,13 +208,15 @@ impl VoteCommand {
                         &vote_account_pubkey,
                         &authorized_keypair,
                         &recipient_address,
-                        amount.to_lamports(),
+                        amount,
                     ),
                 )
                 .await?;
             }
             VoteCommand::ShowVoteAccount => {
-                let vote_account_pubkey: Pubkey = prompt_data("Enter Vote Account Address:")?;
+                let vote_account_pubkey =
+                    prompt_pubkey(ctx, "Enter Vote Account Address:", PubkeyCheck::Existing)
+                        .await?;
                 show_spinner(
                     self.spinner_msg(),
                     process_fetch_vote_account(ctx, &vote_account_pubkey),
@@ -136,10 +224,13 @@ impl VoteCommand {
                 .await?;
             }
             VoteCommand::CloseVoteAccount => {
-                let vote_account_pubkey: Pubkey = prompt_data("Enter Vote Account Address:")?;
+                let vote_account_pubkey =
+                    prompt_pubkey(ctx, "Enter Vote Account Address:", PubkeyCheck::Existing)
+                        .await?;
                 let withdraw_authority_path: PathBuf =
                     prompt_data("Enter Withdraw Authority Keypair Path:")?;
-                let destination_pubkey: Pubkey = prompt_data("Enter Destination Address:")?;
+                let destination_pubkey =
+                    prompt_pubkey(ctx, "Enter Destination Address:", PubkeyCheck::Wallet).await?;
 
                 let withdraw_authority = read_keypair_from_path(&withdraw_authority_path)?;
 
@@ -165,12 +256,14 @@ async fn process_create_vote_account(
     ctx: &ScillaContext,
     vote_account_keypair: &Keypair,
     identity_keypair: &Keypair,
+    authorized_voter: Option<Pubkey>,
     authorized_withdrawer: &Keypair,
     commission: u8,
 ) -> anyhow::Result<()> {
     let vote_account_pubkey = vote_account_keypair.pubkey();
     let identity_pubkey = identity_keypair.pubkey();
     let withdrawer_pubkey = authorized_withdrawer.pubkey();
+    let voter_pubkey = authorized_voter.unwrap_or(identity_pubkey);
     let fee_payer_pubkey = ctx.pubkey();
 
     if fee_payer_pubkey == &vote_account_pubkey {
@@ -202,7 +295,7 @@ async fn process_create_vote_account(
 
     let vote_init = VoteInit {
         node_pubkey: identity_pubkey,
-        authorized_voter: identity_pubkey, // defaults to identity
+        authorized_voter: voter_pubkey,
         authorized_withdrawer: withdrawer_pubkey,
         commission,
     };
@@ -215,32 +308,57 @@ async fn process_create_vote_account(
         CreateVoteAccountConfig::default(),
     );
 
+    ensure_sufficient_balance(ctx, &instructions, &[("rent exemption", required_balance)]).await?;
+
+    confirm_fee(ctx, &instructions).await?;
+
     let signature = build_and_send_tx(
         ctx,
         &instructions,
-        &[ctx.keypair(), vote_account_keypair, identity_keypair],
+        &[ctx.signer(), vote_account_keypair, identity_keypair],
     )
     .await?;
 
-    println!(
-        "{} {}",
-        style("Vote account created successfully!").green().bold(),
-        style(format!("Signature: {signature}")).cyan()
-    );
-    println!(
-        "{} {}",
-        style("Vote account address:").green(),
-        style(vote_account_pubkey).cyan()
-    );
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "vote_account": vote_account_pubkey.to_string(),
+            "identity": identity_pubkey.to_string(),
+            "authorized_voter": voter_pubkey.to_string(),
+            "authorized_withdrawer": withdrawer_pubkey.to_string(),
+            "commission": commission,
+        }),
+        || {
+            println!(
+                "{} {}",
+                style("Vote account created successfully!").green().bold(),
+                style(format!("Signature: {signature}")).cyan()
+            );
+            println!(
+                "{} {}",
+                style("Vote account address:").green(),
+                style(vote_account_pubkey).cyan()
+            );
+            println!(
+                "{} {}",
+                style("Vote authority:").green(),
+                style(voter_pubkey).cyan()
+            );
+        },
+    )?;
 
     Ok(())
 }
 
-async fn process_authorize_voter(
+/// Rotates the vote or withdraw authority. The current voter or the
+/// withdrawer may change the voter, only the withdrawer may change itself.
+async fn process_authorize_vote(
     ctx: &ScillaContext,
     vote_account_pubkey: &Pubkey,
     authorized_keypair: &Keypair,
     new_authorized_pubkey: &Pubkey,
+    vote_authorize: VoteAuthorize,
 ) -> anyhow::Result<()> {
     let authorized_pubkey = authorized_keypair.pubkey();
 
@@ -260,33 +378,239 @@ async fn process_authorize_voter(
         .get_authorized_voter(current_epoch)
         .ok_or_else(|| anyhow!("Invalid vote account state; no authorized voters found"))?;
 
-    if authorized_pubkey != current_authorized_voter
-        && authorized_pubkey != vote_state.authorized_withdrawer
-    {
-        bail!(
-            "Keypair {} is not the current authorized voter ({}) or withdrawer ({})",
-            authorized_pubkey,
-            current_authorized_voter,
-            vote_state.authorized_withdrawer
-        );
+    let (authority_label, old_authority) = match vote_authorize {
+        VoteAuthorize::Voter => {
+            if authorized_pubkey != current_authorized_voter
+                && authorized_pubkey != vote_state.authorized_withdrawer
+            {
+                bail!(
+                    "Keypair {} is not the current authorized voter ({}) or withdrawer ({})",
+                    authorized_pubkey,
+                    current_authorized_voter,
+                    vote_state.authorized_withdrawer
+                );
+            }
+            ("voter", current_authorized_voter)
+        }
+        VoteAuthorize::Withdrawer => {
+            if authorized_pubkey != vote_state.authorized_withdrawer {
+                bail!(
+                    "Keypair {} is not the authorized withdrawer ({})",
+                    authorized_pubkey,
+                    vote_state.authorized_withdrawer
+                );
+            }
+            ("withdrawer", vote_state.authorized_withdrawer)
+        }
+    };
+
+    if old_authority == *new_authorized_pubkey {
+        bail!("{new_authorized_pubkey} is already the authorized {authority_label}");
     }
 
     let vote_ix = vote_instruction::authorize(
         vote_account_pubkey,
         &authorized_pubkey,
         new_authorized_pubkey,
-        VoteAuthorize::Voter,
+        vote_authorize,
     );
 
-    let signature =
-        build_and_send_tx(ctx, &[vote_ix], &[ctx.keypair(), authorized_keypair]).await?;
+    confirm_fee(ctx, std::slice::from_ref(&vote_ix)).await?;
+
+    let signature = build_and_send_tx(ctx, &[vote_ix], &[ctx.signer(), authorized_keypair]).await?;
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "vote_account": vote_account_pubkey.to_string(),
+            "authority": authority_label,
+            "old_authority": old_authority.to_string(),
+            "new_authority": new_authorized_pubkey.to_string(),
+        }),
+        || {
+            println!(
+                "{}\n{}\n{}\n{}",
+                style(format!(
+                    "Authorized {authority_label} updated successfully!"
+                ))
+                .green()
+                .bold(),
+                style(format!("Old Authority: {old_authority}")).yellow(),
+                style(format!("New Authority: {new_authorized_pubkey}")).yellow(),
+                style(format!("Signature: {signature}")).cyan()
+            );
+            // A new voter only starts voting from the next epoch
+            if matches!(vote_authorize, VoteAuthorize::Voter) {
+                println!(
+                    "{}",
+                    style(format!(
+                        "The new voter takes over at the start of epoch {}.",
+                        current_epoch + 1
+                    ))
+                    .dim()
+                );
+            }
+        },
+    )?;
+
+    Ok(())
+}
+
+async fn process_update_validator_identity(
+    ctx: &ScillaContext,
+    vote_account_pubkey: &Pubkey,
+    new_identity: &Keypair,
+    authorized_withdrawer: &Keypair,
+) -> anyhow::Result<()> {
+    let withdrawer_pubkey = authorized_withdrawer.pubkey();
+    let new_identity_pubkey = new_identity.pubkey();
+
+    let vote_account = ctx.rpc().get_account(vote_account_pubkey).await?;
+
+    if vote_account.owner != solana_vote_program::id() {
+        bail!("{vote_account_pubkey} is not a vote account");
+    }
+
+    let vote_state = VoteStateV4::deserialize(&vote_account.data, vote_account_pubkey)
+        .map_err(|_| anyhow!("Account data could not be deserialized to vote state"))?;
 
-    println!(
-        "{} {}",
-        style("Signature:").green().bold(),
-        style(signature).cyan()
+    if withdrawer_pubkey != vote_state.authorized_withdrawer {
+        bail!(
+            "Keypair {} is not the authorized withdrawer ({})",
+            withdrawer_pubkey,
+            vote_state.authorized_withdrawer
+        );
+    }
+
+    let old_identity = vote_state.node_pubkey;
+
+    if old_identity == new_identity_pubkey {
+        bail!("{new_identity_pubkey} is already the identity of this vote account");
+    }
+
+    // The new identity has to sign, proving the operator holds its key
+    let update_ix = vote_instruction::update_validator_identity(
+        vote_account_pubkey,
+        &withdrawer_pubkey,
+        &new_identity_pubkey,
+    );
+
+    confirm_fee(ctx, std::slice::from_ref(&update_ix)).await?;
+
+    let signature = build_and_send_tx(
+        ctx,
+        &[update_ix],
+        &[ctx.signer(), authorized_withdrawer, new_identity],
+    )
+    .await?;
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "vote_account": vote_account_pubkey.to_string(),
+            "old_identity": old_identity.to_string(),
+            "new_identity": new_identity_pubkey.to_string(),
+        }),
+        || {
+            println!(
+                "{}\n{}\n{}",
+                style("Validator identity updated successfully!")
+                    .green()
+                    .bold(),
+                style(format!("Identity: {old_identity} → {new_identity_pubkey}")).yellow(),
+                style(format!("Signature: {signature}")).cyan()
+            );
+        },
+    )?;
+
+    Ok(())
+}
+
+async fn process_update_commission(
+    ctx: &ScillaContext,
+    vote_account_pubkey: &Pubkey,
+    authorized_withdrawer: &Keypair,
+    new_commission: u8,
+) -> anyhow::Result<()> {
+    let withdrawer_pubkey = authorized_withdrawer.pubkey();
+
+    let (vote_account, epoch_info) = fetch_account_with_epoch(ctx, vote_account_pubkey).await?;
+
+    if vote_account.owner != solana_vote_program::id() {
+        bail!("{vote_account_pubkey} is not a vote account");
+    }
+
+    let vote_state = VoteStateV4::deserialize(&vote_account.data, vote_account_pubkey)
+        .map_err(|_| anyhow!("Account data could not be deserialized to vote state"))?;
+
+    if withdrawer_pubkey != vote_state.authorized_withdrawer {
+        bail!(
+            "Keypair {} is not the authorized withdrawer ({})",
+            withdrawer_pubkey,
+            vote_state.authorized_withdrawer
+        );
+    }
+
+    let old_commission = (vote_state.inflation_rewards_commission_bps / 100) as u8;
+
+    if new_commission == old_commission {
+        bail!("Commission is already {old_commission}%");
+    }
+
+    if new_commission > old_commission {
+        // The vote program rejects commission increases in the second half of an
+        // epoch
+        if epoch_info.slot_index > epoch_info.slots_in_epoch / 2 {
+            bail!(
+                "Commission can only be raised during the first half of an epoch. Epoch {} is at \
+                 slot {} of {}; try again next epoch",
+                epoch_info.epoch,
+                epoch_info.slot_index,
+                epoch_info.slots_in_epoch
+            );
+        }
+
+        notice!(
+            "{}",
+            style(format!(
+                "⚠ Raising commission from {old_commission}% to {new_commission}%. Delegators \
+                 will earn less starting next epoch."
+            ))
+            .yellow()
+        );
+    }
+
+    let update_ix = vote_instruction::update_commission(
+        vote_account_pubkey,
+        &withdrawer_pubkey,
+        new_commission,
     );
 
+    confirm_fee(ctx, std::slice::from_ref(&update_ix)).await?;
+
+    let signature =
+        build_and_send_tx(ctx, &[update_ix], &[ctx.signer(), authorized_withdrawer]).await?;
+
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "vote_account": vote_account_pubkey.to_string(),
+            "old_commission": old_commission,
+            "new_commission": new_commission,
+        }),
+        || {
+            println!(
+                "{}\n{}\n{}",
+                style("Commission updated successfully!").green().bold(),
+                style(format!("Commission: {old_commission}% → {new_commission}%")).yellow(),
+                style(format!("Signature: {signature}")).cyan()
+            );
+        },
+    )?;
+
     Ok(())
 }
 
@@ -295,7 +619,7 @@ async fn process_sol_withdraw_from_vote_account(
     vote_account_pubkey: &Pubkey,
     authorized_withdrawer: &Keypair,
     recipient_address: &Pubkey,
-    amount: u64,
+    amount: Option<u64>,
 ) -> anyhow::Result<()> {
     let withdrawer_pubkey = authorized_withdrawer.pubkey();
 
@@ -320,6 +644,33 @@ async fn process_sol_withdraw_from_vote_account(
         );
     }
 
+    // Without an explicit amount the whole balance is withdrawn, closing the
+    // account. Partial withdrawals must leave the rent-exempt reserve behind.
+    let amount = match amount {
+        None => vote_account.lamports,
+        Some(amount) => {
+            let rent_exempt_minimum = ctx
+                .rpc()
+                .get_minimum_balance_for_rent_exemption(vote_account.data.len())
+                .await?;
+            let withdrawable = vote_account.lamports.saturating_sub(rent_exempt_minimum);
+
+            if amount > withdrawable {
+                bail!(
+                    "Can withdraw at most {} SOL while keeping the rent-exempt minimum of {} SOL. \
+                     Choose to close the account to withdraw everything",
+                    lamports_to_sol(withdrawable),
+                    lamports_to_sol(rent_exempt_minimum)
+                );
+            }
+            amount
+        }
+    };
+
+    if amount == 0 {
+        bail!("Vote account {vote_account_pubkey} has zero balance");
+    }
+
     let withdraw_ix = withdraw(
         vote_account_pubkey,
         &withdrawer_pubkey,
@@ -327,14 +678,30 @@ async fn process_sol_withdraw_from_vote_account(
         recipient_address,
     );
 
+    confirm_fee(ctx, std::slice::from_ref(&withdraw_ix)).await?;
+
     let signature =
-        build_and_send_tx(ctx, &[withdraw_ix], &[ctx.keypair(), authorized_withdrawer]).await?;
+        simulate_then_send(ctx, &[withdraw_ix], &[ctx.signer(), authorized_withdrawer]).await?;
 
-    println!(
-        "{} {}",
-        style("Signature:").green().bold(),
-        style(signature).cyan()
-    );
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "vote_account": vote_account_pubkey.to_string(),
+            "recipient": recipient_address.to_string(),
+            "lamports": amount,
+            "sol": lamports_to_sol(amount),
+        }),
+        || {
+            println!(
+                "{}\n{}\n{}\n{}",
+                style("Vote Withdrawal Successful!").green().bold(),
+                style(format!("To Recipient: {recipient_address}")).yellow(),
+                style(format!("Amount: {} SOL", lamports_to_sol(amount))).cyan(),
+                style(format!("Signature: {signature}")).cyan()
+            );
+        },
+    )?;
 
     Ok(())
 }
@@ -379,14 +746,27 @@ async fn close_vote_account(
         destination_pubkey,
     );
 
+    confirm_fee(ctx, std::slice::from_ref(&withdraw_ix)).await?;
+
     let signature =
-        build_and_send_tx(ctx, &[withdraw_ix], &[ctx.keypair(), withdraw_authority]).await?;
+        build_and_send_tx(ctx, &[withdraw_ix], &[ctx.signer(), withdraw_authority]).await?;
 
-    println!(
-        "{} {}",
-        style("Vote account closed! Signature:").green().bold(),
-        style(signature).cyan()
-    );
+    print_tx_output(
+        ctx,
+        &signature,
+        json!({
+            "vote_account": vote_account_pubkey.to_string(),
+            "destination": destination_pubkey.to_string(),
+            "lamports": current_balance,
+        }),
+        || {
+            println!(
+                "{} {}",
+                style("Vote account closed! Signature:").green().bold(),
+                style(signature).cyan()
+            );
+        },
+    )?;
 
     Ok(())
 }
@@ -415,10 +795,7 @@ async fn process_fetch_vote_account(
         None => "~".to_string(),
     };
 
-    let timestamp = chrono::DateTime::from_timestamp(vote_state.last_timestamp.timestamp, 0)
-        .expect("Solana timestamp should always be in valid range")
-        .format("%Y-%m-%dT%H:%M:%SZ")
-        .to_string();
+    let timestamp = format_block_time(vote_state.last_timestamp.timestamp, ctx.timestamps());
 
     let vote_authority = vote_state
         .authorized_voters
@@ -426,7 +803,7 @@ async fn process_fetch_vote_account(
         .map(|(_, v)| v.to_string())
         .unwrap_or_else(|| vote_state.node_pubkey.to_string());
 
-    let mut table = Table::new();
+    let mut table = new_table();
     table
         .load_preset(UTF8_FULL)
         .set_header(vec![
@@ -441,7 +818,10 @@ async fn process_fetch_vote_account(
             Cell::new("Validator Identity"),
             Cell::new(vote_state.node_pubkey.to_string()),
         ])
-        .add_row(vec![Cell::new("Vote Authority"), Cell::new(vote_authority)])
+        .add_row(vec![
+            Cell::new("Vote Authority"),
+            Cell::new(&vote_authority),
+        ])
         .add_row(vec![
             Cell::new("Withdraw Authority"),
             Cell::new(vote_state.authorized_withdrawer.to_string()),
@@ -466,8 +846,63 @@ async fn process_fetch_vote_account(
             )),
         ]);
 
-    println!("\n{}", style("VOTE ACCOUNT INFORMATION").green().bold());
-    println!("{table}");
+    let recent_credits: Vec<_> = vote_state
+        .epoch_credits
+        .iter()
+        .rev()
+        .take(RECENT_EPOCH_CREDITS_LIMIT)
+        .collect();
+
+    let value = json!({
+        "address": vote_account_pubkey.to_string(),
+        "lamports": vote_account.lamports,
+        "node_pubkey": vote_state.node_pubkey.to_string(),
+        "authorized_voter": vote_authority,
+        "authorized_withdrawer": vote_state.authorized_withdrawer.to_string(),
+        "credits": vote_state.credits(),
+        "commission": vote_state.inflation_rewards_commission_bps / 100,
+        "root_slot": vote_state.root_slot,
+        "last_timestamp": {
+            "slot": vote_state.last_timestamp.slot,
+            "timestamp": vote_state.last_timestamp.timestamp,
+        },
+        "recent_epoch_credits": recent_credits
+            .iter()
+            .map(|(epoch, credits, prev_credits)| {
+                json!({
+                    "epoch": epoch,
+                    "credits_earned": credits.saturating_sub(*prev_credits),
+                    "total_credits": credits,
+                })
+            })
+            .collect::<Vec<_>>(),
+    });
+
+    print_output(ctx, &value, || {
+        println!("\n{}", style("VOTE ACCOUNT INFORMATION").green().bold());
+        println!("{table}");
+
+        if recent_credits.is_empty() {
+            println!("{}", style("No epoch credits recorded yet.").yellow());
+            return;
+        }
+
+        let mut credits_table = new_table();
+        credits_table.load_preset(UTF8_FULL).set_header(vec![
+            Cell::new("Epoch").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Credits Earned").add_attribute(comfy_table::Attribute::Bold),
+            Cell::new("Total Credits").add_attribute(comfy_table::Attribute::Bold),
+        ]);
+
+        for (epoch, credits, prev_credits) in &recent_credits {
+            credits_table.add_row(vec![
+                Cell::new(epoch.to_string()),
+                Cell::new(credits.saturating_sub(*prev_credits).to_string()),
+                Cell::new(credits.to_string()),
+            ]);
+        }
 
-    Ok(())
+        println!("\n{}", style("RECENT EPOCH CREDITS").green().bold());
+        println!("{credits_table}");
+    })
 }
@@ -1,11 +1,21 @@
 use {
     crate::{
-        constants::{DEFAULT_KEYPAIR_PATH, DEVNET_RPC, SCILLA_CONFIG_RELATIVE_PATH},
+        constants::{
+            DEFAULT_KEYPAIR_PATH, DEFAULT_PROFILE_NAME, DEVNET_RPC, SCILLA_COMMITMENT_ENV,
+            SCILLA_CONFIG_RELATIVE_PATH, SCILLA_KEYPAIR_PATH_ENV, SCILLA_RPC_URL_ENV,
+        },
         error::ScillaError,
+        notice,
     },
     serde::{Deserialize, Serialize},
     solana_commitment_config::CommitmentLevel,
-    std::{env::home_dir, fs, path::PathBuf},
+    std::{
+        collections::BTreeMap,
+        env::{self, home_dir},
+        fs,
+        path::{Path, PathBuf},
+        str::FromStr,
+    },
 };
 
 pub fn scilla_config_path() -> PathBuf {
@@ -14,6 +24,18 @@ pub fn scilla_config_path() -> PathBuf {
     path
 }
 
+/// Copies the config at `path` next to it with a `.bak` suffix, replacing an
+/// older backup, and returns where it went. The file isn't parsed, so a broken
+/// config is kept as is.
+pub fn backup_config(path: &Path) -> Result<PathBuf, ScillaError> {
+    let mut backup = path.as_os_str().to_owned();
+    backup.push(".bak");
+    let backup = PathBuf::from(backup);
+
+    fs::copy(path, &backup)?;
+    Ok(backup)
+}
+
 pub fn expand_tilde(path: &str) -> PathBuf {
     // On TOMLs, ~ is not expanded, so do it manually
 
@@ -33,6 +55,69 @@ where
     Ok(expand_tilde(&s))
 }
 
+/// Block explorer used for transaction links
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Explorer {
+    #[default]
+    SolanaExplorer,
+    Solscan,
+    SolanaFm,
+}
+
+impl std::fmt::Display for Explorer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Explorer::SolanaExplorer => write!(f, "Solana Explorer"),
+            Explorer::Solscan => write!(f, "Solscan"),
+            Explorer::SolanaFm => write!(f, "SolanaFM"),
+        }
+    }
+}
+
+/// Time zone block times are shown in. UTC by default so output reads the
+/// same on every machine.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum TimestampZone {
+    #[default]
+    Utc,
+    Local,
+}
+
+impl std::fmt::Display for TimestampZone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TimestampZone::Utc => write!(f, "UTC"),
+            TimestampZone::Local => write!(f, "Local time"),
+        }
+    }
+}
+
+/// Connection settings that can be switched between as a unit
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Profile {
+    pub rpc_url: String,
+    pub commitment_level: CommitmentLevel,
+    #[serde(deserialize_with = "deserialize_path_with_tilde")]
+    pub keypair_path: PathBuf,
+}
+
+/// Derives the signing keypair from a BIP39 seed phrase instead of reading
+/// `keypair-path`. The phrase itself is never stored and is asked for at
+/// startup.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub struct SeedPhraseConfig {
+    pub derivation_path: String,
+    /// Whether to also ask for the optional BIP39 passphrase
+    #[serde(default)]
+    pub passphrase: bool,
+}
+
+/// The top-level `rpc-url`, `commitment-level` and `keypair-path` always hold
+/// the active profile, which also keeps single-profile configs readable.
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "kebab-case")]
 pub struct ScillaConfig {
@@ -40,6 +125,26 @@ pub struct ScillaConfig {
     pub commitment_level: CommitmentLevel,
     #[serde(deserialize_with = "deserialize_path_with_tilde")]
     pub keypair_path: PathBuf,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority_fee_micro_lamports: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compute_unit_limit: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_retries: Option<u32>,
+    /// Wait for confirmations over a `signatureSubscribe` WebSocket rather
+    /// than polling the RPC
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub websocket_confirmation: Option<bool>,
+    #[serde(default)]
+    pub explorer: Explorer,
+    #[serde(default)]
+    pub timestamps: TimestampZone,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed_phrase: Option<SeedPhraseConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_profile: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Profile>,
 }
 
 impl Default for ScillaConfig {
@@ -52,22 +157,56 @@ impl Default for ScillaConfig {
             rpc_url: DEVNET_RPC.to_string(),
             commitment_level: CommitmentLevel::Confirmed,
             keypair_path: default_keypair_path,
+            priority_fee_micro_lamports: None,
+            compute_unit_limit: None,
+            tx_retries: None,
+            websocket_confirmation: None,
+            explorer: Explorer::default(),
+            timestamps: TimestampZone::default(),
+            seed_phrase: None,
+            active_profile: None,
+            profiles: BTreeMap::new(),
         }
     }
 }
 
 impl ScillaConfig {
+    /// Loads the effective config. Precedence is environment variables, then
+    /// the config file, then built-in defaults. With no config file but an
+    /// environment override set, the defaults are used instead of prompting to
+    /// generate one.
     pub async fn load() -> Result<ScillaConfig, ScillaError> {
+        let has_env_overrides = [
+            SCILLA_RPC_URL_ENV,
+            SCILLA_KEYPAIR_PATH_ENV,
+            SCILLA_COMMITMENT_ENV,
+        ]
+        .iter()
+        .any(|key| env::var_os(key).is_some());
+
+        let mut config = if has_env_overrides && !scilla_config_path().exists() {
+            ScillaConfig::default()
+        } else {
+            Self::load_stored().await?
+        };
+
+        config.apply_overrides(|key| env::var(key).ok())?;
+        Ok(config)
+    }
+
+    /// Loads the config file as written, without environment overrides, so it
+    /// can be edited and saved back. Walks through generating one if missing.
+    pub async fn load_stored() -> Result<ScillaConfig, ScillaError> {
         let scilla_config_path = scilla_config_path();
 
         if !scilla_config_path.exists() {
             use console::style;
 
-            println!(
+            notice!(
                 "\n{}",
                 style("No configuration file found!").yellow().bold()
             );
-            println!(
+            notice!(
                 "{}",
                 style(format!(
                     "Creating config at: {}",
@@ -75,14 +214,14 @@ impl ScillaConfig {
                 ))
                 .cyan()
             );
-            println!(
+            notice!(
                 "{}",
                 style("Let's set up your configuration to get started.\n").cyan()
             );
 
             crate::commands::config::generate_config().await?;
 
-            println!(
+            notice!(
                 "\n{}",
                 style("Configuration complete! Starting Scilla...\n")
                     .green()
@@ -90,10 +229,8 @@ impl ScillaConfig {
             );
         }
 
-        println!("Using Scilla config path : {scilla_config_path:?}");
-        let data = fs::read_to_string(scilla_config_path)?;
-        let config: ScillaConfig = toml::from_str(&data)?;
-        Ok(config)
+        notice!("Using Scilla config path : {scilla_config_path:?}");
+        Self::load_from_path(&scilla_config_path)
     }
 
     pub fn load_from_path(path: &std::path::Path) -> Result<ScillaConfig, ScillaError> {
@@ -101,9 +238,108 @@ impl ScillaConfig {
             return Err(ScillaError::ConfigPathDoesNotExist);
         }
         let data = fs::read_to_string(path)?;
-        let config: ScillaConfig = toml::from_str(&data)?;
+        let mut config: ScillaConfig = toml::from_str(&data)?;
+        config.apply_active_profile()?;
         Ok(config)
     }
+
+    /// Writes the config to the default path, recording the current
+    /// connection settings under the active profile
+    pub fn save(&mut self) -> Result<(), ScillaError> {
+        self.store_active_profile();
+
+        let config_path = scilla_config_path();
+        if let Some(parent) = config_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let toml_string = toml::to_string_pretty(self).map_err(anyhow::Error::from)?;
+        fs::write(&config_path, toml_string)?;
+        Ok(())
+    }
+
+    /// Overrides the connection settings with the values `lookup` returns for
+    /// the `SCILLA_*` environment variables
+    pub fn apply_overrides(
+        &mut self,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<(), ScillaError> {
+        if let Some(rpc_url) = lookup(SCILLA_RPC_URL_ENV) {
+            self.rpc_url = rpc_url;
+        }
+
+        if let Some(keypair_path) = lookup(SCILLA_KEYPAIR_PATH_ENV) {
+            self.keypair_path = expand_tilde(&keypair_path);
+        }
+
+        if let Some(commitment) = lookup(SCILLA_COMMITMENT_ENV) {
+            self.commitment_level = CommitmentLevel::from_str(commitment.trim())
+                .map_err(|_| ScillaError::InvalidCommitment(SCILLA_COMMITMENT_ENV, commitment))?;
+        }
+
+        Ok(())
+    }
+
+    pub fn active_profile_name(&self) -> &str {
+        self.active_profile
+            .as_deref()
+            .unwrap_or(DEFAULT_PROFILE_NAME)
+    }
+
+    pub fn current_profile(&self) -> Profile {
+        Profile {
+            rpc_url: self.rpc_url.clone(),
+            commitment_level: self.commitment_level,
+            keypair_path: self.keypair_path.clone(),
+        }
+    }
+
+    /// Makes `name` the active profile and loads its connection settings
+    pub fn switch_profile(&mut self, name: &str) -> Result<(), ScillaError> {
+        if !self.profiles.contains_key(name) {
+            return Err(ScillaError::UnknownProfile(name.to_string()));
+        }
+
+        self.store_active_profile();
+        self.active_profile = Some(name.to_string());
+        self.apply_active_profile()
+    }
+
+    /// Copies the active profile into the top-level settings. Configs written
+    /// before profiles existed become a single `default` profile.
+    fn apply_active_profile(&mut self) -> Result<(), ScillaError> {
+        if self.profiles.is_empty() {
+            self.store_active_profile();
+            return Ok(());
+        }
+
+        let name = self.active_profile_name().to_string();
+        let profile = self
+            .profiles
+            .get(&name)
+            .ok_or(ScillaError::UnknownProfile(name.clone()))?
+            .clone();
+
+        // Saving keeps the top level equal to the active profile. Matching
+        // another profile means only `active-profile` was edited by hand,
+        // matching none means the top-level keys were, and those win.
+        let top_level = self.current_profile();
+        if top_level != profile && !self.profiles.values().any(|other| *other == top_level) {
+            self.profiles.insert(name, top_level);
+            return Ok(());
+        }
+
+        self.rpc_url = profile.rpc_url;
+        self.commitment_level = profile.commitment_level;
+        self.keypair_path = profile.keypair_path;
+        Ok(())
+    }
+
+    fn store_active_profile(&mut self) {
+        let name = self.active_profile_name().to_string();
+        self.profiles.insert(name.clone(), self.current_profile());
+        self.active_profile = Some(name);
+    }
 }
 
 #[cfg(test)]
@@ -127,6 +363,22 @@ mod tests {
         assert!(matches!(result, Err(ScillaError::TomlParseError(_))));
     }
 
+    #[test]
+    fn test_backup_config_keeps_unparseable_file() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let config_path = temp_dir.path().join("scilla.toml");
+        fs::write(&config_path, "rpc-url = ").expect("Failed to write file");
+
+        let backup = backup_config(&config_path).expect("Backup should succeed");
+
+        assert_eq!(backup, temp_dir.path().join("scilla.toml.bak"));
+        assert_eq!(
+            fs::read_to_string(&backup).expect("Backup should exist"),
+            "rpc-url = "
+        );
+        assert!(config_path.exists());
+    }
+
     #[test]
     fn test_load_from_path_valid_config_with_tilde_expansion() {
         let home = env::home_dir().expect("HOME should be set");
@@ -151,4 +403,277 @@ commitment-level = "confirmed"
         assert_eq!(config.commitment_level, CommitmentLevel::Confirmed);
         assert_eq!(config.keypair_path, home.join("my/key.json"));
     }
+
+    #[test]
+    fn test_load_from_path_with_seed_phrase() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let config_path = temp_dir.path().join("config.toml");
+
+        fs::write(
+            &config_path,
+            r#"
+rpc-url = "https://api.mainnet-beta.solana.com"
+keypair-path = "/tmp/key.json"
+commitment-level = "confirmed"
+
+[seed-phrase]
+derivation-path = "m/44'/501'/1'/0'"
+"#,
+        )
+        .expect("Failed to write file");
+
+        let config = ScillaConfig::load_from_path(&config_path)
+            .expect("Config with seed phrase should load successfully");
+
+        assert_eq!(
+            config.seed_phrase,
+            Some(SeedPhraseConfig {
+                derivation_path: "m/44'/501'/1'/0'".to_string(),
+                passphrase: false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_load_from_path_with_priority_fee() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let config_path = temp_dir.path().join("config.toml");
+
+        fs::write(
+            &config_path,
+            r#"
+rpc-url = "https://api.mainnet-beta.solana.com"
+keypair-path = "/tmp/key.json"
+commitment-level = "confirmed"
+priority-fee-micro-lamports = 10000
+compute-unit-limit = 200000
+"#,
+        )
+        .expect("Failed to write file");
+
+        let config = ScillaConfig::load_from_path(&config_path)
+            .expect("Valid config should load successfully");
+
+        assert_eq!(config.priority_fee_micro_lamports, Some(10_000));
+        assert_eq!(config.compute_unit_limit, Some(200_000));
+        assert_eq!(config.explorer, Explorer::SolanaExplorer);
+        assert_eq!(config.timestamps, TimestampZone::Utc);
+    }
+
+    #[test]
+    fn test_load_from_path_without_priority_fee() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let config_path = temp_dir.path().join("config.toml");
+
+        fs::write(
+            &config_path,
+            r#"
+rpc-url = "https://api.mainnet-beta.solana.com"
+keypair-path = "/tmp/key.json"
+commitment-level = "confirmed"
+"#,
+        )
+        .expect("Failed to write file");
+
+        let config = ScillaConfig::load_from_path(&config_path)
+            .expect("Valid config should load successfully");
+
+        assert_eq!(config.priority_fee_micro_lamports, None);
+        assert_eq!(config.compute_unit_limit, None);
+    }
+
+    #[test]
+    fn test_load_from_path_migrates_single_profile_config() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let config_path = temp_dir.path().join("config.toml");
+
+        fs::write(
+            &config_path,
+            r#"
+rpc-url = "https://api.devnet.solana.com"
+keypair-path = "/tmp/key.json"
+commitment-level = "confirmed"
+"#,
+        )
+        .expect("Failed to write file");
+
+        let config = ScillaConfig::load_from_path(&config_path)
+            .expect("Valid config should load successfully");
+
+        assert_eq!(config.active_profile_name(), DEFAULT_PROFILE_NAME);
+        assert_eq!(
+            config.profiles.get(DEFAULT_PROFILE_NAME),
+            Some(&config.current_profile())
+        );
+    }
+
+    #[test]
+    fn test_load_from_path_uses_active_profile() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let config_path = temp_dir.path().join("config.toml");
+
+        fs::write(
+            &config_path,
+            r#"
+rpc-url = "https://api.devnet.solana.com"
+keypair-path = "/tmp/key.json"
+commitment-level = "confirmed"
+active-profile = "mainnet"
+
+[profiles.devnet]
+rpc-url = "https://api.devnet.solana.com"
+keypair-path = "/tmp/key.json"
+commitment-level = "confirmed"
+
+[profiles.mainnet]
+rpc-url = "https://api.mainnet-beta.solana.com"
+keypair-path = "/tmp/validator.json"
+commitment-level = "finalized"
+"#,
+        )
+        .expect("Failed to write file");
+
+        let mut config = ScillaConfig::load_from_path(&config_path)
+            .expect("Valid config should load successfully");
+
+        assert_eq!(config.rpc_url, "https://api.mainnet-beta.solana.com");
+        assert_eq!(config.commitment_level, CommitmentLevel::Finalized);
+        assert_eq!(config.keypair_path, PathBuf::from("/tmp/validator.json"));
+
+        config
+            .switch_profile("devnet")
+            .expect("devnet profile exists");
+        assert_eq!(config.rpc_url, "https://api.devnet.solana.com");
+        assert_eq!(config.commitment_level, CommitmentLevel::Confirmed);
+
+        assert!(matches!(
+            config.switch_profile("testnet"),
+            Err(ScillaError::UnknownProfile(name)) if name == "testnet"
+        ));
+    }
+
+    #[test]
+    fn test_load_from_path_adopts_edited_top_level() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let config_path = temp_dir.path().join("config.toml");
+
+        fs::write(
+            &config_path,
+            r#"
+rpc-url = "http://localhost:8899"
+keypair-path = "/tmp/validator.json"
+commitment-level = "processed"
+active-profile = "mainnet"
+
+[profiles.devnet]
+rpc-url = "https://api.devnet.solana.com"
+keypair-path = "/tmp/key.json"
+commitment-level = "confirmed"
+
+[profiles.mainnet]
+rpc-url = "https://api.mainnet-beta.solana.com"
+keypair-path = "/tmp/validator.json"
+commitment-level = "finalized"
+"#,
+        )
+        .expect("Failed to write file");
+
+        let config = ScillaConfig::load_from_path(&config_path)
+            .expect("Valid config should load successfully");
+
+        assert_eq!(config.rpc_url, "http://localhost:8899");
+        assert_eq!(config.commitment_level, CommitmentLevel::Processed);
+        assert_eq!(config.profiles["mainnet"], config.current_profile());
+        assert_eq!(
+            config.profiles["devnet"].rpc_url,
+            "https://api.devnet.solana.com"
+        );
+    }
+
+    #[test]
+    fn test_apply_overrides_takes_precedence() {
+        let mut config = ScillaConfig::default();
+
+        config
+            .apply_overrides(|key| match key {
+                SCILLA_RPC_URL_ENV => Some("http://localhost:8899".to_string()),
+                SCILLA_KEYPAIR_PATH_ENV => Some("/tmp/ci.json".to_string()),
+                SCILLA_COMMITMENT_ENV => Some("finalized".to_string()),
+                _ => None,
+            })
+            .expect("Valid overrides should apply");
+
+        assert_eq!(config.rpc_url, "http://localhost:8899");
+        assert_eq!(config.keypair_path, PathBuf::from("/tmp/ci.json"));
+        assert_eq!(config.commitment_level, CommitmentLevel::Finalized);
+    }
+
+    #[test]
+    fn test_apply_overrides_keeps_unset_values() {
+        let mut config = ScillaConfig::default();
+        let default = ScillaConfig::default();
+
+        config
+            .apply_overrides(|_| None)
+            .expect("No overrides should apply");
+
+        assert_eq!(config.current_profile(), default.current_profile());
+    }
+
+    #[test]
+    fn test_apply_overrides_rejects_invalid_commitment() {
+        let mut config = ScillaConfig::default();
+
+        let result = config
+            .apply_overrides(|key| (key == SCILLA_COMMITMENT_ENV).then(|| "fast".to_string()));
+
+        assert!(matches!(
+            result,
+            Err(ScillaError::InvalidCommitment(SCILLA_COMMITMENT_ENV, value)) if value == "fast"
+        ));
+    }
+
+    #[test]
+    fn test_load_from_path_with_explorer() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let config_path = temp_dir.path().join("config.toml");
+
+        fs::write(
+            &config_path,
+            r#"
+rpc-url = "https://api.devnet.solana.com"
+keypair-path = "/tmp/key.json"
+commitment-level = "confirmed"
+explorer = "solscan"
+"#,
+        )
+        .expect("Failed to write file");
+
+        let config = ScillaConfig::load_from_path(&config_path)
+            .expect("Valid config should load successfully");
+
+        assert_eq!(config.explorer, Explorer::Solscan);
+    }
+
+    #[test]
+    fn test_load_from_path_with_local_timestamps() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let config_path = temp_dir.path().join("config.toml");
+
+        fs::write(
+            &config_path,
+            r#"
+rpc-url = "https://api.devnet.solana.com"
+keypair-path = "/tmp/key.json"
+commitment-level = "confirmed"
+timestamps = "local"
+"#,
+        )
+        .expect("Failed to write file");
+
+        let config = ScillaConfig::load_from_path(&config_path)
+            .expect("Valid config should load successfully");
+
+        assert_eq!(config.timestamps, TimestampZone::Local);
+    }
 }
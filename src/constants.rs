@@ -1,9 +1,35 @@
 pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
 
+pub const SOL_DECIMALS: usize = 9;
+
 pub const SCILLA_CONFIG_RELATIVE_PATH: &str = ".config/scilla.toml";
 
+/// Editor to open the config in when neither `$VISUAL` nor `$EDITOR` is set
+pub const DEFAULT_EDITOR: &str = "vi";
+
+pub const ADDRESS_BOOK_RELATIVE_PATH: &str = ".config/scilla-addresses.toml";
+
+pub const COMMAND_HISTORY_RELATIVE_PATH: &str = ".config/scilla-history.toml";
+
+/// Commands kept in the history, oldest dropped first
+pub const MAX_COMMAND_HISTORY: usize = 50;
+
 pub const DEFAULT_KEYPAIR_PATH: &str = ".config/solana/id.json";
 
+pub const DEFAULT_DERIVATION_PATH: &str = "m/44'/501'/0'/0'";
+
+pub const HARDWARE_WALLET_SCHEME: &str = "usb://";
+
+pub const SEED_PHRASE_WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+
+pub const DEFAULT_PROFILE_NAME: &str = "default";
+
+pub const SCILLA_RPC_URL_ENV: &str = "SCILLA_RPC_URL";
+
+pub const SCILLA_KEYPAIR_PATH_ENV: &str = "SCILLA_KEYPAIR_PATH";
+
+pub const SCILLA_COMMITMENT_ENV: &str = "SCILLA_COMMITMENT";
+
 pub const ACTIVE_STAKE_EPOCH_BOUND: u64 = u64::MAX;
 
 pub const DEVNET_RPC: &str = "https://api.devnet.solana.com";
@@ -12,8 +38,131 @@ pub const MAINNET_RPC: &str = "https://api.mainnet-beta.solana.com";
 
 pub const TESTNET_RPC: &str = "https://api.testnet.solana.com";
 
+pub const AIRDROP_FAUCET_LIMIT_SOL: f64 = 2.0;
+
+pub const DEFAULT_AIRDROP_ATTEMPTS: u32 = 5;
+
+/// First wait after a rate-limited airdrop, doubled on every further attempt
+pub const AIRDROP_RETRY_BASE_DELAY_SECS: u64 = 2;
+
+pub const SOLANA_FAUCET_URL: &str = "https://faucet.solana.com";
+
+pub const EXPLORER_TX_BASE_URL: &str = "https://explorer.solana.com/tx";
+
+pub const SOLSCAN_TX_BASE_URL: &str = "https://solscan.io/tx";
+
+pub const SOLANA_FM_TX_BASE_URL: &str = "https://solana.fm/tx";
+
+pub const RPC_PROBE_TIMEOUT_SECS: u64 = 5;
+
+/// Largest serialized transaction the cluster accepts, in bytes
+pub const MAX_TRANSACTION_SIZE: usize = 1232;
+
+/// Bytes of account data shown in the terminal; the rest is only written to
+/// a file
+pub const HEXDUMP_PREVIEW_BYTES: usize = 512;
+
+/// Program write transactions in flight at once while deploying
+pub const PROGRAM_WRITE_CONCURRENCY: usize = 8;
+
+/// Data sizes of SPL token program accounts, in bytes
+pub const TOKEN_ACCOUNT_SIZE: usize = 165;
+pub const TOKEN_MINT_SIZE: usize = 82;
+
+pub const DEFAULT_TX_RETRIES: u32 = 3;
+
+pub const TX_RETRY_BASE_DELAY_MS: u64 = 500;
+
+pub const DEFAULT_CONFIRMATION_TIMEOUT_SECS: u64 = 60;
+
+/// Pause before resubscribing after a WebSocket connection drops
+pub const WS_RECONNECT_DELAY_SECS: u64 = 3;
+
+/// How long to wait for a `signatureSubscribe` notification before falling
+/// back to polling
+pub const WS_CONFIRMATION_TIMEOUT_SECS: u64 = 30;
+
 pub const DEFAULT_EPOCH_LIMIT: usize = 10;
 
+pub const DEFAULT_HISTORY_PAGE_SIZE: usize = 20;
+
+pub const DEFAULT_REWARD_EPOCHS: u64 = 5;
+
+/// Most signatures `getSignaturesForAddress` returns per request
+pub const MAX_SIGNATURES_PER_REQUEST: usize = 1000;
+
+pub const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+
+/// Addresses appended to a lookup table per transaction, well within the
+/// transaction size limit at 32 bytes each
+pub const LOOKUP_TABLE_EXTEND_CHUNK_SIZE: usize = 20;
+
+/// Slots a deactivated lookup table waits out before it can be closed, until
+/// its deactivation slot has left the 512-entry SlotHashes sysvar
+pub const LOOKUP_TABLE_COOLDOWN_SLOTS: u64 = 513;
+
+/// Token accounts closed per transaction. Each close adds one writable
+/// account to the message, so this stays well under the size limit.
+pub const CLOSE_TOKEN_ACCOUNTS_PER_TX: usize = 20;
+
+/// Stake accounts merged per transaction. Each merge is a separate stake
+/// program call, so this keeps a batch well inside the compute budget.
+pub const MERGE_STAKE_ACCOUNTS_PER_TX: usize = 10;
+
+pub const DEFAULT_MS_PER_SLOT: u64 = 400;
+
+pub const PERFORMANCE_SAMPLE_LIMIT: usize = 10;
+
+/// Performance samples shown by default, each covering about a minute
+pub const DEFAULT_TPS_SAMPLES: usize = 30;
+
+pub const EPOCH_PROGRESS_BAR_WIDTH: usize = 30;
+
+pub const DEFAULT_PING_COUNT: usize = 10;
+
+pub const DEFAULT_VALIDATOR_LIMIT: usize = 25;
+
+/// Highest commission, in percent, the validator finder accepts by default
+pub const DEFAULT_FINDER_MAX_COMMISSION: u8 = 5;
+
+// How long epoch info and blockhashes are reused within a session. A
+// blockhash stays valid for about a minute, so half that leaves time to land.
+pub const EPOCH_INFO_CACHE_TTL_SECS: u64 = 5;
+
+pub const BLOCKHASH_CACHE_TTL_SECS: u64 = 30;
+
+pub const RECENT_EPOCH_CREDITS_LIMIT: usize = 5;
+
+pub const HIGH_COMMISSION_THRESHOLD: u8 = 10;
+
+/// Skip rate, in percent, above which block production is flagged
+pub const HIGH_SKIP_RATE_PCT: f64 = 10.0;
+
+pub const VALIDATOR_SELECT_PAGE_SIZE: usize = 15;
+
+// The reduced warmup/cooldown rate is active on every public cluster, so treat
+// it as enabled from genesis when deriving stake activation client-side.
+pub const NEW_WARMUP_COOLDOWN_RATE_EPOCH: Option<u64> = Some(0);
+
+// Byte offsets of the authorities in a serialized `StakeStateV2`: a 4 byte
+// enum tag and the 8 byte rent exempt reserve come first
+pub const STAKE_AUTHORIZED_STAKER_OFFSET: usize = 12;
+
+pub const STAKE_AUTHORIZED_WITHDRAWER_OFFSET: usize = 44;
+
 pub const STAKE_HISTORY_SYSVAR_ADDR: &str = "SysvarStakeHistory1111111111111111111111111";
 
 pub const MEMO_PROGRAM_ID: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+
+/// Symbols shown next to well-known mints in token listings
+pub const KNOWN_TOKEN_MINTS: [(&str, &str); 6] = [
+    ("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v", "USDC"),
+    ("Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB", "USDT"),
+    ("So11111111111111111111111111111111111111112", "wSOL"),
+    ("DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263", "BONK"),
+    ("JUPyiwrYJFskUPiHa7hkeR8VUtAeFoSYbKedZNsDvCN", "JUP"),
+    (
+        "4zMMC9srt5Ri5X14GAgXhaHii3GnPAEERYPJgZJDncDU",
+        "USDC (devnet)",
+    ),
+];
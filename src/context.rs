@@ -1,20 +1,116 @@
 use {
-    crate::config::ScillaConfig,
-    solana_commitment_config::CommitmentConfig,
-    solana_keypair::{EncodableKey, Keypair, Signer},
+    crate::{
+        config::{Explorer, ScillaConfig, SeedPhraseConfig, TimestampZone},
+        constants::{
+            BLOCKHASH_CACHE_TTL_SECS, DEFAULT_TX_RETRIES, EPOCH_INFO_CACHE_TTL_SECS,
+            HARDWARE_WALLET_SCHEME,
+        },
+        misc::helpers::{keypair_from_seed_phrase, read_keypair_from_path, ws_url_from_rpc_url},
+        prompt::prompt_secret,
+    },
+    solana_commitment_config::{CommitmentConfig, CommitmentLevel},
+    solana_epoch_info::EpochInfo,
+    solana_hash::Hash,
+    solana_keypair::{Keypair, Signer},
     solana_pubkey::Pubkey,
     solana_rpc_client::nonblocking::rpc_client::RpcClient,
+    std::{
+        path::Path,
+        sync::{
+            Mutex,
+            atomic::{AtomicBool, Ordering},
+        },
+        time::{Duration, Instant},
+    },
 };
 
+/// How command results are printed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Styled text and tables
+    #[default]
+    Text,
+    /// A single JSON document per command, for scripting
+    Json,
+}
+
+/// A value read from the cluster, reused until it is older than its TTL or was
+/// read at a different commitment
+struct Cached<T> {
+    value: T,
+    commitment: CommitmentConfig,
+    fetched_at: Instant,
+}
+
+impl<T: Clone> Cached<T> {
+    fn fresh(entry: &Option<Self>, commitment: CommitmentConfig, ttl: Duration) -> Option<T> {
+        entry
+            .as_ref()
+            .filter(|entry| entry.commitment == commitment && entry.fetched_at.elapsed() < ttl)
+            .map(|entry| entry.value.clone())
+    }
+
+    fn new(value: T, commitment: CommitmentConfig) -> Option<Self> {
+        Some(Self {
+            value,
+            commitment,
+            fetched_at: Instant::now(),
+        })
+    }
+}
+
+/// Cluster state that barely changes between back-to-back commands, so slow
+/// or rate-limited RPCs aren't asked for it again
+#[derive(Default)]
+struct SessionCache {
+    epoch_info: Option<Cached<EpochInfo>>,
+    blockhash: Option<Cached<Hash>>,
+}
+
+/// A commitment picked from the config menu for the next command only
+static NEXT_COMMITMENT: Mutex<Option<CommitmentLevel>> = Mutex::new(None);
+
+pub fn set_next_commitment(commitment: Option<CommitmentLevel>) {
+    *NEXT_COMMITMENT.lock().unwrap() = commitment;
+}
+
+pub fn take_next_commitment() -> Option<CommitmentLevel> {
+    NEXT_COMMITMENT.lock().unwrap().take()
+}
+
 pub struct ScillaContext {
     rpc_client: RpcClient,
-    keypair: Keypair,
+    /// Commitment from the config file
+    config_commitment: CommitmentLevel,
+    /// Commitment the RPC client returns to after a one-off override, the
+    /// config's unless `--commitment` was passed
+    session_commitment: CommitmentLevel,
+    signer: Box<dyn Signer>,
     pubkey: Pubkey,
+    priority_fee_micro_lamports: Option<u64>,
+    compute_unit_limit: Option<u32>,
+    tx_retries: u32,
+    websocket_confirmation: bool,
+    explorer: Explorer,
+    timestamps: TimestampZone,
+    output_format: OutputFormat,
+    cache: Mutex<SessionCache>,
+    /// Set whenever the signer is handed out, so the session can tell which
+    /// commands signed something
+    signed: AtomicBool,
 }
 
 impl ScillaContext {
-    pub fn keypair(&self) -> &Keypair {
-        &self.keypair
+    /// The wallet that pays for and signs transactions: a keypair file, a seed
+    /// phrase or a hardware wallet
+    pub fn signer(&self) -> &dyn Signer {
+        self.signed.store(true, Ordering::Relaxed);
+        self.signer.as_ref()
+    }
+
+    /// Whether the signer was used since the last call
+    pub fn take_signed(&self) -> bool {
+        self.signed.swap(false, Ordering::Relaxed)
     }
 
     pub fn rpc(&self) -> &RpcClient {
@@ -24,6 +120,103 @@ impl ScillaContext {
     pub fn pubkey(&self) -> &Pubkey {
         &self.pubkey
     }
+
+    pub fn priority_fee_micro_lamports(&self) -> Option<u64> {
+        self.priority_fee_micro_lamports
+    }
+
+    pub fn compute_unit_limit(&self) -> Option<u32> {
+        self.compute_unit_limit
+    }
+
+    pub fn tx_retries(&self) -> u32 {
+        self.tx_retries
+    }
+
+    pub fn websocket_confirmation(&self) -> bool {
+        self.websocket_confirmation
+    }
+
+    pub fn explorer(&self) -> Explorer {
+        self.explorer
+    }
+
+    pub fn timestamps(&self) -> TimestampZone {
+        self.timestamps
+    }
+
+    pub fn output_format(&self) -> OutputFormat {
+        self.output_format
+    }
+
+    /// The commitment in use when it differs from the config's, so output can
+    /// say which one it was read at
+    pub fn commitment_override(&self) -> Option<CommitmentLevel> {
+        let commitment = self.rpc_client.commitment().commitment;
+        (commitment != self.config_commitment).then_some(commitment)
+    }
+
+    /// Points the RPC client at `commitment` until
+    /// [`Self::clear_commitment_override`]
+    pub fn set_commitment_override(&mut self, commitment: CommitmentLevel) {
+        if commitment != self.rpc_client.commitment().commitment {
+            self.rpc_client = RpcClient::new_with_commitment(
+                self.rpc_client.url(),
+                CommitmentConfig { commitment },
+            );
+        }
+    }
+
+    pub fn clear_commitment_override(&mut self) {
+        self.set_commitment_override(self.session_commitment);
+    }
+
+    /// The PubSub endpoint of the configured RPC
+    pub fn ws_url(&self) -> anyhow::Result<String> {
+        ws_url_from_rpc_url(&self.rpc_client.url())
+    }
+
+    /// Epoch info, reused for a few seconds
+    pub async fn epoch_info(&self) -> anyhow::Result<EpochInfo> {
+        let commitment = self.rpc_client.commitment();
+        let cached = Cached::fresh(
+            &self.cache.lock().unwrap().epoch_info,
+            commitment,
+            Duration::from_secs(EPOCH_INFO_CACHE_TTL_SECS),
+        );
+        if let Some(epoch_info) = cached {
+            return Ok(epoch_info);
+        }
+
+        let epoch_info = self.rpc_client.get_epoch_info().await?;
+        self.cache.lock().unwrap().epoch_info = Cached::new(epoch_info.clone(), commitment);
+
+        Ok(epoch_info)
+    }
+
+    /// A recent blockhash, reused while it still leaves plenty of time for a
+    /// transaction to land
+    pub async fn latest_blockhash(&self) -> anyhow::Result<Hash> {
+        let commitment = self.rpc_client.commitment();
+        let cached = Cached::fresh(
+            &self.cache.lock().unwrap().blockhash,
+            commitment,
+            Duration::from_secs(BLOCKHASH_CACHE_TTL_SECS),
+        );
+        if let Some(blockhash) = cached {
+            return Ok(blockhash);
+        }
+
+        let blockhash = self.rpc_client.get_latest_blockhash().await?;
+        self.cache.lock().unwrap().blockhash = Cached::new(blockhash, commitment);
+
+        Ok(blockhash)
+    }
+
+    /// Drops the cached blockhash, e.g. after the cluster reported it expired
+    pub fn invalidate_blockhash(&self) {
+        self.cache.lock().unwrap().blockhash = None;
+    }
 }
 
 impl ScillaContext {
@@ -34,23 +227,111 @@ impl ScillaContext {
                 commitment: config.commitment_level,
             },
         );
+        let config_commitment = config.commitment_level;
 
-        use anyhow::anyhow;
-
-        let keypair = Keypair::read_from_file(&config.keypair_path).map_err(|e| {
-            anyhow!(
-                "Failed to read keypair from {}: {}",
-                config.keypair_path.display(),
-                e
-            )
-        })?;
+        let signer: Box<dyn Signer> = match &config.seed_phrase {
+            Some(seed_phrase) => Box::new(prompt_seed_phrase_keypair(seed_phrase)?),
+            None if is_hardware_wallet_locator(&config.keypair_path) => {
+                hardware_wallet_signer(&config.keypair_path.to_string_lossy())?
+            }
+            None => Box::new(read_keypair_from_path(&config.keypair_path)?),
+        };
 
-        let pubkey = keypair.pubkey();
+        let pubkey = signer.try_pubkey()?;
 
         Ok(Self {
             rpc_client,
-            keypair,
+            config_commitment,
+            session_commitment: config_commitment,
+            signer,
             pubkey,
+            priority_fee_micro_lamports: config.priority_fee_micro_lamports,
+            compute_unit_limit: config.compute_unit_limit,
+            tx_retries: config.tx_retries.unwrap_or(DEFAULT_TX_RETRIES),
+            websocket_confirmation: config.websocket_confirmation.unwrap_or(false),
+            explorer: config.explorer,
+            timestamps: config.timestamps,
+            output_format: OutputFormat::default(),
+            cache: Mutex::default(),
+            signed: AtomicBool::default(),
         })
     }
+
+    pub fn with_output_format(mut self, output_format: OutputFormat) -> Self {
+        self.output_format = output_format;
+        self
+    }
+
+    /// Uses `commitment` for every command of this session, from `--commitment`
+    pub fn with_commitment(mut self, commitment: Option<CommitmentLevel>) -> Self {
+        if let Some(commitment) = commitment {
+            self.session_commitment = commitment;
+            self.set_commitment_override(commitment);
+        }
+        self
+    }
+}
+
+/// Whether a keypair path is a hardware wallet locator such as
+/// `usb://ledger` or `usb://ledger?key=0/0`
+pub fn is_hardware_wallet_locator(path: &Path) -> bool {
+    path.to_str()
+        .is_some_and(|path| path.starts_with(HARDWARE_WALLET_SCHEME))
+}
+
+/// Connects to the Ledger named by `locator`. The device asks for approval of
+/// every transaction it signs.
+#[cfg(feature = "ledger")]
+fn hardware_wallet_signer(locator: &str) -> anyhow::Result<Box<dyn Signer>> {
+    use {
+        anyhow::anyhow,
+        solana_derivation_path::DerivationPath,
+        solana_remote_wallet::{
+            locator::Locator, remote_keypair::generate_remote_keypair,
+            remote_wallet::maybe_wallet_manager,
+        },
+    };
+
+    let (device, derivation_path) = match locator.split_once("?key=") {
+        Some((device, key)) => (
+            device,
+            DerivationPath::from_key_str(key)
+                .map_err(|e| anyhow!("Invalid key `{key}` in {locator}: {e}"))?,
+        ),
+        None => (locator, DerivationPath::default()),
+    };
+
+    let wallet_manager = maybe_wallet_manager()
+        .map_err(|e| anyhow!("Failed to open USB devices: {e}"))?
+        .ok_or_else(|| {
+            anyhow!("No hardware wallet found. Connect and unlock it, then open the Solana app")
+        })?;
+
+    let locator =
+        Locator::new_from_path(device).map_err(|e| anyhow!("Invalid locator {locator}: {e}"))?;
+    let keypair =
+        generate_remote_keypair(locator, derivation_path, &wallet_manager, false, "keypair")
+            .map_err(|e| anyhow!("Failed to connect to {device}: {e}"))?;
+
+    Ok(Box::new(keypair))
+}
+
+#[cfg(not(feature = "ledger"))]
+fn hardware_wallet_signer(locator: &str) -> anyhow::Result<Box<dyn Signer>> {
+    anyhow::bail!(
+        "{locator} needs hardware wallet support. Reinstall with `cargo install --path . \
+         --features ledger`"
+    )
+}
+
+fn prompt_seed_phrase_keypair(seed_phrase: &SeedPhraseConfig) -> anyhow::Result<Keypair> {
+    let phrase = prompt_secret("Enter seed phrase:")?;
+
+    let passphrase = if seed_phrase.passphrase {
+        prompt_secret("Enter BIP39 passphrase:")?
+    } else {
+        String::new()
+    };
+
+    keypair_from_seed_phrase(&phrase, &passphrase, &seed_phrase.derivation_path)
 }
@@ -1,4 +1,13 @@
-use {crate::commands::CommandExec, thiserror::Error};
+use {
+    crate::commands::CommandExec,
+    solana_instruction::error::InstructionError,
+    solana_pubkey::Pubkey,
+    solana_rpc_client_api::{
+        client_error::{Error as ClientError, ErrorKind as ClientErrorKind, TransactionError},
+        request::RpcError,
+    },
+    thiserror::Error,
+};
 
 pub type ScillaResult<T> = anyhow::Result<CommandExec<T>>;
 
@@ -8,8 +17,174 @@ pub enum ScillaError {
     ConfigPathDoesNotExist,
     #[error("Io error")]
     IoError(#[from] std::io::Error),
+    #[error("Profile `{0}` is not defined in the Scilla config")]
+    UnknownProfile(String),
+    #[error("Invalid {0} value `{1}`, expected processed, confirmed or finalized")]
+    InvalidCommitment(&'static str, String),
     #[error("Toml Parse error")]
     TomlParseError(#[from] toml::de::Error),
-    #[error("Anyhow err")]
-    Anyhow(#[from] anyhow::Error),
+    #[error("Could not reach the RPC endpoint. Check the RPC URL and your connection")]
+    RpcUnreachable,
+    #[error("Insufficient funds. The paying account can't cover this transaction and its fees")]
+    InsufficientFunds,
+    #[error("Account {0} not found. Check the address and that you're on the right cluster")]
+    AccountNotFound(String),
+    #[error("Missing a required signature. Your keypair is not the authority for this account")]
+    NotAuthorized,
+    #[error("The transaction expired before it landed. Please try again")]
+    BlockhashExpired,
+    #[error(transparent)]
+    Anyhow(anyhow::Error),
+}
+
+/// Why an entered address was rejected, from the cheapest check to the one
+/// that needs the RPC
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum InvalidPubkey {
+    #[error("`{0}` is not a valid base58 address or address book name")]
+    Malformed(String),
+    #[error(
+        "{0} is off the ed25519 curve, likely a program derived address. No keypair can sign for \
+         it"
+    )]
+    OffCurve(Pubkey),
+    #[error("Account {0} not found. Check the address and that you're on the right cluster")]
+    NotFound(Pubkey),
+}
+
+/// Swaps the client and transaction errors anywhere in the chain for a short
+/// message, keeping everything else as is
+impl From<anyhow::Error> for ScillaError {
+    fn from(err: anyhow::Error) -> Self {
+        let friendly = err.chain().find_map(friendly_cause);
+
+        friendly.unwrap_or(ScillaError::Anyhow(err))
+    }
+}
+
+/// Like the conversion to [`ScillaError`], but keeps the contexts added above
+/// the swapped cause, e.g. which buffer still holds a failed deploy's rent
+pub fn with_friendly_cause(err: anyhow::Error) -> anyhow::Error {
+    let causes: Vec<_> = err.chain().collect();
+    let Some((index, friendly)) = causes
+        .iter()
+        .enumerate()
+        .find_map(|(index, cause)| Some((index, friendly_cause(*cause)?)))
+    else {
+        return err;
+    };
+
+    causes[..index]
+        .iter()
+        .rev()
+        .fold(anyhow::Error::new(friendly), |err, context| {
+            err.context(context.to_string())
+        })
+}
+
+fn friendly_cause(cause: &(dyn std::error::Error + 'static)) -> Option<ScillaError> {
+    if let Some(tx_err) = cause.downcast_ref::<TransactionError>() {
+        return from_transaction_error(tx_err);
+    }
+    from_client_error(cause.downcast_ref::<ClientError>()?)
+}
+
+fn from_client_error(err: &ClientError) -> Option<ScillaError> {
+    if let Some(tx_err) = err.get_transaction_error() {
+        return from_transaction_error(&tx_err);
+    }
+
+    match err.kind() {
+        ClientErrorKind::Reqwest(err) if err.is_connect() || err.is_timeout() => {
+            Some(ScillaError::RpcUnreachable)
+        }
+        ClientErrorKind::RpcError(RpcError::ForUser(message)) => {
+            let pubkey = message.strip_prefix("AccountNotFound: pubkey=")?;
+            let pubkey = pubkey.split(':').next().unwrap_or(pubkey);
+            Some(ScillaError::AccountNotFound(pubkey.to_string()))
+        }
+        _ => None,
+    }
+}
+
+fn from_transaction_error(err: &TransactionError) -> Option<ScillaError> {
+    match err {
+        // A fee payer that was never funded shows up as a missing account
+        TransactionError::InsufficientFundsForFee
+        | TransactionError::InsufficientFundsForRent { .. }
+        | TransactionError::AccountNotFound
+        | TransactionError::InstructionError(_, InstructionError::InsufficientFunds) => {
+            Some(ScillaError::InsufficientFunds)
+        }
+        TransactionError::InstructionError(_, InstructionError::MissingRequiredSignature) => {
+            Some(ScillaError::NotAuthorized)
+        }
+        TransactionError::BlockhashNotFound => Some(ScillaError::BlockhashExpired),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, anyhow::Context};
+
+    #[test]
+    fn test_maps_transaction_errors_in_chain() {
+        let err = anyhow::Error::new(TransactionError::InsufficientFundsForFee)
+            .context("Transaction simulation failed");
+        assert!(matches!(
+            ScillaError::from(err),
+            ScillaError::InsufficientFunds
+        ));
+
+        let err = anyhow::Error::new(TransactionError::InstructionError(
+            0,
+            InstructionError::MissingRequiredSignature,
+        ));
+        assert!(matches!(ScillaError::from(err), ScillaError::NotAuthorized));
+    }
+
+    #[test]
+    fn test_keeps_contexts_above_friendly_cause() {
+        let err = anyhow::Error::new(TransactionError::InsufficientFundsForFee)
+            .context("Transaction simulation failed")
+            .context("Buffer holds 1 SOL of rent");
+
+        assert_eq!(
+            format!("{:#}", with_friendly_cause(err)),
+            "Buffer holds 1 SOL of rent: Transaction simulation failed: Insufficient funds. The \
+             paying account can't cover this transaction and its fees"
+        );
+
+        let err = anyhow::anyhow!("Invalid amount").context("Failed to parse CSV");
+        assert_eq!(
+            format!("{:#}", with_friendly_cause(err)),
+            "Failed to parse CSV: Invalid amount"
+        );
+    }
+
+    #[test]
+    fn test_maps_account_not_found() {
+        let client_err: ClientError = RpcError::ForUser(
+            "AccountNotFound: pubkey=Vote111111111111111111111111111111111111111".into(),
+        )
+        .into();
+        let err = Err::<(), _>(client_err)
+            .context("Failed to fetch vote account")
+            .unwrap_err();
+
+        assert!(matches!(
+            ScillaError::from(err),
+            ScillaError::AccountNotFound(pubkey) if pubkey == "Vote111111111111111111111111111111111111111"
+        ));
+    }
+
+    #[test]
+    fn test_keeps_other_errors() {
+        let err = anyhow::anyhow!("Stake account is uninitialized");
+        let mapped = ScillaError::from(err);
+
+        assert!(matches!(mapped, ScillaError::Anyhow(_)));
+        assert_eq!(mapped.to_string(), "Stake account is uninitialized");
+    }
 }
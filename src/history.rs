@@ -0,0 +1,179 @@
+use {
+    crate::{
+        cli::Cli,
+        commands::Command,
+        constants::{COMMAND_HISTORY_RELATIVE_PATH, MAX_COMMAND_HISTORY},
+        misc::helpers::truncate_middle,
+    },
+    anyhow::Context,
+    clap::Parser,
+    serde::{Deserialize, Serialize},
+    std::{
+        env::home_dir,
+        fmt, fs, iter,
+        path::{Path, PathBuf},
+    },
+};
+
+pub fn command_history_path() -> PathBuf {
+    let mut path = home_dir().expect("Error getting home path");
+    path.push(COMMAND_HISTORY_RELATIVE_PATH);
+    path
+}
+
+/// A command run from the interactive session, with the answers to its
+/// prompts when they are safe to replay
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Group and command name as taken on the command line, e.g. `stake show`
+    pub command: Vec<String>,
+    /// Left empty for commands that signed something, so replaying one asks
+    /// for every input again
+    #[serde(default)]
+    pub answers: Vec<String>,
+}
+
+impl HistoryEntry {
+    /// The command to run and the answers to feed it, `None` when the entry
+    /// no longer names a command
+    pub fn to_command(&self) -> Option<(Command, Vec<String>)> {
+        let words = iter::once("scilla").chain(self.command.iter().map(String::as_str));
+        let (command, _) = Cli::try_parse_from(words).ok()?.command?.into_parts();
+        Some((command, self.answers.clone()))
+    }
+}
+
+impl fmt::Display for HistoryEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.command.join(" "))?;
+        for answer in &self.answers {
+            match answer.is_empty() {
+                true => write!(f, " -")?,
+                false => write!(f, " {}", truncate_middle(answer, 6))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Recently run commands, most recent first
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CommandHistory {
+    #[serde(default)]
+    entries: Vec<HistoryEntry>,
+}
+
+impl CommandHistory {
+    /// Loads the history, or an empty one if nothing was run yet
+    pub fn load() -> anyhow::Result<Self> {
+        Self::load_from_path(&command_history_path())
+    }
+
+    pub fn load_from_path(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let data = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read command history {}", path.display()))?;
+        toml::from_str(&data)
+            .with_context(|| format!("Failed to parse command history {}", path.display()))
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        self.save_to_path(&command_history_path())
+    }
+
+    pub fn save_to_path(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, toml::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Puts `entry` first, dropping an identical earlier run and the oldest
+    /// entries past [`MAX_COMMAND_HISTORY`]
+    pub fn record(&mut self, entry: HistoryEntry) {
+        self.entries.retain(|existing| *existing != entry);
+        self.entries.insert(0, entry);
+        self.entries.truncate(MAX_COMMAND_HISTORY);
+    }
+
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::commands::{Command, stake::StakeCommand},
+        tempfile::TempDir,
+    };
+
+    fn entry(command: &str, answers: &[&str]) -> HistoryEntry {
+        HistoryEntry {
+            command: command.split(' ').map(str::to_string).collect(),
+            answers: answers.iter().map(|answer| answer.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_command_history_round_trip() -> anyhow::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let path = temp_dir.path().join("history.toml");
+
+        let mut history = CommandHistory::load_from_path(&path)?;
+        assert!(history.is_empty());
+
+        history.record(entry("stake show", &["stake-account"]));
+        history.record(entry("account balance", &[]));
+        history.record(entry("stake show", &["stake-account"]));
+        history.save_to_path(&path)?;
+
+        let history = CommandHistory::load_from_path(&path)?;
+        assert_eq!(
+            history.entries(),
+            [
+                entry("stake show", &["stake-account"]),
+                entry("account balance", &[]),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_command_history_keeps_most_recent() {
+        let mut history = CommandHistory::default();
+        for epoch in 0..MAX_COMMAND_HISTORY + 5 {
+            history.record(entry(
+                "stake rewards",
+                &["stake-account", &epoch.to_string()],
+            ));
+        }
+
+        assert_eq!(history.entries().len(), MAX_COMMAND_HISTORY);
+        assert_eq!(
+            history.entries()[0].answers[1],
+            (MAX_COMMAND_HISTORY + 4).to_string()
+        );
+    }
+
+    #[test]
+    fn test_history_entry_to_command() {
+        let (command, answers) = entry("stake show", &["stake-account"])
+            .to_command()
+            .unwrap();
+        assert!(matches!(command, Command::Stake(StakeCommand::Show)));
+        assert_eq!(answers, ["stake-account"]);
+
+        assert!(entry("stake no-such-command", &[]).to_command().is_none());
+    }
+}
@@ -1,23 +1,66 @@
 use {
     crate::{
-        commands::CommandExec, config::ScillaConfig, context::ScillaContext, error::ScillaResult,
-        prompt::prompt_for_command,
+        cli::Cli,
+        commands::{Command, CommandExec, account::AccountCommand, config::ConfigCommand},
+        config::ScillaConfig,
+        context::{OutputFormat, ScillaContext, set_next_commitment, take_next_commitment},
+        error::{ScillaResult, with_friendly_cause},
+        history::{CommandHistory, HistoryEntry},
+        prompt::{
+            clear_preset_answers, is_interrupted, prompt_for_command, set_assume_yes,
+            set_preset_answers, take_answer_log,
+        },
+        ui::{abandon_spinner, disable_colors, print_error, set_json_output},
     },
+    anyhow::anyhow,
+    clap::Parser,
     console::style,
+    solana_commitment_config::CommitmentLevel,
 };
 
+pub mod address_book;
+pub mod cli;
 pub mod commands;
 pub mod config;
 pub mod constants;
 pub mod context;
 pub mod error;
+pub mod history;
 pub mod misc;
 pub mod prompt;
 pub mod ui;
 
 #[tokio::main(flavor = "multi_thread")]
 async fn main() -> ScillaResult<()> {
-    println!(
+    let cli = Cli::parse();
+    set_assume_yes(cli.yes);
+    set_json_output(cli.json);
+    if cli.no_color {
+        disable_colors();
+    }
+
+    let output_format = match cli.json {
+        true => OutputFormat::Json,
+        false => OutputFormat::Text,
+    };
+
+    if let Some(cli_command) = cli.command {
+        let config = ScillaConfig::load().await?;
+        let ctx = ScillaContext::from_config(config)?
+            .with_output_format(output_format)
+            .with_commitment(cli.commitment);
+
+        let (command, args) = cli_command.into_parts();
+        set_preset_answers(args);
+        run_interruptible(&command, &ctx)
+            .await
+            .ok_or_else(|| anyhow!("Interrupted"))?
+            .map_err(with_friendly_cause)?;
+
+        return Ok(CommandExec::Process(()));
+    }
+
+    notice!(
         "{}",
         style("⚡ Scilla — Hacking Through the Solana Matrix")
             .bold()
@@ -25,19 +68,100 @@ async fn main() -> ScillaResult<()> {
     );
 
     let config = ScillaConfig::load().await?;
-    let ctx = ScillaContext::from_config(config)?;
+    let mut ctx = ScillaContext::from_config(config)?
+        .with_output_format(output_format)
+        .with_commitment(cli.commitment);
 
     loop {
-        let command = prompt_for_command()?;
+        let command = match prompt_for_command() {
+            Ok(command) => command,
+            Err(err) if is_interrupted(&err) => break,
+            Err(err) => return Err(err),
+        };
 
-        let res = command.process_command(&ctx).await?;
+        let next_commitment = take_next_commitment();
+        if let Some(commitment) = next_commitment {
+            ctx.set_commitment_override(commitment);
+        }
+        take_answer_log();
+        ctx.take_signed();
+        let result = run_interruptible(&command, &ctx).await;
+        ctx.clear_commitment_override();
+        clear_preset_answers();
+        let Some(result) = result else {
+            break;
+        };
 
-        match res {
-            CommandExec::Process(_) => continue,
-            CommandExec::GoBack => continue,
-            CommandExec::Exit => break,
+        // A failing command should not tear down the whole session
+        match result {
+            Ok(CommandExec::Exit) => break,
+            Err(err) if is_interrupted(&err) => break,
+            // Backing out of a menu doesn't use up a one-off commitment
+            Ok(CommandExec::GoBack) => set_next_commitment(next_commitment),
+            Ok(CommandExec::Process(_)) => {
+                if let Err(err) = record_history(&command, &ctx) {
+                    print_error(format!("Error saving command history: {err:#}"));
+                }
+
+                // Config edits and profile switches apply without a restart
+                let reloads = match &command {
+                    Command::ScillaConfig(ConfigCommand::NextCommitment) => false,
+                    Command::ScillaConfig(_) | Command::Account(AccountCommand::NewKeypair) => true,
+                    _ => false,
+                };
+                if reloads {
+                    match reload_context(output_format, cli.commitment).await {
+                        Ok(new_ctx) => ctx = new_ctx,
+                        Err(err) => print_error(format!("Error reloading config: {err:#}")),
+                    }
+                }
+            }
+            Err(err) => print_error(format!("Error: {:#}", with_friendly_cause(err))),
         }
     }
 
     Ok(CommandExec::Exit)
 }
+
+/// Saves a finished command to the history. Answers are only kept for
+/// commands that didn't sign anything, so replaying a transfer or a stake
+/// change asks for its inputs again.
+fn record_history(command: &Command, ctx: &ScillaContext) -> anyhow::Result<()> {
+    let answers = take_answer_log();
+    let signed = ctx.take_signed();
+    let Some(words) = command.cli_words() else {
+        return Ok(());
+    };
+
+    let mut history = CommandHistory::load()?;
+    history.record(HistoryEntry {
+        command: words,
+        answers: if signed { Vec::new() } else { answers },
+    });
+    history.save()
+}
+
+/// Runs `command` until it finishes or Ctrl-C is pressed, in which case it is
+/// dropped mid-flight and `None` is returned. Prompts read Ctrl-C as a key
+/// press instead, and fail with an interrupted error.
+async fn run_interruptible(command: &Command, ctx: &ScillaContext) -> Option<ScillaResult<()>> {
+    // Commands that handle Ctrl-C themselves, like Watch, get to see it first
+    tokio::select! {
+        biased;
+        result = command.process_command(ctx) => Some(result),
+        _ = tokio::signal::ctrl_c() => {
+            abandon_spinner();
+            None
+        }
+    }
+}
+
+async fn reload_context(
+    output_format: OutputFormat,
+    commitment: Option<CommitmentLevel>,
+) -> anyhow::Result<ScillaContext> {
+    let config = ScillaConfig::load().await?;
+    Ok(ScillaContext::from_config(config)?
+        .with_output_format(output_format)
+        .with_commitment(commitment))
+}
@@ -1,16 +1,55 @@
 use {
-    crate::{ScillaContext, constants::LAMPORTS_PER_SOL},
+    crate::{
+        ScillaContext,
+        config::{Explorer, TimestampZone},
+        constants::{
+            DEFAULT_MS_PER_SLOT, EXPLORER_TX_BASE_URL, LAMPORTS_PER_SOL,
+            LOOKUP_TABLE_EXTEND_CHUNK_SIZE, MAX_MULTIPLE_ACCOUNTS, MAX_TRANSACTION_SIZE,
+            PERFORMANCE_SAMPLE_LIMIT, SEED_PHRASE_WORD_COUNTS, SOL_DECIMALS, SOLANA_FM_TX_BASE_URL,
+            SOLSCAN_TX_BASE_URL, TX_RETRY_BASE_DELAY_MS, WS_CONFIRMATION_TIMEOUT_SECS,
+        },
+        error::InvalidPubkey,
+        notice,
+        prompt::prompt_confirm,
+        ui::suspend_spinner,
+    },
     anyhow::{Context, anyhow, bail},
     base64::Engine,
     bincode::Options,
+    bip39::Mnemonic,
+    console::style,
+    futures::{StreamExt, future::try_join_all},
+    qrcode::{QrCode, render::unicode::Dense1x2},
     solana_account::Account,
+    solana_address_lookup_table_interface::{
+        instruction as lookup_table_instruction,
+        state::{AddressLookupTable, LOOKUP_TABLE_MAX_ADDRESSES, LOOKUP_TABLE_META_SIZE},
+    },
+    solana_commitment_config::CommitmentConfig,
+    solana_compute_budget_interface::ComputeBudgetInstruction,
+    solana_derivation_path::DerivationPath,
     solana_epoch_info::EpochInfo,
+    solana_hash::Hash,
     solana_instruction::Instruction,
-    solana_keypair::{EncodableKey, Keypair, Signature, Signer},
-    solana_message::Message,
+    solana_keypair::{
+        EncodableKey, Keypair, Signature, Signer,
+        seed_derivable::keypair_from_seed_and_derivation_path, write_keypair_file,
+    },
+    solana_loader_v3_interface::instruction as loader_v3_instruction,
+    solana_message::{AddressLookupTableAccount, Message, VersionedMessage, v0},
     solana_pubkey::Pubkey,
-    solana_transaction::Transaction,
-    std::{path::Path, str::FromStr},
+    solana_pubsub_client::nonblocking::pubsub_client::PubsubClient,
+    solana_rpc_client::rpc_client::SerializableTransaction,
+    solana_rpc_client_api::{
+        client_error::TransactionError,
+        config::{RpcSendTransactionConfig, RpcSignatureSubscribeConfig},
+        response::RpcSignatureResult,
+    },
+    solana_seed_phrase::generate_seed_from_seed_phrase_and_passphrase,
+    solana_system_interface::instruction::advance_nonce_account,
+    solana_transaction::{Transaction, versioned::VersionedTransaction},
+    spl_memo_interface::instruction::build_memo,
+    std::{collections::HashSet, ops::Range, path::Path, str::FromStr, time::Duration},
     tokio::try_join,
 };
 
@@ -26,6 +65,35 @@ pub fn trim_and_parse<T: FromStr>(s: &str, field_name: &str) -> anyhow::Result<O
     }
 }
 
+/// Prompt input that may be left empty to skip the field
+#[derive(Debug, Clone, Copy)]
+pub struct OptionalInput<T>(Option<T>);
+
+impl<T> OptionalInput<T> {
+    pub fn value(self) -> Option<T> {
+        self.0
+    }
+}
+
+impl<T> FromStr for OptionalInput<T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: std::fmt::Display,
+{
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Ok(OptionalInput(None));
+        }
+        trimmed
+            .parse()
+            .map(|value| OptionalInput(Some(value)))
+            .map_err(|e| anyhow!("{e}"))
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct Commission(u8);
 
@@ -50,16 +118,19 @@ impl FromStr for Commission {
     }
 }
 
+/// A positive amount of SOL, kept in lamports. Parses `1.5`, `1.5 SOL`,
+/// `1_000 sol` or an exact `500_000_000 lamports`, never through floating
+/// point.
 #[derive(Debug, Clone, Copy)]
-pub struct SolAmount(f64);
+pub struct SolAmount(u64);
 
 impl SolAmount {
     pub fn value(&self) -> f64 {
-        self.0
+        lamports_to_sol(self.0)
     }
 
     pub fn to_lamports(&self) -> u64 {
-        sol_to_lamports(self.0)
+        self.0
     }
 }
 
@@ -67,128 +138,2403 @@ impl FromStr for SolAmount {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let sol = trim_and_parse::<f64>(s, "amount")?
-            .ok_or_else(|| anyhow!("Amount cannot be empty. Please enter a SOL amount"))?;
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            bail!("Amount cannot be empty. Please enter a SOL amount");
+        }
+
+        let lowercase = trimmed.to_ascii_lowercase();
+        let (number, in_lamports) = match lowercase
+            .strip_suffix("lamports")
+            .or_else(|| lowercase.strip_suffix("lamport"))
+        {
+            Some(number) => (number, true),
+            None => (lowercase.strip_suffix("sol").unwrap_or(&lowercase), false),
+        };
+        let number = strip_digit_separators(number.trim()).ok_or_else(|| {
+            anyhow!("Invalid amount `{trimmed}`. Underscores may only separate digits")
+        })?;
+
+        let (whole, fraction) = number.split_once('.').unwrap_or((&number, ""));
+        let is_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+        if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+            bail!("Invalid amount `{trimmed}`. Enter SOL, e.g. `1.5`, or `500000 lamports`");
+        }
+
+        if !in_lamports {
+            return parse_token_amount(&number, SOL_DECIMALS as u8).map(SolAmount);
+        }
+
+        if number.contains('.') {
+            bail!("Invalid amount `{trimmed}`. Lamports can't be split");
+        }
+        let lamports: u64 = number
+            .parse()
+            .map_err(|_| anyhow!("Amount `{trimmed}` is too large"))?;
+        if lamports == 0 {
+            bail!("Amount must be greater than zero");
+        }
+        Ok(SolAmount(lamports))
+    }
+}
+
+/// Drops `_` digit separators, `None` if one doesn't sit between two digits
+fn strip_digit_separators(s: &str) -> Option<String> {
+    let chars: Vec<char> = s.chars().collect();
+    for (idx, c) in chars.iter().enumerate() {
+        let between_digits = idx > 0
+            && chars[idx - 1].is_ascii_digit()
+            && chars.get(idx + 1).is_some_and(char::is_ascii_digit);
+        if *c == '_' && !between_digits {
+            return None;
+        }
+    }
+
+    Some(s.replace('_', ""))
+}
+
+/// A withdrawal amount where `all`, or empty input, means the whole balance
+#[derive(Debug, Clone, Copy)]
+pub enum WithdrawAmount {
+    All,
+    Sol(SolAmount),
+}
 
-        if sol <= 0.0 || !sol.is_finite() {
-            bail!("Amount must be a positive finite number, got {sol}");
+impl WithdrawAmount {
+    /// The lamports to withdraw from an account holding `balance`
+    pub fn to_lamports(&self, balance: u64) -> u64 {
+        match self {
+            WithdrawAmount::All => balance,
+            WithdrawAmount::Sol(amount) => amount.to_lamports(),
         }
-        if sol * LAMPORTS_PER_SOL as f64 > u64::MAX as f64 {
-            bail!("Amount too large: {sol} SOL would overflow");
+    }
+}
+
+impl FromStr for WithdrawAmount {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("all") {
+            return Ok(WithdrawAmount::All);
         }
-        Ok(SolAmount(sol))
+        trimmed.parse().map(WithdrawAmount::Sol)
     }
 }
 
+/// Converts to the nearest lamport. Truncating would turn amounts such as 0.1
+/// SOL, which are not exact in binary floating point, into one lamport less.
 pub fn sol_to_lamports(sol: f64) -> u64 {
-    (sol * LAMPORTS_PER_SOL as f64) as u64
+    (sol * LAMPORTS_PER_SOL as f64).round() as u64
 }
 
 pub fn lamports_to_sol(lamports: u64) -> f64 {
     lamports as f64 / LAMPORTS_PER_SOL as f64
 }
 
+/// Exact decimal SOL for `lamports`, without trailing zeros
+pub fn format_sol_exact(lamports: u64) -> String {
+    let whole = lamports / LAMPORTS_PER_SOL;
+    let fraction = lamports % LAMPORTS_PER_SOL;
+    if fraction == 0 {
+        return whole.to_string();
+    }
+
+    let fraction = format!("{fraction:0width$}", width = SOL_DECIMALS);
+    format!("{whole}.{}", fraction.trim_end_matches('0'))
+}
+
+/// A Solana Pay transfer request URI, optionally asking for a set amount
+pub fn solana_pay_uri(recipient: &Pubkey, lamports: Option<u64>) -> String {
+    match lamports {
+        Some(lamports) => format!("solana:{recipient}?amount={}", format_sol_exact(lamports)),
+        None => format!("solana:{recipient}"),
+    }
+}
+
+/// Renders `data` as a QR code of half-height block characters, light modules
+/// drawn filled so it scans on a dark terminal background
+pub fn render_qr(data: &str) -> anyhow::Result<String> {
+    let code = QrCode::new(data.as_bytes())?;
+    Ok(code
+        .render::<Dense1x2>()
+        .dark_color(Dense1x2::Light)
+        .light_color(Dense1x2::Dark)
+        .build())
+}
+
+/// Parses a decimal token amount such as `12.5` into base units for a mint
+/// with `decimals` decimals, without going through floating point
+pub fn parse_token_amount(s: &str, decimals: u8) -> anyhow::Result<u64> {
+    let trimmed = s.trim();
+    let (whole, fraction) = trimmed.split_once('.').unwrap_or((trimmed, ""));
+
+    let is_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+        bail!("Invalid token amount: `{trimmed}`");
+    }
+    if fraction.len() > decimals as usize {
+        bail!("Amount `{trimmed}` has more than {decimals} decimal places");
+    }
+
+    let padded = format!("{whole}{fraction:0<width$}", width = decimals as usize);
+    let amount: u64 = padded
+        .parse()
+        .map_err(|_| anyhow!("Token amount `{trimmed}` is too large"))?;
+
+    if amount == 0 {
+        bail!("Amount must be greater than zero");
+    }
+    Ok(amount)
+}
+
+/// File formats results can be exported to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl std::fmt::Display for ExportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportFormat::Csv => write!(f, "CSV"),
+            ExportFormat::Json => write!(f, "JSON"),
+        }
+    }
+}
+
+/// Quotes a CSV field when it contains a separator, quote or newline
+pub fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Renders a header and rows as CSV text
+pub fn to_csv(header: &[&str], rows: impl IntoIterator<Item = Vec<String>>) -> String {
+    let mut csv = header.join(",");
+    csv.push('\n');
+    for row in rows {
+        let fields: Vec<String> = row.iter().map(|field| csv_escape(field)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Shortens long strings like pubkeys and signatures to `head…tail`, keeping
+/// `keep` characters on each side
+pub fn truncate_middle(s: &str, keep: usize) -> String {
+    let char_count = s.chars().count();
+    if char_count <= keep * 2 + 1 {
+        return s.to_string();
+    }
+
+    let head: String = s.chars().take(keep).collect();
+    let tail: String = s.chars().skip(char_count - keep).collect();
+    format!("{head}…{tail}")
+}
+
+/// Renders bytes as `offset  hex  |ascii|` lines, 16 bytes per line
+pub fn hexdump(data: &[u8]) -> String {
+    data.chunks(16)
+        .enumerate()
+        .map(|(line, chunk)| {
+            let hex: Vec<String> = chunk.iter().map(|byte| format!("{byte:02x}")).collect();
+            let (first, second) = hex.split_at(hex.len().min(8));
+            let ascii: String = chunk
+                .iter()
+                .map(|&byte| match byte {
+                    0x20..=0x7e => byte as char,
+                    _ => '.',
+                })
+                .collect();
+            format!(
+                "{:08x}  {:<23}  {:<23}  |{ascii}|",
+                line * 16,
+                first.join(" "),
+                second.join(" ")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders `fraction` (clamped to 0..=1) as a fixed-width `[████░░░░]` bar
+pub fn progress_bar(fraction: f64, width: usize) -> String {
+    let fraction = fraction.clamp(0.0, 1.0);
+    let filled = ((fraction * width as f64).round() as usize).min(width);
+    format!("[{}{}]", "█".repeat(filled), "░".repeat(width - filled))
+}
+
+/// Formats a duration as a compact `1d 2h 3m` style string
+pub fn format_duration(duration: Duration) -> String {
+    let total_secs = duration.as_secs();
+    let (days, hours, minutes, secs) = (
+        total_secs / 86_400,
+        (total_secs % 86_400) / 3_600,
+        (total_secs % 3_600) / 60,
+        total_secs % 60,
+    );
+
+    match (days, hours, minutes) {
+        (0, 0, 0) => format!("{secs}s"),
+        (0, 0, _) => format!("{minutes}m {secs}s"),
+        (0, _, _) => format!("{hours}h {minutes}m"),
+        _ => format!("{days}d {hours}h {minutes}m"),
+    }
+}
+
+/// Formats a unix timestamp such as a block time in the configured zone,
+/// falling back to the raw number when it is out of range
+pub fn format_block_time(unix_timestamp: i64, zone: TimestampZone) -> String {
+    let Some(utc) = chrono::DateTime::<chrono::Utc>::from_timestamp_secs(unix_timestamp) else {
+        return unix_timestamp.to_string();
+    };
+
+    match zone {
+        TimestampZone::Utc => utc.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+        TimestampZone::Local => utc
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M:%S %:z")
+            .to_string(),
+    }
+}
+
+/// Average slot duration over the most recent performance samples, falling back
+/// to the target slot time when the node has no samples
+pub async fn average_slot_time_ms(ctx: &ScillaContext) -> f64 {
+    let samples = ctx
+        .rpc()
+        .get_recent_performance_samples(Some(PERFORMANCE_SAMPLE_LIMIT))
+        .await
+        .unwrap_or_default();
+
+    let (slots, secs) = samples.iter().fold((0u64, 0u64), |(slots, secs), sample| {
+        (
+            slots + sample.num_slots,
+            secs + sample.sample_period_secs as u64,
+        )
+    });
+
+    if slots == 0 {
+        return DEFAULT_MS_PER_SLOT as f64;
+    }
+    (secs * 1_000) as f64 / slots as f64
+}
+
+/// One block character per value, scaled between the smallest and largest
+pub fn sparkline(values: &[f64]) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+
+    values
+        .iter()
+        .map(|value| {
+            if range <= 0.0 {
+                return BLOCKS[BLOCKS.len() / 2];
+            }
+            let level = ((value - min) / range * (BLOCKS.len() - 1) as f64).round() as usize;
+            BLOCKS[level.min(BLOCKS.len() - 1)]
+        })
+        .collect()
+}
+
+/// Groups sorted slot indices into inclusive `(first, last)` runs of
+/// consecutive slots, the way leaders are scheduled
+pub fn slot_runs(slots: &[usize]) -> Vec<(usize, usize)> {
+    let mut runs: Vec<(usize, usize)> = Vec::new();
+    for &slot in slots {
+        match runs.last_mut() {
+            Some((_, last)) if *last + 1 == slot => *last = slot,
+            _ => runs.push((slot, slot)),
+        }
+    }
+    runs
+}
+
+/// Slots left until `target_epoch` starts, zero once it has been reached
+pub fn slots_until_epoch(epoch_info: &EpochInfo, target_epoch: u64) -> u64 {
+    if target_epoch <= epoch_info.epoch {
+        return 0;
+    }
+
+    let left_in_epoch = epoch_info
+        .slots_in_epoch
+        .saturating_sub(epoch_info.slot_index);
+    let full_epochs = target_epoch - epoch_info.epoch - 1;
+    left_in_epoch + full_epochs * epoch_info.slots_in_epoch
+}
+
+pub fn slots_to_duration(slots: u64, slot_time_ms: f64) -> Duration {
+    Duration::from_millis((slots as f64 * slot_time_ms).round() as u64)
+}
+
+/// How many epochs of `slots_in_epoch` slots fit in a year at the given slot
+/// time
+pub fn epochs_per_year(slots_in_epoch: u64, slot_time_ms: f64) -> f64 {
+    const MS_PER_YEAR: f64 = 365.25 * 86_400.0 * 1_000.0;
+    MS_PER_YEAR / (slots_in_epoch as f64 * slot_time_ms)
+}
+
+/// Compounds a single epoch's reward into an annual percentage yield.
+/// `None` when there was no balance before the reward.
+pub fn annualized_yield(reward: u64, post_balance: u64, epochs_per_year: f64) -> Option<f64> {
+    let pre_balance = post_balance
+        .checked_sub(reward)
+        .filter(|balance| *balance > 0)?;
+    let epoch_rate = reward as f64 / pre_balance as f64;
+    Some(compound_epoch_rate(epoch_rate, epochs_per_year))
+}
+
+/// Compounds a per-epoch rate into an annual percentage yield
+pub fn compound_epoch_rate(epoch_rate: f64, epochs_per_year: f64) -> f64 {
+    ((1.0 + epoch_rate).powf(epochs_per_year) - 1.0) * 100.0
+}
+
+/// Numeric `major.minor.patch` parts of a node version for ordering, so
+/// `2.10.0` sorts after `2.9.3`. Anything past the first non-number is
+/// ignored.
+pub fn version_key(version: &str) -> Vec<u64> {
+    version
+        .split(['.', '-', ' '])
+        .map_while(|part| part.parse().ok())
+        .collect()
+}
+
+/// Average vote credits earned per epoch over the most recent `limit`
+/// completed epochs, `None` when the validator has none yet
+pub fn average_epoch_credits(
+    epoch_credits: &[(u64, u64, u64)],
+    current_epoch: u64,
+    limit: usize,
+) -> Option<f64> {
+    let earned: Vec<u64> = epoch_credits
+        .iter()
+        .rev()
+        .filter(|(epoch, ..)| *epoch < current_epoch)
+        .take(limit)
+        .map(|(_, credits, prev_credits)| credits.saturating_sub(*prev_credits))
+        .collect();
+    if earned.is_empty() {
+        return None;
+    }
+    Some(earned.iter().sum::<u64>() as f64 / earned.len() as f64)
+}
+
+/// Nearest-rank percentile of an ascending slice, `None` when empty
+pub fn percentile(sorted: &[Duration], pct: f64) -> Option<Duration> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.clamp(1, sorted.len()) - 1])
+}
+
+/// Writes a keypair in the `id.json` byte-array format
+pub fn save_keypair(keypair: &Keypair, path: &Path) -> anyhow::Result<()> {
+    write_keypair_file(keypair, path)
+        .map_err(|err| anyhow!("Failed to write keypair to {}: {err}", path.display()))?;
+    Ok(())
+}
+
+/// Asks before a keypair file would be replaced, `true` when there is
+/// nothing there or the user agrees
+pub fn confirm_keypair_overwrite(path: &Path) -> anyhow::Result<bool> {
+    if !path.exists() {
+        return Ok(true);
+    }
+
+    notice!(
+        "{}",
+        style(format!(
+            "⚠ {} already exists. Overwriting it loses that keypair for good unless it is backed \
+             up.",
+            path.display()
+        ))
+        .yellow()
+        .bold()
+    );
+    prompt_confirm("Overwrite it?")
+}
+
 pub fn read_keypair_from_path<P: AsRef<Path>>(path: P) -> anyhow::Result<Keypair> {
     let path = path.as_ref();
     Keypair::read_from_file(path)
         .map_err(|e| anyhow!("Failed to read keypair from {}: {}", path.display(), e))
 }
 
-pub async fn build_and_send_tx(
-    ctx: &ScillaContext,
-    instruction: &[Instruction],
-    signers: &[&dyn Signer],
-) -> anyhow::Result<Signature> {
-    let recent_blockhash = ctx.rpc().get_latest_blockhash().await?;
-    let message = Message::new(instruction, Some(ctx.pubkey()));
-    let mut tx = Transaction::new_unsigned(message);
-    tx.try_sign(&signers.to_vec(), recent_blockhash)?;
+/// Parses an absolute derivation path such as `m/44'/501'/0'/0'`. Every
+/// index is hardened, as ed25519 only supports hardened derivation.
+pub fn parse_derivation_path(path: &str) -> anyhow::Result<DerivationPath> {
+    let path = path.trim();
+    if !path.starts_with("m/") {
+        bail!("Invalid derivation path `{path}`. Expected a path like m/44'/501'/0'/0'");
+    }
+    DerivationPath::from_absolute_path_str(path)
+        .map_err(|e| anyhow!("Invalid derivation path `{path}`: {e}"))
+}
+
+/// Derives a keypair from a BIP39 seed phrase the same way wallets such as
+/// Phantom and the Solana CLI do
+pub fn keypair_from_seed_phrase(
+    seed_phrase: &str,
+    passphrase: &str,
+    derivation_path: &str,
+) -> anyhow::Result<Keypair> {
+    let words: Vec<&str> = seed_phrase.split_whitespace().collect();
+    if !SEED_PHRASE_WORD_COUNTS.contains(&words.len()) {
+        bail!(
+            "Seed phrase has {} words, expected 12, 15, 18, 21 or 24",
+            words.len()
+        );
+    }
 
-    let signature = ctx.rpc().send_and_confirm_transaction(&tx).await?;
+    let derivation_path = parse_derivation_path(derivation_path)?;
+    let seed =
+        generate_seed_from_seed_phrase_and_passphrase(&words.join(" ").to_lowercase(), passphrase);
 
-    Ok(signature)
+    keypair_from_seed_and_derivation_path(&seed, Some(derivation_path))
+        .map_err(|e| anyhow!("Failed to derive keypair from seed phrase: {e}"))
 }
 
-/// Fetches account data and current epoch info in parallel.
-pub async fn fetch_account_with_epoch(
-    ctx: &ScillaContext,
-    pubkey: &Pubkey,
-) -> anyhow::Result<(Account, EpochInfo)> {
-    try_join!(
-        async {
-            ctx.rpc()
-                .get_account(pubkey)
-                .await
-                .map_err(|_| anyhow!("{pubkey} account does not exist"))
-        },
-        async {
-            ctx.rpc()
-                .get_epoch_info()
-                .await
-                .map_err(anyhow::Error::from)
+/// A fresh English BIP39 seed phrase with `word_count` words
+pub fn generate_seed_phrase(word_count: usize) -> anyhow::Result<String> {
+    if !SEED_PHRASE_WORD_COUNTS.contains(&word_count) {
+        bail!("Seed phrases have 12, 15, 18, 21 or 24 words, not {word_count}");
+    }
+
+    Ok(Mnemonic::generate(word_count)?.to_string())
+}
+
+/// Prepends compute budget instructions so they run before the rest of the
+/// transaction
+pub fn with_compute_budget(
+    instructions: &[Instruction],
+    micro_lamports: Option<u64>,
+    cu_limit: Option<u32>,
+) -> Vec<Instruction> {
+    let mut all_instructions = Vec::with_capacity(instructions.len() + 2);
+    if let Some(cu_limit) = cu_limit {
+        all_instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(cu_limit));
+    }
+    if let Some(micro_lamports) = micro_lamports {
+        all_instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            micro_lamports,
+        ));
+    }
+    all_instructions.extend_from_slice(instructions);
+    all_instructions
+}
+
+/// A validated row of a batch transfer CSV
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransferRow {
+    /// 1-based line number in the CSV file
+    pub line: usize,
+    pub recipient: Pubkey,
+    pub lamports: u64,
+}
+
+/// Parses `pubkey,amount_sol` rows. Blank lines, `#` comments and a leading
+/// header row are skipped. Every row is validated, recipients off the curve
+/// included, and all problems are reported together.
+pub fn parse_transfer_csv(content: &str) -> anyhow::Result<Vec<TransferRow>> {
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+
+    for (idx, raw_line) in content.lines().enumerate() {
+        let line = idx + 1;
+        let trimmed = raw_line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
         }
-    )
+
+        let fields: Vec<&str> = trimmed.split(',').map(str::trim).collect();
+        if rows.is_empty() && errors.is_empty() && fields[0].eq_ignore_ascii_case("pubkey") {
+            continue;
+        }
+
+        let [recipient, amount] = fields[..] else {
+            errors.push(format!(
+                "line {line}: expected 2 columns (pubkey, amount_sol), found {}",
+                fields.len()
+            ));
+            continue;
+        };
+
+        match (Pubkey::from_str(recipient), amount.parse::<SolAmount>()) {
+            (Ok(recipient), Ok(amount)) => match ensure_on_curve(recipient) {
+                Ok(recipient) => rows.push(TransferRow {
+                    line,
+                    recipient,
+                    lamports: amount.to_lamports(),
+                }),
+                Err(err) => errors.push(format!("line {line}: {err}")),
+            },
+            (Err(_), _) => errors.push(format!("line {line}: invalid pubkey `{recipient}`")),
+            (_, Err(err)) => errors.push(format!("line {line}: {err}")),
+        }
+    }
+
+    if !errors.is_empty() {
+        bail!("Invalid transfer CSV:\n  {}", errors.join("\n  "));
+    }
+    if rows.is_empty() {
+        bail!("The CSV file contains no transfers");
+    }
+
+    Ok(rows)
 }
 
-/// Generic helper to deserialize bincode data with consistent error
-/// context
-pub fn bincode_deserialize<T>(data: &[u8], ctx: &str) -> anyhow::Result<T>
-where
-    T: serde::de::DeserializeOwned,
-{
-    bincode::deserialize::<T>(data).with_context(|| format!("Failed to deserialize {}", ctx))
+/// Whether an RPC error means the node or faucet is throttling requests, which
+/// is worth waiting out rather than reporting straight away
+pub fn is_rate_limit_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+    ["429", "too many requests", "rate limit", "airdrop limit"]
+        .iter()
+        .any(|marker| message.contains(marker))
 }
 
-/// Generic helper to deserialize bincode data with limit and proper error
-/// context
-pub fn bincode_deserialize_with_limit<T>(limit: u64, data: &[u8], ctx: &str) -> anyhow::Result<T>
-where
-    T: serde::de::DeserializeOwned,
-{
-    bincode::options()
-        .with_fixint_encoding()
-        .with_limit(limit)
-        .deserialize::<T>(data)
-        .with_context(|| format!("Failed to deserialize {}", ctx))
+/// Parses pubkeys separated by commas, whitespace or newlines, skipping `#`
+/// comment lines and repeats. Every entry is validated and all problems are
+/// reported together.
+pub fn parse_pubkey_list(content: &str) -> anyhow::Result<Vec<Pubkey>> {
+    let mut pubkeys = Vec::new();
+    let mut errors = Vec::new();
+
+    let entries = content
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .flat_map(|line| line.split(|c: char| c == ',' || c.is_whitespace()))
+        .filter(|entry| !entry.is_empty());
+    for entry in entries {
+        match Pubkey::from_str(entry) {
+            Ok(pubkey) if !pubkeys.contains(&pubkey) => pubkeys.push(pubkey),
+            Ok(_) => {}
+            Err(_) => errors.push(format!("invalid pubkey `{entry}`")),
+        }
+    }
+
+    if !errors.is_empty() {
+        bail!("Invalid address list:\n  {}", errors.join("\n  "));
+    }
+    if pubkeys.is_empty() {
+        bail!("No addresses given");
+    }
+
+    Ok(pubkeys)
 }
 
-pub fn decode_base64(encoded: &str) -> anyhow::Result<Vec<u8>> {
-    let trimmed = encoded.trim();
-    if trimmed.is_empty() {
-        bail!("Encoded data cannot be empty");
+/// Splits `instructions` into consecutive ranges that each fit in a single
+/// transaction paid and signed by `payer`, including the compute budget
+/// instructions that get prepended when sending. With `lookup_tables`, the
+/// ranges are sized for v0 transactions loading accounts from them.
+pub fn pack_instructions(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    lookup_tables: &[AddressLookupTableAccount],
+    micro_lamports: Option<u64>,
+    cu_limit: Option<u32>,
+) -> anyhow::Result<Vec<Range<usize>>> {
+    let fits = |range: Range<usize>| -> anyhow::Result<bool> {
+        let instructions = with_compute_budget(&instructions[range], micro_lamports, cu_limit);
+        let size = match lookup_tables {
+            [] => bincode::serialized_size(&Transaction::new_unsigned(Message::new(
+                &instructions,
+                Some(payer),
+            )))?,
+            _ => {
+                let message =
+                    v0::Message::try_compile(payer, &instructions, lookup_tables, Hash::default())?;
+                let signatures = message.header.num_required_signatures as usize;
+                bincode::serialized_size(&VersionedTransaction {
+                    signatures: vec![Signature::default(); signatures],
+                    message: VersionedMessage::V0(message),
+                })?
+            }
+        };
+        Ok(size as usize <= MAX_TRANSACTION_SIZE)
+    };
+
+    let mut batches = Vec::new();
+    let mut start = 0;
+
+    while start < instructions.len() {
+        if !fits(start..start + 1)? {
+            bail!(
+                "Instruction {} alone does not fit in a transaction",
+                start + 1
+            );
+        }
+
+        let mut end = start + 1;
+        while end < instructions.len() && fits(start..end + 1)? {
+            end += 1;
+        }
+
+        batches.push(start..end);
+        start = end;
     }
 
-    base64::engine::general_purpose::STANDARD
-        .decode(trimmed)
-        .map_err(|e| {
-            anyhow::anyhow!(
-                "Failed to decode Base64: {}. Please ensure the data is valid Base64 encoded.",
-                e
-            )
-        })
+    Ok(batches)
 }
 
-pub fn decode_base58(encoded: &str) -> anyhow::Result<Vec<u8>> {
-    let trimmed = encoded.trim();
-    if trimmed.is_empty() {
-        bail!("Encoded data cannot be empty");
+/// A memo instruction signed by `signer`, once it is known to still fit in a
+/// single transaction ahead of `instructions`
+pub fn memo_instruction(
+    memo: &str,
+    signer: &Pubkey,
+    instructions: &[Instruction],
+    micro_lamports: Option<u64>,
+    cu_limit: Option<u32>,
+) -> anyhow::Result<Instruction> {
+    let memo_ix = build_memo(&spl_memo_interface::v3::id(), memo.as_bytes(), &[signer]);
+
+    let mut with_memo = vec![memo_ix.clone()];
+    with_memo.extend_from_slice(instructions);
+    let message = Message::new(
+        &with_compute_budget(&with_memo, micro_lamports, cu_limit),
+        Some(signer),
+    );
+    let size = bincode::serialized_size(&Transaction::new_unsigned(message))? as usize;
+    if size > MAX_TRANSACTION_SIZE {
+        bail!(
+            "Memo is {} bytes too long to fit in the transaction",
+            size - MAX_TRANSACTION_SIZE
+        );
     }
 
-    bs58::decode(trimmed).into_vec().map_err(|e| {
-        anyhow::anyhow!(
-            "Failed to decode Base58: {}. Please ensure the data is valid Base58 encoded.",
-            e
-        )
-    })
+    Ok(memo_ix)
 }
 
-#[cfg(test)]
-mod tests {
-    use {
-        super::*, crate::constants::MEMO_PROGRAM_ID, solana_message::VersionedMessage,
-        solana_transaction::versioned::VersionedTransaction,
+/// The most program bytes a single loader `Write` instruction can carry while
+/// its transaction, paid and signed by `payer`, still fits
+pub fn max_write_chunk_size(
+    payer: &Pubkey,
+    buffer: &Pubkey,
+    micro_lamports: Option<u64>,
+    cu_limit: Option<u32>,
+) -> anyhow::Result<usize> {
+    // Big enough for the instruction data length to take its two-byte
+    // compact encoding, as it does for any real chunk
+    const PROBE_LEN: usize = 128;
+    let probe_write = loader_v3_instruction::write(buffer, payer, 0, vec![0; PROBE_LEN]);
+    let message = Message::new(
+        &with_compute_budget(&[probe_write], micro_lamports, cu_limit),
+        Some(payer),
+    );
+    let size = bincode::serialized_size(&Transaction::new_unsigned(message))? as usize;
+
+    (MAX_TRANSACTION_SIZE + PROBE_LEN)
+        .checked_sub(size)
+        .filter(|chunk_size| *chunk_size >= PROBE_LEN)
+        .ok_or_else(|| anyhow!("A program write instruction does not fit in a transaction"))
+}
+
+/// The PubSub WebSocket endpoint that goes with an HTTP RPC URL. An explicit
+/// port is bumped by one, as `solana-test-validator` serves it there.
+pub fn ws_url_from_rpc_url(rpc_url: &str) -> anyhow::Result<String> {
+    let (scheme, rest) = if let Some(rest) = rpc_url.strip_prefix("https://") {
+        ("wss", rest)
+    } else if let Some(rest) = rpc_url.strip_prefix("http://") {
+        ("ws", rest)
+    } else {
+        bail!("Can't derive a WebSocket URL from `{rpc_url}`, expected an http(s) RPC URL");
+    };
+
+    let (host, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    let host = match host.rsplit_once(':') {
+        Some((name, port)) => match port.parse::<u16>() {
+            Ok(port) => format!("{name}:{}", port.wrapping_add(1)),
+            Err(_) => host.to_string(),
+        },
+        None => host.to_string(),
     };
 
+    Ok(format!("{scheme}://{host}{path}"))
+}
+
+/// Builds and sends a transaction using the priority fee and compute unit
+/// limit from the config
+pub async fn build_and_send_tx(
+    ctx: &ScillaContext,
+    instruction: &[Instruction],
+    signers: &[&dyn Signer],
+) -> anyhow::Result<Signature> {
+    build_and_send_tx_with_fee(
+        ctx,
+        instruction,
+        signers,
+        ctx.priority_fee_micro_lamports(),
+        ctx.compute_unit_limit(),
+    )
+    .await
+}
+
+pub async fn build_and_send_tx_with_fee(
+    ctx: &ScillaContext,
+    instruction: &[Instruction],
+    signers: &[&dyn Signer],
+    micro_lamports: Option<u64>,
+    cu_limit: Option<u32>,
+) -> anyhow::Result<Signature> {
+    send_with_retry(ctx, false, |blockhash| {
+        build_signed_tx(
+            ctx,
+            instruction,
+            signers,
+            blockhash,
+            micro_lamports,
+            cu_limit,
+        )
+    })
+    .await
+}
+
+/// Like [`build_and_send_tx`], but sends a v0 transaction that loads accounts
+/// found in `lookup_tables` by index. That fits far more accounts than the
+/// legacy format, e.g. for batches touching many wallets.
+pub async fn build_and_send_versioned_tx(
+    ctx: &ScillaContext,
+    instructions: &[Instruction],
+    signers: &[&dyn Signer],
+    lookup_tables: &[AddressLookupTableAccount],
+) -> anyhow::Result<Signature> {
+    send_with_retry(ctx, false, |blockhash| {
+        build_versioned_tx(
+            instructions,
+            ctx.pubkey(),
+            signers,
+            blockhash,
+            lookup_tables,
+            ctx.priority_fee_micro_lamports(),
+            ctx.compute_unit_limit(),
+        )
+    })
+    .await
+}
+
+/// Sends a transaction signed over the value stored in a durable nonce
+/// account, with advancing the nonce as its first instruction. It stays valid
+/// until the nonce moves on, so it is sent once instead of re-signed.
+pub async fn build_and_send_nonce_tx(
+    ctx: &ScillaContext,
+    instructions: &[Instruction],
+    signers: &[&dyn Signer],
+    nonce: DurableNonce,
+    nonce_blockhash: Hash,
+) -> anyhow::Result<Signature> {
+    let tx = build_offline_tx(
+        instructions,
+        ctx.pubkey(),
+        signers,
+        nonce_blockhash,
+        Some(nonce),
+        ctx.priority_fee_micro_lamports(),
+        ctx.compute_unit_limit(),
+    )?;
+    send_and_confirm(ctx, &tx).await
+}
+
+/// Simulates the transaction first and only broadcasts it if the simulation
+/// succeeds
+pub async fn simulate_then_send(
+    ctx: &ScillaContext,
+    instruction: &[Instruction],
+    signers: &[&dyn Signer],
+) -> anyhow::Result<Signature> {
+    send_with_retry(ctx, true, |blockhash| {
+        build_signed_tx(
+            ctx,
+            instruction,
+            signers,
+            blockhash,
+            ctx.priority_fee_micro_lamports(),
+            ctx.compute_unit_limit(),
+        )
+    })
+    .await
+}
+
+/// Errors that leave the transaction unconfirmed rather than rejected. An
+/// expired blockhash means it can no longer land, but after a timeout it
+/// still might, so it must not be re-signed before its blockhash expires.
+pub fn is_retryable_send_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+    [
+        "blockhash not found",
+        "unable to confirm transaction",
+        "timed out",
+        "timeout",
+    ]
+    .iter()
+    .any(|pattern| message.contains(pattern))
+}
+
+/// Sends the transaction `build` signs over the given blockhash, re-building
+/// it over a fresh blockhash for each retry once the previous attempt can no
+/// longer land
+async fn send_with_retry<T: SerializableTransaction>(
+    ctx: &ScillaContext,
+    simulate: bool,
+    build: impl Fn(Hash) -> anyhow::Result<T>,
+) -> anyhow::Result<Signature> {
+    let max_retries = ctx.tx_retries();
+    let mut attempt = 0;
+
+    loop {
+        let tx = build(ctx.latest_blockhash().await?)?;
+
+        if simulate && attempt == 0 {
+            simulate_tx(ctx, &tx).await?;
+        }
+
+        // Identical transactions signed over the same blockhash would be
+        // rejected as duplicates, so the next send gets a fresh one
+        let result = send_and_confirm(ctx, &tx).await;
+        ctx.invalidate_blockhash();
+        let err = match result {
+            Ok(signature) => return Ok(signature),
+            Err(err) => err,
+        };
+
+        if attempt >= max_retries || !is_retryable_send_error(&err.to_string()) {
+            return Err(err);
+        }
+
+        // A timed out attempt may still land; re-signing it now could pay twice
+        if let Some(signature) = rebroadcast_until_expired(ctx, &tx).await? {
+            return Ok(signature);
+        }
+
+        let backoff = Duration::from_millis(TX_RETRY_BASE_DELAY_MS << attempt);
+        attempt += 1;
+        notice!(
+            "{}",
+            style(format!(
+                "Transaction attempt failed ({err}), retrying {attempt}/{max_retries} in {}ms…",
+                backoff.as_millis()
+            ))
+            .yellow()
+        );
+        tokio::time::sleep(backoff).await;
+    }
+}
+
+/// Keeps rebroadcasting the same signed transaction until it lands or its
+/// blockhash expires. `None` means it expired without landing and is safe to
+/// re-sign; one that landed and failed comes back as the error.
+async fn rebroadcast_until_expired(
+    ctx: &ScillaContext,
+    tx: &impl SerializableTransaction,
+) -> anyhow::Result<Option<Signature>> {
+    let signature = tx.get_signature();
+
+    loop {
+        if landed(ctx, signature).await? {
+            return Ok(Some(*signature));
+        }
+
+        let blockhash_valid = ctx
+            .rpc()
+            .is_blockhash_valid(tx.get_recent_blockhash(), CommitmentConfig::processed())
+            .await?;
+        if !blockhash_valid {
+            // It may have landed in the last slots before the expiry
+            return Ok(landed(ctx, signature).await?.then_some(*signature));
+        }
+
+        // The same signature lands at most once, so resending it is harmless
+        let config = RpcSendTransactionConfig {
+            skip_preflight: true,
+            ..RpcSendTransactionConfig::default()
+        };
+        let _ = ctx.rpc().send_transaction_with_config(tx, config).await;
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+}
+
+/// Whether the transaction was processed, failing if it landed with an error
+async fn landed(ctx: &ScillaContext, signature: &Signature) -> anyhow::Result<bool> {
+    let status = ctx
+        .rpc()
+        .get_signature_status_with_commitment(signature, CommitmentConfig::processed())
+        .await?;
+    match status {
+        Some(Ok(())) => Ok(true),
+        Some(Err(err)) => Err(err.into()),
+        None => Ok(false),
+    }
+}
+
+/// Sends the transaction and waits until it reaches the configured commitment,
+/// over a WebSocket notification when enabled in the config
+pub(crate) async fn send_and_confirm(
+    ctx: &ScillaContext,
+    tx: &impl SerializableTransaction,
+) -> anyhow::Result<Signature> {
+    if !ctx.websocket_confirmation() {
+        return Ok(ctx.rpc().send_and_confirm_transaction(tx).await?);
+    }
+
+    let signature = ctx.rpc().send_transaction(tx).await?;
+    let timeout = Duration::from_secs(WS_CONFIRMATION_TIMEOUT_SECS);
+    match wait_for_signature_notification(ctx, &signature, timeout).await {
+        Some(Ok(())) => Ok(signature),
+        Some(Err(err)) => Err(err.into()),
+        None => poll_until_landed_or_expired(ctx, tx).await,
+    }
+}
+
+/// Waits up to `timeout` for `signature` to reach the configured commitment
+/// over a `signatureSubscribe` WebSocket. `None` means the WebSocket was
+/// unavailable or stayed silent, and the caller should poll instead.
+pub async fn wait_for_signature_notification(
+    ctx: &ScillaContext,
+    signature: &Signature,
+    timeout: Duration,
+) -> Option<Result<(), TransactionError>> {
+    let client = PubsubClient::new(&ctx.ws_url().ok()?).await.ok()?;
+    let config = RpcSignatureSubscribeConfig {
+        commitment: Some(ctx.rpc().commitment()),
+        enable_received_notification: Some(false),
+    };
+    let (mut notifications, unsubscribe) = client
+        .signature_subscribe(signature, Some(config))
+        .await
+        .ok()?;
+
+    // It may have landed before the subscription was set up, in which case no
+    // notification would come
+    let result = match ctx.rpc().get_signature_status(signature).await {
+        Ok(Some(result)) => Some(result),
+        _ => match tokio::time::timeout(timeout, notifications.next()).await {
+            Ok(Some(notification)) => match notification.value {
+                RpcSignatureResult::ProcessedSignature(processed) => {
+                    Some(processed.err.map_or(Ok(()), |err| Err(err.into())))
+                }
+                RpcSignatureResult::ReceivedSignature(_) => None,
+            },
+            _ => None,
+        },
+    };
+
+    unsubscribe().await;
+    result
+}
+
+/// Polls the signature status until the transaction lands or its blockhash
+/// expires
+async fn poll_until_landed_or_expired(
+    ctx: &ScillaContext,
+    tx: &impl SerializableTransaction,
+) -> anyhow::Result<Signature> {
+    let signature = *tx.get_signature();
+
+    loop {
+        match ctx.rpc().get_signature_status(&signature).await? {
+            Some(Ok(())) => return Ok(signature),
+            Some(Err(err)) => return Err(err.into()),
+            None => {
+                let blockhash_valid = ctx
+                    .rpc()
+                    .is_blockhash_valid(tx.get_recent_blockhash(), CommitmentConfig::processed())
+                    .await?;
+                if !blockhash_valid {
+                    bail!("Unable to confirm transaction before its blockhash expired");
+                }
+                tokio::time::sleep(Duration::from_millis(500)).await;
+            }
+        }
+    }
+}
+
+fn build_signed_tx(
+    ctx: &ScillaContext,
+    instruction: &[Instruction],
+    signers: &[&dyn Signer],
+    recent_blockhash: Hash,
+    micro_lamports: Option<u64>,
+    cu_limit: Option<u32>,
+) -> anyhow::Result<Transaction> {
+    let instructions = with_compute_budget(instruction, micro_lamports, cu_limit);
+    let message = Message::new(&instructions, Some(ctx.pubkey()));
+    let mut tx = Transaction::new_unsigned(message);
+    tx.try_sign(&signers.to_vec(), recent_blockhash)?;
+
+    Ok(tx)
+}
+
+/// Builds and signs a v0 transaction. Accounts that appear in one of the
+/// lookup tables, other than signers and invoked programs, are loaded by
+/// one-byte index instead of taking 32 bytes each.
+pub fn build_versioned_tx(
+    instructions: &[Instruction],
+    payer: &Pubkey,
+    signers: &[&dyn Signer],
+    recent_blockhash: Hash,
+    lookup_tables: &[AddressLookupTableAccount],
+    micro_lamports: Option<u64>,
+    cu_limit: Option<u32>,
+) -> anyhow::Result<VersionedTransaction> {
+    let instructions = with_compute_budget(instructions, micro_lamports, cu_limit);
+    let message = v0::Message::try_compile(payer, &instructions, lookup_tables, recent_blockhash)?;
+
+    Ok(VersionedTransaction::try_new(
+        VersionedMessage::V0(message),
+        &signers.to_vec(),
+    )?)
+}
+
+/// Fetches an address lookup table in the form v0 messages are compiled
+/// against
+pub async fn fetch_lookup_table(
+    ctx: &ScillaContext,
+    address: &Pubkey,
+) -> anyhow::Result<AddressLookupTableAccount> {
+    let account = ctx.rpc().get_account(address).await?;
+    if account.owner != solana_address_lookup_table_interface::program::id() {
+        bail!("{address} is not an address lookup table");
+    }
+
+    let table = AddressLookupTable::deserialize(&account.data)
+        .map_err(|err| anyhow!("Failed to decode lookup table {address}: {err}"))?;
+
+    Ok(AddressLookupTableAccount {
+        key: *address,
+        addresses: table.addresses.to_vec(),
+    })
+}
+
+/// Accounts the instructions touch that a lookup table can hold, in order of
+/// first use. Signers and invoked programs always stay in the message itself.
+pub fn lookup_table_candidates(instructions: &[Instruction]) -> Vec<Pubkey> {
+    let programs: HashSet<Pubkey> = instructions.iter().map(|ix| ix.program_id).collect();
+    let mut seen = HashSet::new();
+
+    instructions
+        .iter()
+        .flat_map(|ix| &ix.accounts)
+        .filter(|meta| !meta.is_signer && !programs.contains(&meta.pubkey))
+        .map(|meta| meta.pubkey)
+        .filter(|pubkey| seen.insert(*pubkey))
+        .take(LOOKUP_TABLE_MAX_ADDRESSES)
+        .collect()
+}
+
+/// Lamports a lookup table holding `len` addresses locks up until it is
+/// deactivated and closed
+pub async fn lookup_table_rent(ctx: &ScillaContext, len: usize) -> anyhow::Result<u64> {
+    Ok(ctx
+        .rpc()
+        .get_minimum_balance_for_rent_exemption(LOOKUP_TABLE_META_SIZE + len * 32)
+        .await?)
+}
+
+/// Creates an address lookup table owned by the wallet, fills it with
+/// `addresses` and waits until they can be looked up, which is only from the
+/// slot after they were added
+pub async fn create_lookup_table(
+    ctx: &ScillaContext,
+    addresses: &[Pubkey],
+) -> anyhow::Result<AddressLookupTableAccount> {
+    // The table address is derived from a slot that must still be in the
+    // SlotHashes sysvar, so take a finalized one
+    let recent_slot = ctx
+        .rpc()
+        .get_slot_with_commitment(CommitmentConfig::finalized())
+        .await?;
+    let (create_ix, table) =
+        lookup_table_instruction::create_lookup_table(*ctx.pubkey(), *ctx.pubkey(), recent_slot);
+    build_and_send_tx(ctx, &[create_ix], &[ctx.signer()]).await?;
+
+    let recover_hint = || {
+        format!(
+            "Lookup table {table} holds rent. Get it back with `solana address-lookup-table \
+             deactivate {table}`, then `close` once it has cooled down"
+        )
+    };
+
+    extend_lookup_table(ctx, &table, addresses)
+        .await
+        .with_context(recover_hint)?;
+    let extended_slot = ctx.rpc().get_slot().await?;
+    while ctx
+        .rpc()
+        .get_slot_with_commitment(CommitmentConfig::processed())
+        .await?
+        <= extended_slot
+    {
+        tokio::time::sleep(Duration::from_millis(DEFAULT_MS_PER_SLOT)).await;
+    }
+
+    fetch_lookup_table(ctx, &table)
+        .await
+        .with_context(recover_hint)
+}
+
+/// Fees for the transactions that create and fill a lookup table holding
+/// `addresses`, and for the one that deactivates it afterwards
+pub async fn lookup_table_setup_fee(
+    ctx: &ScillaContext,
+    addresses: &[Pubkey],
+) -> anyhow::Result<u64> {
+    // Only the message shape matters for the fee, so any slot and table do
+    let (create_ix, table) =
+        lookup_table_instruction::create_lookup_table(*ctx.pubkey(), *ctx.pubkey(), 0);
+    let mut transactions = vec![
+        vec![create_ix],
+        vec![lookup_table_instruction::deactivate_lookup_table(
+            table,
+            *ctx.pubkey(),
+        )],
+    ];
+    transactions.extend(
+        addresses
+            .chunks(LOOKUP_TABLE_EXTEND_CHUNK_SIZE)
+            .map(|chunk| {
+                vec![lookup_table_instruction::extend_lookup_table(
+                    table,
+                    *ctx.pubkey(),
+                    Some(*ctx.pubkey()),
+                    chunk.to_vec(),
+                )]
+            }),
+    );
+
+    let mut fee = 0u64;
+    for instructions in transactions {
+        let instructions = with_compute_budget(
+            &instructions,
+            ctx.priority_fee_micro_lamports(),
+            ctx.compute_unit_limit(),
+        );
+        fee = fee.saturating_add(estimate_fee(ctx, &instructions).await?);
+    }
+
+    Ok(fee)
+}
+
+/// Deactivates a lookup table the wallet is the authority of. It can be
+/// closed for its rent once `LOOKUP_TABLE_COOLDOWN_SLOTS` have passed.
+pub async fn deactivate_lookup_table(
+    ctx: &ScillaContext,
+    table: &Pubkey,
+) -> anyhow::Result<Signature> {
+    let deactivate_ix = lookup_table_instruction::deactivate_lookup_table(*table, *ctx.pubkey());
+    build_and_send_tx(ctx, &[deactivate_ix], &[ctx.signer()]).await
+}
+
+/// Appends `addresses` to a lookup table the wallet is the authority of, a
+/// transaction-sized chunk at a time
+pub async fn extend_lookup_table(
+    ctx: &ScillaContext,
+    table: &Pubkey,
+    addresses: &[Pubkey],
+) -> anyhow::Result<()> {
+    for chunk in addresses.chunks(LOOKUP_TABLE_EXTEND_CHUNK_SIZE) {
+        let extend_ix = lookup_table_instruction::extend_lookup_table(
+            *table,
+            *ctx.pubkey(),
+            Some(*ctx.pubkey()),
+            chunk.to_vec(),
+        );
+        build_and_send_tx(ctx, &[extend_ix], &[ctx.signer()]).await?;
+    }
+
+    Ok(())
+}
+
+/// A durable nonce to sign an offline transaction against, so it stays valid
+/// until the nonce is advanced instead of expiring with the blockhash
+#[derive(Debug, Clone, Copy)]
+pub struct DurableNonce {
+    pub account: Pubkey,
+    pub authority: Pubkey,
+}
+
+/// Builds a transaction over the given blockhash (or stored nonce value) and
+/// signs it with the signers at hand, leaving the other signatures empty to be
+/// added before broadcasting. Nothing is fetched, so this works offline.
+pub fn build_offline_tx(
+    instructions: &[Instruction],
+    fee_payer: &Pubkey,
+    signers: &[&dyn Signer],
+    blockhash: Hash,
+    nonce: Option<DurableNonce>,
+    micro_lamports: Option<u64>,
+    cu_limit: Option<u32>,
+) -> anyhow::Result<Transaction> {
+    let mut instructions = with_compute_budget(instructions, micro_lamports, cu_limit);
+    // The runtime only treats a transaction as durable when advancing the
+    // nonce is its very first instruction
+    if let Some(nonce) = nonce {
+        instructions.insert(0, advance_nonce_account(&nonce.account, &nonce.authority));
+    }
+
+    let message = Message::new(&instructions, Some(fee_payer));
+    let mut tx = Transaction::new_unsigned(message);
+    tx.try_partial_sign(&signers.to_vec(), blockhash)?;
+
+    Ok(tx)
+}
+
+/// The required signers whose signature is still missing
+pub fn missing_signers(tx: &Transaction) -> Vec<Pubkey> {
+    let required = tx.message.header.num_required_signatures as usize;
+    tx.message.account_keys[..required]
+        .iter()
+        .zip(&tx.signatures)
+        .filter(|(_, signature)| **signature == Signature::default())
+        .map(|(pubkey, _)| *pubkey)
+        .collect()
+}
+
+pub fn encode_tx_base64(tx: &Transaction) -> anyhow::Result<String> {
+    let bytes = bincode::serialize(tx).context("Failed to serialize transaction")?;
+    Ok(base64::engine::general_purpose::STANDARD.encode(bytes))
+}
+
+/// Whether `signature` is `pubkey`'s ed25519 signature over the UTF-8 bytes of
+/// `message`
+pub fn is_valid_message_signature(pubkey: &Pubkey, message: &str, signature: &Signature) -> bool {
+    signature.verify(pubkey.as_ref(), message.as_bytes())
+}
+
+/// Runs the transaction through `simulateTransaction`, printing its logs and
+/// bailing if it would fail on-chain
+pub async fn simulate_tx(
+    ctx: &ScillaContext,
+    tx: &impl SerializableTransaction,
+) -> anyhow::Result<()> {
+    let simulation = ctx.rpc().simulate_transaction(tx).await?.value;
+
+    for log in simulation.logs.iter().flatten() {
+        notice!("{}", style(log).dim());
+    }
+
+    if let Some(units) = simulation.units_consumed {
+        notice!(
+            "{}",
+            style(format!("Compute units consumed: {units}")).dim()
+        );
+    }
+
+    if let Some(err) = simulation.err {
+        return Err(anyhow::Error::new(TransactionError::from(err))
+            .context("Transaction simulation failed"));
+    }
+
+    Ok(())
+}
+
+/// Estimates the network fee for sending the given instructions with the
+/// configured wallet as fee payer
+pub async fn estimate_fee(
+    ctx: &ScillaContext,
+    instructions: &[Instruction],
+) -> anyhow::Result<u64> {
+    let recent_blockhash = ctx.latest_blockhash().await?;
+    let message = Message::new_with_blockhash(instructions, Some(ctx.pubkey()), &recent_blockhash);
+
+    Ok(ctx.rpc().get_fee_for_message(&message).await?)
+}
+
+/// Shows the fee for sending `instructions`, priority fee included, and asks
+/// to go ahead before anything is broadcast. Bails if the answer is no.
+pub async fn confirm_fee(ctx: &ScillaContext, instructions: &[Instruction]) -> anyhow::Result<()> {
+    let instructions = with_compute_budget(
+        instructions,
+        ctx.priority_fee_micro_lamports(),
+        ctx.compute_unit_limit(),
+    );
+    let fee = estimate_fee(ctx, &instructions).await?;
+
+    let proceed = suspend_spinner(|| {
+        prompt_confirm(&format!(
+            "Estimated fee: {} SOL. Send the transaction?",
+            lamports_to_sol(fee)
+        ))
+    })?;
+    if !proceed {
+        bail!("Transaction cancelled, nothing was sent");
+    }
+
+    Ok(())
+}
+
+/// Bails with "need X SOL, have Y SOL" unless `balance` covers every cost,
+/// each given as a label and its lamports
+pub fn check_affordable(balance: u64, costs: &[(&str, u64)]) -> anyhow::Result<()> {
+    let required = costs
+        .iter()
+        .fold(0u64, |total, (_, lamports)| total.saturating_add(*lamports));
+
+    if required > balance {
+        let breakdown = costs
+            .iter()
+            .map(|(label, lamports)| format!("{} SOL {label}", lamports_to_sol(*lamports)))
+            .collect::<Vec<_>>()
+            .join(" + ");
+        bail!(
+            "Insufficient balance. Need {} SOL, have {} SOL ({breakdown})",
+            lamports_to_sol(required),
+            lamports_to_sol(balance)
+        );
+    }
+
+    Ok(())
+}
+
+/// Checks up front that the wallet can pay `costs` (amounts sent and rent for
+/// new accounts) plus the fee for `instructions`, rather than letting the
+/// transaction fail on-chain
+pub async fn ensure_sufficient_balance(
+    ctx: &ScillaContext,
+    instructions: &[Instruction],
+    costs: &[(&str, u64)],
+) -> anyhow::Result<()> {
+    let (fee, balance) = try_join!(estimate_fee(ctx, instructions), async {
+        Ok(ctx.rpc().get_balance(ctx.pubkey()).await?)
+    })?;
+
+    let mut costs = costs.to_vec();
+    costs.push(("fee", fee));
+
+    check_affordable(balance, &costs)
+}
+
+/// Fetches many accounts with `getMultipleAccounts`, split into requests of
+/// at most [`MAX_MULTIPLE_ACCOUNTS`] sent concurrently. Results line up with
+/// `pubkeys`, `None` where an account doesn't exist.
+pub async fn fetch_accounts(
+    ctx: &ScillaContext,
+    pubkeys: &[Pubkey],
+) -> anyhow::Result<Vec<Option<Account>>> {
+    let chunks = try_join_all(
+        pubkeys
+            .chunks(MAX_MULTIPLE_ACCOUNTS)
+            .map(|chunk| ctx.rpc().get_multiple_accounts(chunk)),
+    )
+    .await?;
+
+    Ok(chunks.into_iter().flatten().collect())
+}
+
+pub async fn account_exists(ctx: &ScillaContext, pubkey: &Pubkey) -> anyhow::Result<bool> {
+    let response = ctx
+        .rpc()
+        .get_account_with_commitment(pubkey, ctx.rpc().commitment())
+        .await?;
+
+    Ok(response.value.is_some())
+}
+
+/// Rejects addresses off the ed25519 curve. Only the program that derived
+/// such an address can move funds sent to it.
+pub fn ensure_on_curve(pubkey: Pubkey) -> Result<Pubkey, InvalidPubkey> {
+    match pubkey.is_on_curve() {
+        true => Ok(pubkey),
+        false => Err(InvalidPubkey::OffCurve(pubkey)),
+    }
+}
+
+/// Builds a block explorer link for a transaction, picking the cluster from
+/// the RPC URL
+pub fn explorer_tx_url(explorer: Explorer, rpc_url: &str, signature: &Signature) -> String {
+    let cluster_param = match explorer {
+        Explorer::SolanaExplorer | Explorer::Solscan => {
+            if rpc_url.contains("devnet") {
+                "?cluster=devnet".to_string()
+            } else if rpc_url.contains("testnet") {
+                "?cluster=testnet".to_string()
+            } else if rpc_url.contains("mainnet") {
+                String::new()
+            } else {
+                format!("?cluster=custom&customUrl={rpc_url}")
+            }
+        }
+        Explorer::SolanaFm => {
+            if rpc_url.contains("devnet") {
+                "?cluster=devnet-solana".to_string()
+            } else if rpc_url.contains("testnet") {
+                "?cluster=testnet-solana".to_string()
+            } else if rpc_url.contains("mainnet") {
+                String::new()
+            } else {
+                "?cluster=localnet-solana".to_string()
+            }
+        }
+    };
+
+    let base_url = match explorer {
+        Explorer::SolanaExplorer => EXPLORER_TX_BASE_URL,
+        Explorer::Solscan => SOLSCAN_TX_BASE_URL,
+        Explorer::SolanaFm => SOLANA_FM_TX_BASE_URL,
+    };
+
+    format!("{base_url}/{signature}{cluster_param}")
+}
+
+/// Fetches account data and current epoch info in parallel.
+pub async fn fetch_account_with_epoch(
+    ctx: &ScillaContext,
+    pubkey: &Pubkey,
+) -> anyhow::Result<(Account, EpochInfo)> {
+    try_join!(
+        async {
+            ctx.rpc()
+                .get_account(pubkey)
+                .await
+                .map_err(|_| anyhow!("{pubkey} account does not exist"))
+        },
+        ctx.epoch_info()
+    )
+}
+
+/// Generic helper to deserialize bincode data with consistent error
+/// context
+pub fn bincode_deserialize<T>(data: &[u8], ctx: &str) -> anyhow::Result<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    bincode::deserialize::<T>(data).with_context(|| format!("Failed to deserialize {}", ctx))
+}
+
+/// Generic helper to deserialize bincode data with limit and proper error
+/// context
+pub fn bincode_deserialize_with_limit<T>(limit: u64, data: &[u8], ctx: &str) -> anyhow::Result<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    bincode::options()
+        .with_fixint_encoding()
+        .with_limit(limit)
+        .deserialize::<T>(data)
+        .with_context(|| format!("Failed to deserialize {}", ctx))
+}
+
+pub fn decode_base64(encoded: &str) -> anyhow::Result<Vec<u8>> {
+    let trimmed = encoded.trim();
+    if trimmed.is_empty() {
+        bail!("Encoded data cannot be empty");
+    }
+
+    base64::engine::general_purpose::STANDARD
+        .decode(trimmed)
+        .map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to decode Base64: {}. Please ensure the data is valid Base64 encoded.",
+                e
+            )
+        })
+}
+
+pub fn decode_base58(encoded: &str) -> anyhow::Result<Vec<u8>> {
+    let trimmed = encoded.trim();
+    if trimmed.is_empty() {
+        bail!("Encoded data cannot be empty");
+    }
+
+    bs58::decode(trimmed).into_vec().map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to decode Base58: {}. Please ensure the data is valid Base58 encoded.",
+            e
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::constants::MEMO_PROGRAM_ID};
+
+    const TEST_SEED_PHRASE: &str = "abandon abandon abandon abandon abandon abandon abandon \
+                                    abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_keypair_from_seed_phrase_is_deterministic() -> anyhow::Result<()> {
+        let keypair = keypair_from_seed_phrase(TEST_SEED_PHRASE, "", "m/44'/501'/0'/0'")?;
+        let spaced = keypair_from_seed_phrase(
+            &format!("  {}  ", TEST_SEED_PHRASE.to_uppercase()),
+            "",
+            "m/44'/501'/0'/0'",
+        )?;
+        assert_eq!(
+            keypair.pubkey().to_string(),
+            "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk"
+        );
+        assert_eq!(keypair.pubkey(), spaced.pubkey());
+
+        let other_account = keypair_from_seed_phrase(TEST_SEED_PHRASE, "", "m/44'/501'/1'/0'")?;
+        let with_passphrase =
+            keypair_from_seed_phrase(TEST_SEED_PHRASE, "secret", "m/44'/501'/0'/0'")?;
+        assert_ne!(keypair.pubkey(), other_account.pubkey());
+        assert_ne!(keypair.pubkey(), with_passphrase.pubkey());
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_seed_phrase() -> anyhow::Result<()> {
+        let phrase = generate_seed_phrase(24)?;
+        assert_eq!(phrase.split_whitespace().count(), 24);
+        assert_ne!(phrase, generate_seed_phrase(24)?);
+        keypair_from_seed_phrase(&phrase, "", "m/44'/501'/0'/0'")?;
+
+        assert!(generate_seed_phrase(13).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_keypair_from_seed_phrase_rejects_bad_input() {
+        assert!(keypair_from_seed_phrase("abandon about", "", "m/44'/501'/0'/0'").is_err());
+        assert!(keypair_from_seed_phrase(TEST_SEED_PHRASE, "", "44'/501'").is_err());
+        assert!(parse_derivation_path("m/44'/501'/x'").is_err());
+        assert!(parse_derivation_path("m/44'/501'/0'/0'").is_ok());
+    }
+
+    #[test]
+    fn test_parse_transfer_csv_skips_header_and_comments() -> anyhow::Result<()> {
+        let first = Keypair::new().pubkey();
+        let second = Keypair::new().pubkey();
+        let csv = format!("pubkey,amount_sol\n\n# payroll\n{first}, 1.5\n{second},0.25\n");
+
+        let rows = parse_transfer_csv(&csv)?;
+
+        assert_eq!(
+            rows,
+            vec![
+                TransferRow {
+                    line: 4,
+                    recipient: first,
+                    lamports: 1_500_000_000,
+                },
+                TransferRow {
+                    line: 5,
+                    recipient: second,
+                    lamports: 250_000_000,
+                },
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_memo_instruction() -> anyhow::Result<()> {
+        let payer = Pubkey::new_unique();
+        let transfer_ix =
+            solana_system_interface::instruction::transfer(&payer, &Pubkey::new_unique(), 1);
+
+        let memo = memo_instruction(
+            "deposit 1234",
+            &payer,
+            std::slice::from_ref(&transfer_ix),
+            None,
+            None,
+        )?;
+        assert_eq!(memo.program_id, Pubkey::from_str(MEMO_PROGRAM_ID)?);
+        assert_eq!(memo.data, b"deposit 1234");
+        assert!(memo.accounts[0].is_signer);
+
+        let too_long = "x".repeat(MAX_TRANSACTION_SIZE);
+        assert!(memo_instruction(&too_long, &payer, &[transfer_ix], None, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_version_key() {
+        assert_eq!(version_key("2.2.14"), vec![2, 2, 14]);
+        assert_eq!(version_key("0.505.20216-beta"), vec![0, 505, 20216]);
+        assert!(version_key("2.10.0") > version_key("2.9.3"));
+        assert!(version_key("unknown").is_empty());
+    }
+
+    #[test]
+    fn test_average_epoch_credits() {
+        let credits = [(8, 100, 0), (9, 400, 100), (10, 800, 400), (11, 850, 800)];
+
+        // The current epoch is still running, so it is left out
+        assert_eq!(average_epoch_credits(&credits, 11, 5), Some(800.0 / 3.0));
+        assert_eq!(average_epoch_credits(&credits, 11, 1), Some(400.0));
+        assert_eq!(average_epoch_credits(&credits, 12, 2), Some(225.0));
+        assert_eq!(average_epoch_credits(&credits, 8, 5), None);
+        assert_eq!(average_epoch_credits(&[], 11, 5), None);
+    }
+
+    #[test]
+    fn test_format_block_time() {
+        assert_eq!(
+            format_block_time(1_700_000_000, TimestampZone::Utc),
+            "2023-11-14 22:13:20 UTC"
+        );
+        assert_eq!(
+            format_block_time(i64::MAX, TimestampZone::Utc),
+            i64::MAX.to_string()
+        );
+
+        let local = format_block_time(1_700_000_000, TimestampZone::Local);
+        assert!(!local.ends_with("UTC"), "local time was {local}");
+    }
+
+    #[test]
+    fn test_is_rate_limit_error() {
+        assert!(is_rate_limit_error(
+            "HTTP status client error (429 Too Many Requests)"
+        ));
+        assert!(is_rate_limit_error(
+            "airdrop request failed. This can happen when the rate limit is reached."
+        ));
+        assert!(is_rate_limit_error(
+            "You've either reached your airdrop limit today"
+        ));
+        assert!(!is_rate_limit_error("Invalid param: WrongSize"));
+    }
+
+    #[test]
+    fn test_ensure_on_curve() {
+        let wallet = Keypair::new().pubkey();
+        assert_eq!(ensure_on_curve(wallet), Ok(wallet));
+
+        let (pda, _) = Pubkey::find_program_address(&[b"scilla"], &Pubkey::new_unique());
+        assert_eq!(ensure_on_curve(pda), Err(InvalidPubkey::OffCurve(pda)));
+    }
+
+    #[test]
+    fn test_parse_pubkey_list() -> anyhow::Result<()> {
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        let third = Pubkey::new_unique();
+
+        let inline = parse_pubkey_list(&format!("{first}, {second},{third}"))?;
+        assert_eq!(inline, [first, second, third]);
+
+        let file = format!("# treasury\n{first}\n\n{second} {first}\n  {third}\n");
+        assert_eq!(parse_pubkey_list(&file)?, [first, second, third]);
+
+        let err = parse_pubkey_list(&format!("{first},nope,also-bad")).unwrap_err();
+        assert!(err.to_string().contains("`nope`"));
+        assert!(err.to_string().contains("`also-bad`"));
+        assert!(parse_pubkey_list(" , \n# only a comment").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_transfer_csv_reports_every_bad_row() {
+        let valid = Keypair::new().pubkey();
+        let csv = format!("{valid},1\nnot-a-key,1\n{valid},-2\n{valid}\n");
+
+        let err = parse_transfer_csv(&csv).unwrap_err().to_string();
+
+        assert!(err.contains("line 2: invalid pubkey `not-a-key`"));
+        assert!(err.contains("line 3:"));
+        assert!(err.contains("line 4: expected 2 columns"));
+        assert!(parse_transfer_csv("pubkey,amount_sol\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_transfer_csv_rejects_off_curve_recipient() {
+        let wallet = Keypair::new().pubkey();
+        let (pda, _) = Pubkey::find_program_address(&[b"vault"], &Pubkey::new_unique());
+        let csv = format!("{wallet},1\n{pda},2\n");
+
+        let err = parse_transfer_csv(&csv).unwrap_err().to_string();
+
+        assert!(err.contains(&format!("line 2: {}", InvalidPubkey::OffCurve(pda))));
+        assert!(!err.contains("line 1:"));
+    }
+
+    #[test]
+    fn test_pack_instructions_respects_transaction_size() -> anyhow::Result<()> {
+        let payer = Pubkey::new_unique();
+        let instructions: Vec<Instruction> = (0..60)
+            .map(|_| {
+                solana_system_interface::instruction::transfer(&payer, &Pubkey::new_unique(), 1)
+            })
+            .collect();
+
+        let batches = pack_instructions(&payer, &instructions, &[], Some(1_000), Some(200_000))?;
+
+        assert!(batches.len() > 1);
+        assert_eq!(batches.first().map(|batch| batch.start), Some(0));
+        assert_eq!(
+            batches.last().map(|batch| batch.end),
+            Some(instructions.len())
+        );
+        for pair in batches.windows(2) {
+            assert_eq!(pair[0].end, pair[1].start);
+        }
+        for batch in &batches {
+            let message = Message::new(
+                &with_compute_budget(&instructions[batch.clone()], Some(1_000), Some(200_000)),
+                Some(&payer),
+            );
+            let size = bincode::serialized_size(&Transaction::new_unsigned(message))?;
+            assert!(size as usize <= MAX_TRANSACTION_SIZE);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_token_amount_uses_mint_decimals() -> anyhow::Result<()> {
+        assert_eq!(parse_token_amount("12.5", 6)?, 12_500_000);
+        assert_eq!(parse_token_amount(" 3 ", 2)?, 300);
+        assert_eq!(parse_token_amount(".25", 2)?, 25);
+        assert_eq!(parse_token_amount("7", 0)?, 7);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_token_amount_rejects_invalid_input() {
+        assert!(parse_token_amount("1.234", 2).is_err());
+        assert!(parse_token_amount("0", 6).is_err());
+        assert!(parse_token_amount("-1", 6).is_err());
+        assert!(parse_token_amount("1e3", 6).is_err());
+        assert!(parse_token_amount(".", 6).is_err());
+        assert!(parse_token_amount("99999999999999999999", 0).is_err());
+    }
+
+    #[test]
+    fn test_stake_authority_offsets_match_layout() -> anyhow::Result<()> {
+        use {
+            crate::constants::{
+                STAKE_AUTHORIZED_STAKER_OFFSET, STAKE_AUTHORIZED_WITHDRAWER_OFFSET,
+            },
+            solana_stake_interface::state::{Authorized, Meta, StakeStateV2},
+        };
+
+        let staker = Pubkey::new_unique();
+        let withdrawer = Pubkey::new_unique();
+        let state = StakeStateV2::Initialized(Meta {
+            authorized: Authorized { staker, withdrawer },
+            ..Meta::default()
+        });
+        let data = bincode::serialize(&state)?;
+
+        let at = |offset: usize| &data[offset..offset + 32];
+        assert_eq!(at(STAKE_AUTHORIZED_STAKER_OFFSET), staker.as_ref());
+        assert_eq!(at(STAKE_AUTHORIZED_WITHDRAWER_OFFSET), withdrawer.as_ref());
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_csv_escapes_fields() {
+        let csv = to_csv(
+            &["slot", "status"],
+            vec![
+                vec!["1".to_string(), "Success".to_string()],
+                vec!["2".to_string(), "Failed: a, \"b\"".to_string()],
+            ],
+        );
+
+        assert_eq!(csv, "slot,status\n1,Success\n2,\"Failed: a, \"\"b\"\"\"\n");
+    }
+
+    #[test]
+    fn test_sol_to_lamports_rounds_to_nearest_lamport() {
+        assert_eq!(sol_to_lamports(0.1), 100_000_000);
+        assert_eq!(sol_to_lamports(0.3), 300_000_000);
+        assert_eq!(sol_to_lamports(0.7), 700_000_000);
+        assert_eq!(sol_to_lamports(1.1), 1_100_000_000);
+        assert_eq!(sol_to_lamports(2.675), 2_675_000_000);
+        assert_eq!(sol_to_lamports(0.000000001), 1);
+        assert_eq!(sol_to_lamports(123.456789012), 123_456_789_012);
+    }
+
+    #[test]
+    fn test_sol_amount_rejects_sub_lamport_precision() -> anyhow::Result<()> {
+        assert_eq!("0.1".parse::<SolAmount>()?.to_lamports(), 100_000_000);
+        assert_eq!("0.000000001".parse::<SolAmount>()?.to_lamports(), 1);
+        assert!("0.0000000001".parse::<SolAmount>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_sol_amount_accepts_units_and_separators() -> anyhow::Result<()> {
+        assert_eq!("1.5 SOL".parse::<SolAmount>()?.to_lamports(), 1_500_000_000);
+        assert_eq!("1.5sol".parse::<SolAmount>()?.to_lamports(), 1_500_000_000);
+        assert_eq!(
+            "1_000 SOL".parse::<SolAmount>()?.to_lamports(),
+            1_000_000_000_000
+        );
+        assert_eq!(
+            "500000000 lamports".parse::<SolAmount>()?.to_lamports(),
+            500_000_000
+        );
+        assert_eq!(
+            "500_000_000 Lamports".parse::<SolAmount>()?.to_lamports(),
+            500_000_000
+        );
+        assert_eq!("1 lamport".parse::<SolAmount>()?.to_lamports(), 1);
+        // Exact where a float would round
+        assert_eq!(
+            "18446744073709551615 lamports"
+                .parse::<SolAmount>()?
+                .to_lamports(),
+            u64::MAX
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sol_amount_rejects_ambiguous_input() {
+        for input in [
+            "",
+            "SOL",
+            "lamports",
+            "1.5 lamports",
+            "1,000",
+            "1e9",
+            "-1",
+            "0",
+            "0 lamports",
+            "_1000",
+            "1000_",
+            "1__000",
+            "1_.5",
+            "1 SOL lamports",
+            "1 SOL SOL",
+            "18446744073709551616 lamports",
+        ] {
+            assert!(input.parse::<SolAmount>().is_err(), "accepted `{input}`");
+        }
+    }
+
+    #[test]
+    fn test_withdraw_amount_accepts_all() -> anyhow::Result<()> {
+        assert!(matches!(
+            "ALL".parse::<WithdrawAmount>()?,
+            WithdrawAmount::All
+        ));
+        assert!(matches!(
+            "  ".parse::<WithdrawAmount>()?,
+            WithdrawAmount::All
+        ));
+        assert_eq!("all".parse::<WithdrawAmount>()?.to_lamports(42), 42);
+        assert_eq!(
+            "1.5".parse::<WithdrawAmount>()?.to_lamports(42),
+            1_500_000_000
+        );
+        assert!("half".parse::<WithdrawAmount>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_slots_until_epoch() {
+        let epoch_info = EpochInfo {
+            epoch: 100,
+            slot_index: 150_000,
+            slots_in_epoch: 432_000,
+            absolute_slot: 43_350_000,
+            block_height: 0,
+            transaction_count: None,
+        };
+
+        assert_eq!(slots_until_epoch(&epoch_info, 99), 0);
+        assert_eq!(slots_until_epoch(&epoch_info, 100), 0);
+        assert_eq!(slots_until_epoch(&epoch_info, 101), 282_000);
+        assert_eq!(slots_until_epoch(&epoch_info, 103), 282_000 + 2 * 432_000);
+        assert_eq!(
+            slots_to_duration(282_000, 400.0),
+            Duration::from_secs(112_800)
+        );
+    }
+
+    #[test]
+    fn test_annualized_yield() {
+        let epochs = epochs_per_year(432_000, 400.0);
+        assert!((epochs - 182.625).abs() < 1e-9);
+
+        // 0.03% per epoch compounds to roughly 5.63% a year
+        let apy = annualized_yield(300_000, 1_000_300_000, epochs).unwrap();
+        assert!((apy - 5.63).abs() < 0.01, "apy was {apy}");
+
+        assert_eq!(annualized_yield(0, 5, epochs), Some(0.0));
+        assert_eq!(annualized_yield(10, 10, epochs), None);
+        assert_eq!(annualized_yield(10, 5, epochs), None);
+    }
+
+    #[test]
+    fn test_compound_epoch_rate() {
+        assert_eq!(compound_epoch_rate(0.0, 182.625), 0.0);
+        assert!((compound_epoch_rate(0.01, 2.0) - 2.01).abs() < 1e-9);
+        // A 10% commission takes a tenth of each epoch's rate before compounding
+        let gross = compound_epoch_rate(0.0003, 182.625);
+        let net = compound_epoch_rate(0.0003 * 0.9, 182.625);
+        assert!(net < gross && gross - net > 0.5);
+    }
+
+    #[test]
+    fn test_check_affordable() {
+        assert!(
+            check_affordable(
+                1_000_005_000,
+                &[("transfer", 1_000_000_000), ("fee", 5_000)]
+            )
+            .is_ok()
+        );
+        assert!(check_affordable(0, &[]).is_ok());
+
+        let err = check_affordable(
+            500_000_000,
+            &[
+                ("stake", 1_000_000_000),
+                ("rent exemption", 2_282_880),
+                ("fee", 10_000),
+            ],
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Insufficient balance. Need 1.00229288 SOL, have 0.5 SOL (1 SOL stake + 0.00228288 \
+             SOL rent exemption + 0.00001 SOL fee)"
+        );
+    }
+
+    #[test]
+    fn test_lookup_table_candidates_skip_signers_and_programs() {
+        let payer = Pubkey::new_unique();
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        let instructions = [
+            solana_system_interface::instruction::transfer(&payer, &first, 1),
+            solana_system_interface::instruction::transfer(&payer, &second, 1),
+            solana_system_interface::instruction::transfer(&payer, &first, 1),
+        ];
+
+        assert_eq!(lookup_table_candidates(&instructions), vec![first, second]);
+    }
+
+    #[test]
+    fn test_pack_instructions_with_lookup_table_fits_more() -> anyhow::Result<()> {
+        let signer = Keypair::new();
+        let payer = signer.pubkey();
+        let recipients: Vec<Pubkey> = (0..120).map(|_| Pubkey::new_unique()).collect();
+        let instructions: Vec<Instruction> = recipients
+            .iter()
+            .map(|recipient| solana_system_interface::instruction::transfer(&payer, recipient, 1))
+            .collect();
+        let lookup_table = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: lookup_table_candidates(&instructions),
+        };
+
+        let legacy = pack_instructions(&payer, &instructions, &[], Some(1_000), Some(200_000))?;
+        let with_table = pack_instructions(
+            &payer,
+            &instructions,
+            std::slice::from_ref(&lookup_table),
+            Some(1_000),
+            Some(200_000),
+        )?;
+
+        assert!(with_table.len() < legacy.len());
+        assert_eq!(
+            with_table.last().map(|batch| batch.end),
+            Some(instructions.len())
+        );
+        for batch in &with_table {
+            let tx = build_versioned_tx(
+                &instructions[batch.clone()],
+                &payer,
+                &[&signer],
+                Hash::new_unique(),
+                std::slice::from_ref(&lookup_table),
+                Some(1_000),
+                Some(200_000),
+            )?;
+            assert!(bincode::serialized_size(&tx)? as usize <= MAX_TRANSACTION_SIZE);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_versioned_tx_loads_accounts_from_lookup_table() -> anyhow::Result<()> {
+        let payer = Keypair::new();
+        let recipients: Vec<Pubkey> = (0..40).map(|_| Pubkey::new_unique()).collect();
+        let instructions: Vec<Instruction> = recipients
+            .iter()
+            .map(|recipient| {
+                solana_system_interface::instruction::transfer(&payer.pubkey(), recipient, 1)
+            })
+            .collect();
+        let lookup_table = AddressLookupTableAccount {
+            key: Pubkey::new_unique(),
+            addresses: recipients.clone(),
+        };
+
+        let tx = build_versioned_tx(
+            &instructions,
+            &payer.pubkey(),
+            &[&payer],
+            Hash::new_unique(),
+            &[lookup_table],
+            None,
+            None,
+        )?;
+
+        let VersionedMessage::V0(message) = &tx.message else {
+            panic!("expected a v0 message");
+        };
+        assert_eq!(message.address_table_lookups.len(), 1);
+        assert_eq!(
+            message.address_table_lookups[0].writable_indexes.len(),
+            recipients.len()
+        );
+        // Payer and system program only
+        assert_eq!(message.account_keys.len(), 2);
+        assert!(tx.verify_with_results().iter().all(|valid| *valid));
+
+        // The same transfers don't fit a legacy transaction
+        let legacy = Transaction::new_unsigned(Message::new(&instructions, Some(&payer.pubkey())));
+        assert!(bincode::serialized_size(&legacy)? as usize > MAX_TRANSACTION_SIZE);
+        assert!(bincode::serialized_size(&tx)? as usize <= MAX_TRANSACTION_SIZE);
+        Ok(())
+    }
+
+    #[test]
+    fn test_offline_tx_with_durable_nonce() -> anyhow::Result<()> {
+        let authority = Keypair::new();
+        let fee_payer = Pubkey::new_unique();
+        let nonce = DurableNonce {
+            account: Pubkey::new_unique(),
+            authority: authority.pubkey(),
+        };
+        let instruction = solana_system_interface::instruction::transfer(
+            &authority.pubkey(),
+            &Pubkey::new_unique(),
+            1,
+        );
+        let nonce_value = Hash::new_unique();
+
+        let tx = build_offline_tx(
+            &[instruction],
+            &fee_payer,
+            &[&authority],
+            nonce_value,
+            Some(nonce),
+            Some(1_000),
+            None,
+        )?;
+
+        assert_eq!(tx.message.recent_blockhash, nonce_value);
+        let first_program = tx.message.instructions[0].program_id(&tx.message.account_keys);
+        assert_eq!(*first_program, solana_sdk_ids::system_program::id());
+        assert_eq!(missing_signers(&tx), vec![fee_payer]);
+
+        let encoded = encode_tx_base64(&tx)?;
+        let decoded: Transaction =
+            bincode_deserialize(&decode_base64(&encoded)?, "encoded transaction")?;
+        assert_eq!(decoded, tx);
+        Ok(())
+    }
+
+    #[test]
+    fn test_message_signature_round_trip() {
+        let keypair = Keypair::new();
+        let signature = keypair.sign_message("I own this address".as_bytes());
+
+        assert!(is_valid_message_signature(
+            &keypair.pubkey(),
+            "I own this address",
+            &signature
+        ));
+        assert!(!is_valid_message_signature(
+            &keypair.pubkey(),
+            "I own this address!",
+            &signature
+        ));
+        assert!(!is_valid_message_signature(
+            &Pubkey::new_unique(),
+            "I own this address",
+            &signature
+        ));
+    }
+
+    #[test]
+    fn test_ws_url_from_rpc_url() -> anyhow::Result<()> {
+        assert_eq!(
+            ws_url_from_rpc_url("https://api.devnet.solana.com")?,
+            "wss://api.devnet.solana.com"
+        );
+        assert_eq!(
+            ws_url_from_rpc_url("http://localhost:8899")?,
+            "ws://localhost:8900"
+        );
+        assert_eq!(
+            ws_url_from_rpc_url("https://rpc.example.com/v1/key?x=1")?,
+            "wss://rpc.example.com/v1/key?x=1"
+        );
+        assert_eq!(ws_url_from_rpc_url("http://[::1]:8899")?, "ws://[::1]:8900");
+        assert!(ws_url_from_rpc_url("localhost:8899").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hexdump() {
+        let data: Vec<u8> = (0x41..0x41 + 18).collect();
+        let dump = hexdump(&data);
+        let lines: Vec<&str> = dump.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "00000000  41 42 43 44 45 46 47 48  49 4a 4b 4c 4d 4e 4f 50  |ABCDEFGHIJKLMNOP|"
+        );
+        assert_eq!(
+            lines[1],
+            "00000010  51 52                                             |QR|"
+        );
+        assert_eq!(
+            hexdump(&[0x00, 0x7f]),
+            format!("00000000  00 7f{}|..|", " ".repeat(45))
+        );
+        assert_eq!(hexdump(&[]), "");
+    }
+
+    #[test]
+    fn test_max_write_chunk_size_fills_transaction() -> anyhow::Result<()> {
+        let payer = Pubkey::new_unique();
+        let buffer = Pubkey::new_unique();
+        let chunk_size = max_write_chunk_size(&payer, &buffer, Some(1_000), Some(200_000))?;
+
+        let write = |len: usize| loader_v3_instruction::write(&buffer, &payer, 0, vec![0; len]);
+        let packed = pack_instructions(
+            &payer,
+            &[write(chunk_size)],
+            &[],
+            Some(1_000),
+            Some(200_000),
+        )?;
+        assert_eq!(packed, vec![0..1]);
+        assert!(
+            pack_instructions(
+                &payer,
+                &[write(chunk_size + 1)],
+                &[],
+                Some(1_000),
+                Some(200_000)
+            )
+            .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sparkline() {
+        assert_eq!(sparkline(&[0.0, 1.0, 2.0, 7.0]), "▁▂▃█");
+        assert_eq!(sparkline(&[5.0, 5.0]), "▅▅");
+        assert_eq!(sparkline(&[]), "");
+    }
+
+    #[test]
+    fn test_slot_runs() {
+        assert_eq!(
+            slot_runs(&[4, 5, 6, 7, 20, 21, 22, 23, 40]),
+            vec![(4, 7), (20, 23), (40, 40)]
+        );
+        assert!(slot_runs(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_solana_pay_uri() {
+        let recipient = Pubkey::new_unique();
+        assert_eq!(
+            solana_pay_uri(&recipient, None),
+            format!("solana:{recipient}")
+        );
+        assert_eq!(
+            solana_pay_uri(&recipient, Some(1_500_000_000)),
+            format!("solana:{recipient}?amount=1.5")
+        );
+        assert_eq!(
+            solana_pay_uri(&recipient, Some(1)),
+            format!("solana:{recipient}?amount=0.000000001")
+        );
+        assert_eq!(format_sol_exact(2 * LAMPORTS_PER_SOL), "2");
+    }
+
+    #[test]
+    fn test_render_qr() -> anyhow::Result<()> {
+        let qr = render_qr(&solana_pay_uri(&Pubkey::new_unique(), None))?;
+        let lines: Vec<&str> = qr.lines().collect();
+        assert!(lines.len() > 10);
+        assert!(
+            lines
+                .iter()
+                .all(|line| line.chars().count() == lines[0].chars().count())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_optional_input_empty_is_none() -> anyhow::Result<()> {
+        let input: OptionalInput<u64> = "   ".parse()?;
+        assert_eq!(input.value(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_optional_input_parses_value() -> anyhow::Result<()> {
+        let input: OptionalInput<u64> = " 42 ".parse()?;
+        assert_eq!(input.value(), Some(42));
+        assert!("abc".parse::<OptionalInput<u64>>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_with_compute_budget_prepends_budget_instructions() {
+        let memo =
+            Instruction::new_with_bytes(Pubkey::from_str_const(MEMO_PROGRAM_ID), b"hi", vec![]);
+
+        let instructions =
+            with_compute_budget(std::slice::from_ref(&memo), Some(5_000), Some(200_000));
+
+        assert_eq!(instructions.len(), 3);
+        assert_eq!(
+            instructions[0],
+            ComputeBudgetInstruction::set_compute_unit_limit(200_000)
+        );
+        assert_eq!(
+            instructions[1],
+            ComputeBudgetInstruction::set_compute_unit_price(5_000)
+        );
+        assert_eq!(instructions[2], memo);
+    }
+
+    #[test]
+    fn test_with_compute_budget_without_fee_is_unchanged() {
+        let memo =
+            Instruction::new_with_bytes(Pubkey::from_str_const(MEMO_PROGRAM_ID), b"hi", vec![]);
+
+        let instructions = with_compute_budget(std::slice::from_ref(&memo), None, None);
+
+        assert_eq!(instructions, vec![memo]);
+    }
+
+    #[test]
+    fn test_is_retryable_send_error() {
+        assert!(is_retryable_send_error(
+            "RPC response error -32002: Transaction simulation failed: Blockhash not found"
+        ));
+        assert!(is_retryable_send_error(
+            "unable to confirm transaction. This can happen in situations such as transaction \
+             expiration and insufficient fee-payer funds"
+        ));
+        assert!(is_retryable_send_error("operation timed out"));
+        assert!(!is_retryable_send_error(
+            "Transaction simulation failed: Error processing Instruction 0: insufficient funds"
+        ));
+    }
+
+    #[test]
+    fn test_truncate_middle_shortens_long_strings() {
+        let signature = "2Bpup7xRM9TZ83J5Pk1wfECTcLyUXxb9nr4Buuv6UmePi5WjeiX4iZCPvcVwfkHj3Yanez6BWwLyEPyWydN9S6Hm";
+        assert_eq!(truncate_middle(signature, 8), "2Bpup7xR…ydN9S6Hm");
+    }
+
+    #[test]
+    fn test_truncate_middle_keeps_short_strings() {
+        assert_eq!(truncate_middle("", 8), "");
+        assert_eq!(truncate_middle("abc", 8), "abc");
+        assert_eq!(truncate_middle("0123456789abcdef", 8), "0123456789abcdef");
+        assert_eq!(truncate_middle("0123456789abcdefg", 8), "0123456789abcdefg");
+    }
+
+    #[test]
+    fn test_truncate_middle_handles_multibyte_chars() {
+        assert_eq!(truncate_middle("ααααβββββγγγγ", 4), "αααα…γγγγ");
+    }
+
+    #[test]
+    fn test_progress_bar_fills_proportionally() {
+        assert_eq!(progress_bar(0.0, 4), "[░░░░]");
+        assert_eq!(progress_bar(0.5, 4), "[██░░]");
+        assert_eq!(progress_bar(1.0, 4), "[████]");
+    }
+
+    #[test]
+    fn test_progress_bar_clamps_out_of_range() {
+        assert_eq!(progress_bar(-0.5, 4), "[░░░░]");
+        assert_eq!(progress_bar(1.5, 4), "[████]");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_secs(42)), "42s");
+        assert_eq!(format_duration(Duration::from_secs(125)), "2m 5s");
+        assert_eq!(format_duration(Duration::from_secs(3_725)), "1h 2m");
+        assert_eq!(format_duration(Duration::from_secs(180_000)), "2d 2h 0m");
+    }
+
+    #[test]
+    fn test_percentile_nearest_rank() {
+        let samples: Vec<Duration> = (1..=10).map(Duration::from_millis).collect();
+        assert_eq!(percentile(&samples, 50.0), Some(Duration::from_millis(5)));
+        assert_eq!(percentile(&samples, 90.0), Some(Duration::from_millis(9)));
+        assert_eq!(percentile(&samples, 100.0), Some(Duration::from_millis(10)));
+        assert_eq!(percentile(&samples, 0.0), Some(Duration::from_millis(1)));
+        assert_eq!(percentile(&[], 90.0), None);
+    }
+
+    #[test]
+    fn test_explorer_tx_url_picks_cluster() {
+        let signature = Signature::default();
+
+        assert_eq!(
+            explorer_tx_url(
+                Explorer::SolanaExplorer,
+                "https://api.devnet.solana.com",
+                &signature
+            ),
+            format!("{EXPLORER_TX_BASE_URL}/{signature}?cluster=devnet")
+        );
+        assert_eq!(
+            explorer_tx_url(
+                Explorer::SolanaExplorer,
+                "https://api.mainnet-beta.solana.com",
+                &signature
+            ),
+            format!("{EXPLORER_TX_BASE_URL}/{signature}")
+        );
+        assert_eq!(
+            explorer_tx_url(
+                Explorer::SolanaExplorer,
+                "http://localhost:8899",
+                &signature
+            ),
+            format!(
+                "{EXPLORER_TX_BASE_URL}/{signature}?cluster=custom&customUrl=http://localhost:8899"
+            )
+        );
+    }
+
+    #[test]
+    fn test_explorer_tx_url_alternative_explorers() {
+        let signature = Signature::default();
+
+        assert_eq!(
+            explorer_tx_url(
+                Explorer::Solscan,
+                "https://api.testnet.solana.com",
+                &signature
+            ),
+            format!("{SOLSCAN_TX_BASE_URL}/{signature}?cluster=testnet")
+        );
+        assert_eq!(
+            explorer_tx_url(
+                Explorer::SolanaFm,
+                "https://api.devnet.solana.com",
+                &signature
+            ),
+            format!("{SOLANA_FM_TX_BASE_URL}/{signature}?cluster=devnet-solana")
+        );
+    }
+
     #[test]
     fn test_lamports_to_sol_exact_one_sol() {
         assert_eq!(lamports_to_sol(1_000_000_000), 1.0);
@@ -1,38 +1,247 @@
 use {
-    crate::commands::{
-        Command, CommandGroup, account::AccountCommand, cluster::ClusterCommand,
-        config::ConfigCommand, stake::StakeCommand, transaction::TransactionCommand,
-        vote::VoteCommand,
+    crate::{
+        ScillaContext,
+        address_book::resolve_address_name,
+        commands::{
+            Command, CommandGroup, account::AccountCommand, address_book::AddressBookCommand,
+            cluster::ClusterCommand, config::ConfigCommand, nonce::NonceCommand,
+            program::ProgramCommand, stake::StakeCommand, token::TokenCommand,
+            transaction::TransactionCommand, vote::VoteCommand,
+        },
+        error::InvalidPubkey,
+        history::{CommandHistory, HistoryEntry},
+        misc::helpers::{OptionalInput, account_exists, ensure_on_curve},
+    },
+    anyhow::{anyhow, bail},
+    console::style,
+    inquire::{Confirm, InquireError, Password, PasswordDisplayMode, Select, Text},
+    solana_pubkey::Pubkey,
+    std::{
+        any::TypeId,
+        collections::VecDeque,
+        fmt,
+        str::FromStr,
+        sync::{
+            Mutex,
+            atomic::{AtomicBool, Ordering},
+        },
     },
-    inquire::{Select, Text},
-    std::str::FromStr,
 };
+
+/// Answers supplied on the command line. When set, [`prompt_data`] reads from
+/// here instead of prompting so scripted runs never block on input.
+static PRESET_ANSWERS: Mutex<Option<VecDeque<String>>> = Mutex::new(None);
+
+pub fn set_preset_answers(answers: Vec<String>) {
+    *PRESET_ANSWERS.lock().unwrap() = Some(answers.into());
+}
+
+pub fn clear_preset_answers() {
+    PRESET_ANSWERS.lock().unwrap().take();
+}
+
+/// `None` in interactive mode, otherwise the next answer, with a missing one
+/// read as empty input
+fn next_preset_answer() -> Option<String> {
+    PRESET_ANSWERS
+        .lock()
+        .unwrap()
+        .as_mut()
+        .map(|answers| answers.pop_front().unwrap_or_default())
+}
+
+/// Answers given to [`prompt_data`], [`prompt_select`] and [`prompt_yes_no`]
+/// since the last [`take_answer_log`], kept so
+/// the command can be saved to the history with them
+static ANSWER_LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+fn log_answer(input: &str) {
+    ANSWER_LOG.lock().unwrap().push(input.trim().to_string());
+}
+
+pub fn take_answer_log() -> Vec<String> {
+    std::mem::take(&mut *ANSWER_LOG.lock().unwrap())
+}
+
+/// Set by `--yes`, answers every [`prompt_confirm`] with yes
+static ASSUME_YES: AtomicBool = AtomicBool::new(false);
+
+pub fn set_assume_yes(assume_yes: bool) {
+    ASSUME_YES.store(assume_yes, Ordering::Relaxed);
+}
+
+/// Whether `err` comes from Ctrl-C pressed at a prompt
+pub fn is_interrupted(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        matches!(
+            cause.downcast_ref::<InquireError>(),
+            Some(InquireError::OperationInterrupted)
+        )
+    })
+}
+
+/// Asks a yes/no question that defaults to no, for operations that are costly
+/// to get wrong
+pub fn prompt_confirm(msg: &str) -> anyhow::Result<bool> {
+    if ASSUME_YES.load(Ordering::Relaxed) {
+        return Ok(true);
+    }
+
+    Ok(Confirm::new(msg).with_default(false).prompt()?)
+}
+
+/// A [`Select`] that takes a preset answer by its option label, so choices
+/// can be scripted like any other answer. Case, spaces and dashes are
+/// ignored, e.g. `non-circulating` picks "Non-Circulating".
+pub fn prompt_select<T: fmt::Display>(select: Select<'_, T>) -> anyhow::Result<T> {
+    let Some(input) = next_preset_answer() else {
+        let choice = select.prompt()?;
+        log_answer(&choice.to_string());
+        return Ok(choice);
+    };
+
+    let msg = select.message;
+    if input.trim().is_empty() {
+        bail!("Missing argument for \"{msg}\"");
+    }
+    let labels: Vec<String> = select.options.iter().map(ToString::to_string).collect();
+    let Some(index) = labels
+        .iter()
+        .position(|label| option_key(label) == option_key(&input))
+    else {
+        bail!(
+            "Invalid argument `{input}` for \"{msg}\": expected one of {}",
+            labels.join(", ")
+        );
+    };
+
+    log_answer(&input);
+    Ok(select
+        .options
+        .into_iter()
+        .nth(index)
+        .expect("index of a listed option"))
+}
+
+fn option_key(label: &str) -> String {
+    label.trim().to_lowercase().replace([' ', '_'], "-")
+}
+
+/// A [`Confirm`] for a plain yes/no choice that takes a preset answer such as
+/// `yes` or `n`. An empty one picks the default. Confirmations of costly
+/// operations go through [`prompt_confirm`] and `--yes` instead.
+pub fn prompt_yes_no(confirm: Confirm<'_>) -> anyhow::Result<bool> {
+    let Some(input) = next_preset_answer() else {
+        let answer = confirm.prompt()?;
+        log_answer(if answer { "yes" } else { "no" });
+        return Ok(answer);
+    };
+
+    let msg = confirm.message;
+    let answer = match (input.trim(), confirm.default) {
+        ("", Some(default)) => default,
+        ("", None) => bail!("Missing argument for \"{msg}\""),
+        (input, _) => (confirm.parser)(input).map_err(|()| {
+            anyhow!("Invalid argument `{input}` for \"{msg}\": expected yes or no")
+        })?,
+    };
+
+    log_answer(&input);
+    Ok(answer)
+}
+
 pub fn prompt_for_command() -> anyhow::Result<Command> {
-    let top_level = Select::new(
-        "Choose a command group:",
-        vec![
-            CommandGroup::Account,
-            CommandGroup::Cluster,
-            CommandGroup::Stake,
-            CommandGroup::Vote,
-            CommandGroup::Transaction,
-            CommandGroup::ScillaConfig,
-            CommandGroup::Exit,
-        ],
-    )
-    .prompt()?;
+    loop {
+        let history = CommandHistory::load().unwrap_or_default();
+        let top_level = prompt_command_group(!history.is_empty())?;
+
+        // Backing out of the history list returns to the top level
+        let command = match top_level {
+            CommandGroup::Recent => match prompt_recent(&history)? {
+                Some(command) => command,
+                None => continue,
+            },
+            CommandGroup::Cluster => Command::Cluster(prompt_cluster()?),
+            CommandGroup::Stake => Command::Stake(prompt_stake()?),
+            CommandGroup::Account => Command::Account(prompt_account()?),
+            CommandGroup::Token => Command::Token(prompt_token()?),
+            CommandGroup::Vote => Command::Vote(prompt_vote()?),
+            CommandGroup::ScillaConfig => Command::ScillaConfig(prompt_config()?),
+            CommandGroup::Transaction => Command::Transaction(prompt_transaction()?),
+            CommandGroup::Program => Command::Program(prompt_program()?),
+            CommandGroup::AddressBook => Command::AddressBook(prompt_address_book()?),
+            CommandGroup::Help => Command::Help,
+            CommandGroup::Exit => Command::Exit,
+        };
+
+        return Ok(command);
+    }
+}
+
+fn prompt_command_group(has_history: bool) -> anyhow::Result<CommandGroup> {
+    let mut groups = Vec::new();
+    if has_history {
+        groups.push(CommandGroup::Recent);
+    }
+    groups.extend([
+        CommandGroup::Account,
+        CommandGroup::Token,
+        CommandGroup::Cluster,
+        CommandGroup::Stake,
+        CommandGroup::Vote,
+        CommandGroup::Transaction,
+        CommandGroup::Program,
+        CommandGroup::AddressBook,
+        CommandGroup::ScillaConfig,
+        CommandGroup::Help,
+        CommandGroup::Exit,
+    ]);
+
+    Ok(Select::new("Choose a command group:", groups).prompt()?)
+}
 
-    let command = match top_level {
-        CommandGroup::Cluster => Command::Cluster(prompt_cluster()?),
-        CommandGroup::Stake => Command::Stake(prompt_stake()?),
-        CommandGroup::Account => Command::Account(prompt_account()?),
-        CommandGroup::Vote => Command::Vote(prompt_vote()?),
-        CommandGroup::ScillaConfig => Command::ScillaConfig(prompt_config()?),
-        CommandGroup::Transaction => Command::Transaction(prompt_transaction()?),
-        CommandGroup::Exit => Command::Exit,
+/// An entry in the recent commands list
+enum RecentChoice {
+    Entry(HistoryEntry),
+    GoBack,
+}
+
+impl fmt::Display for RecentChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecentChoice::Entry(entry) => write!(f, "{entry}"),
+            RecentChoice::GoBack => write!(f, "Go back"),
+        }
+    }
+}
+
+/// Picks a command from the history and queues its saved answers, so it runs
+/// again without retyping them. `None` when the user goes back.
+fn prompt_recent(history: &CommandHistory) -> anyhow::Result<Option<Command>> {
+    let mut choices: Vec<_> = history
+        .entries()
+        .iter()
+        .cloned()
+        .map(RecentChoice::Entry)
+        .collect();
+    choices.push(RecentChoice::GoBack);
+
+    let RecentChoice::Entry(entry) = Select::new("Run again:", choices).prompt()? else {
+        return Ok(None);
+    };
+    let Some((command, answers)) = entry.to_command() else {
+        eprintln!(
+            "{}",
+            style(format!("`{entry}` is no longer a Scilla command")).yellow()
+        );
+        return Ok(None);
     };
 
-    Ok(command)
+    if !answers.is_empty() {
+        eprintln!("{}", style(format!("↻ scilla {entry}")).dim());
+        set_preset_answers(answers);
+    }
+    Ok(Some(command))
 }
 
 fn prompt_cluster() -> anyhow::Result<ClusterCommand> {
@@ -43,10 +252,19 @@ fn prompt_cluster() -> anyhow::Result<ClusterCommand> {
             ClusterCommand::CurrentSlot,
             ClusterCommand::BlockHeight,
             ClusterCommand::BlockTime,
+            ClusterCommand::Activity,
             ClusterCommand::Validators,
+            ClusterCommand::FindValidators,
+            ClusterCommand::Nodes,
             ClusterCommand::ClusterVersion,
+            ClusterCommand::Health,
+            ClusterCommand::Ping,
+            ClusterCommand::Performance,
+            ClusterCommand::LeaderSchedule,
+            ClusterCommand::BlockProduction,
             ClusterCommand::SupplyInfo,
             ClusterCommand::Inflation,
+            ClusterCommand::Rent,
             ClusterCommand::GoBack,
         ],
     )
@@ -59,13 +277,22 @@ fn prompt_stake() -> anyhow::Result<StakeCommand> {
     let choice = Select::new(
         "Stake Command:",
         vec![
+            StakeCommand::StakeToValidator,
             StakeCommand::Create,
             StakeCommand::Delegate,
             StakeCommand::Deactivate,
+            StakeCommand::DeactivatePartial,
             StakeCommand::Withdraw,
             StakeCommand::Merge,
+            StakeCommand::MergeAll,
             StakeCommand::Split,
+            StakeCommand::Authorize,
+            StakeCommand::SetLockup,
             StakeCommand::Show,
+            StakeCommand::Rewards,
+            StakeCommand::ExportRewards,
+            StakeCommand::EstimateApy,
+            StakeCommand::List,
             StakeCommand::History,
             StakeCommand::GoBack,
         ],
@@ -81,10 +308,18 @@ fn prompt_account() -> anyhow::Result<AccountCommand> {
         vec![
             AccountCommand::FetchAccount,
             AccountCommand::Balance,
+            AccountCommand::MultiBalance,
             AccountCommand::Transfer,
+            AccountCommand::BatchTransfer,
             AccountCommand::Airdrop,
+            AccountCommand::ConfirmTransaction,
             AccountCommand::LargestAccounts,
             AccountCommand::NonceAccount,
+            AccountCommand::NewKeypair,
+            AccountCommand::SignMessage,
+            AccountCommand::VerifyMessage,
+            AccountCommand::Watch,
+            AccountCommand::Receive,
             AccountCommand::GoBack,
         ],
     )
@@ -93,12 +328,46 @@ fn prompt_account() -> anyhow::Result<AccountCommand> {
     Ok(choice)
 }
 
+pub fn prompt_nonce() -> anyhow::Result<NonceCommand> {
+    let choice = Select::new(
+        "Nonce Command:",
+        vec![
+            NonceCommand::Create,
+            NonceCommand::Show,
+            NonceCommand::Advance,
+            NonceCommand::Withdraw,
+            NonceCommand::Authorize,
+            NonceCommand::GoBack,
+        ],
+    )
+    .prompt()?;
+
+    Ok(choice)
+}
+
+fn prompt_token() -> anyhow::Result<TokenCommand> {
+    let choice = Select::new(
+        "Token Command:",
+        vec![
+            TokenCommand::Balances,
+            TokenCommand::Transfer,
+            TokenCommand::CloseEmpty,
+            TokenCommand::GoBack,
+        ],
+    )
+    .prompt()?;
+
+    Ok(choice)
+}
+
 fn prompt_vote() -> anyhow::Result<VoteCommand> {
     let choice = Select::new(
         "Vote Command:",
         vec![
             VoteCommand::CreateVoteAccount,
-            VoteCommand::AuthorizeVoter,
+            VoteCommand::Authorize,
+            VoteCommand::UpdateIdentity,
+            VoteCommand::UpdateCommission,
             VoteCommand::WithdrawFromVoteAccount,
             VoteCommand::ShowVoteAccount,
             VoteCommand::CloseVoteAccount,
@@ -118,6 +387,33 @@ fn prompt_transaction() -> anyhow::Result<TransactionCommand> {
             TransactionCommand::FetchStatus,
             TransactionCommand::FetchTransaction,
             TransactionCommand::SendTransaction,
+            TransactionCommand::SignOffline,
+            TransactionCommand::Broadcast,
+        ],
+    )
+    .prompt()?;
+
+    Ok(choice)
+}
+
+fn prompt_program() -> anyhow::Result<ProgramCommand> {
+    let choice = Select::new(
+        "Program Command:",
+        vec![ProgramCommand::Deploy, ProgramCommand::GoBack],
+    )
+    .prompt()?;
+
+    Ok(choice)
+}
+
+fn prompt_address_book() -> anyhow::Result<AddressBookCommand> {
+    let choice = Select::new(
+        "Address Book Command:",
+        vec![
+            AddressBookCommand::Add,
+            AddressBookCommand::List,
+            AddressBookCommand::Remove,
+            AddressBookCommand::GoBack,
         ],
     )
     .prompt()?;
@@ -125,6 +421,24 @@ fn prompt_transaction() -> anyhow::Result<TransactionCommand> {
     Ok(choice)
 }
 
+/// Parses `input`, falling back to the address it names in the address book
+/// so any pubkey prompt takes a saved name. Other prompts never resolve
+/// names, so a name can't turn into e.g. a blockhash.
+fn parse_input<T: FromStr + 'static>(input: &str) -> Result<T, T::Err> {
+    let takes_address = TypeId::of::<T>() == TypeId::of::<Pubkey>()
+        || TypeId::of::<T>() == TypeId::of::<OptionalInput<Pubkey>>();
+
+    T::from_str(input).or_else(|err| {
+        if !takes_address {
+            return Err(err);
+        }
+        let address = resolve_address_name(input).ok_or(err)?;
+        let value = T::from_str(&address.to_string())?;
+        eprintln!("{}", style(format!("{} → {address}", input.trim())).dim());
+        Ok(value)
+    })
+}
+
 fn prompt_config() -> anyhow::Result<ConfigCommand> {
     let choice = Select::new(
         "ScillaConfig Command:",
@@ -132,6 +446,11 @@ fn prompt_config() -> anyhow::Result<ConfigCommand> {
             ConfigCommand::Show,
             ConfigCommand::Generate,
             ConfigCommand::Edit,
+            ConfigCommand::OpenInEditor,
+            ConfigCommand::SwitchProfile,
+            ConfigCommand::AddProfile,
+            ConfigCommand::Reset,
+            ConfigCommand::NextCommitment,
             ConfigCommand::GoBack,
         ],
     )
@@ -142,13 +461,136 @@ fn prompt_config() -> anyhow::Result<ConfigCommand> {
 
 pub fn prompt_data<T>(msg: &str) -> anyhow::Result<T>
 where
-    T: FromStr,
+    T: FromStr + 'static,
     <T as FromStr>::Err: ToString + Send + Sync + 'static,
 {
+    // A bad command line argument can't be corrected by re-prompting
+    if let Some(input) = next_preset_answer() {
+        log_answer(&input);
+        return parse_input::<T>(&input).map_err(|e| match input.is_empty() {
+            true => anyhow!("Missing argument for \"{msg}\""),
+            false => anyhow!(
+                "Invalid argument `{input}` for \"{msg}\": {}",
+                e.to_string()
+            ),
+        });
+    }
+
     loop {
         let input = Text::new(msg).prompt()?;
-        match T::from_str(&input) {
-            Ok(value) => return Ok(value),
+        match parse_input::<T>(&input) {
+            Ok(value) => {
+                log_answer(&input);
+                return Ok(value);
+            }
+            Err(e) => {
+                eprintln!("Invalid input: {}. Please try again.\n", e.to_string());
+            }
+        }
+    }
+}
+
+/// Checks [`prompt_pubkey`] runs on top of the base58 format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PubkeyCheck {
+    /// On the ed25519 curve, so a keypair can sign for it. Catches a PDA
+    /// entered where a wallet is expected.
+    Wallet,
+    /// Already created on chain, PDAs included
+    Existing,
+}
+
+/// Like [`prompt_data`] for a pubkey, but also rejects addresses that fail
+/// `check`, telling a malformed address apart from an off-curve or missing one
+pub async fn prompt_pubkey(
+    ctx: &ScillaContext,
+    msg: &str,
+    check: PubkeyCheck,
+) -> anyhow::Result<Pubkey> {
+    loop {
+        let preset = next_preset_answer();
+        let from_args = preset.is_some();
+        let input = match preset {
+            Some(input) => input,
+            None => Text::new(msg).prompt()?,
+        };
+
+        match check_pubkey(ctx, &input, check).await? {
+            Ok(pubkey) => {
+                log_answer(&input);
+                return Ok(pubkey);
+            }
+            // A bad command line argument can't be corrected by re-prompting
+            Err(_) if from_args && input.trim().is_empty() => {
+                return Err(anyhow!("Missing argument for \"{msg}\""));
+            }
+            Err(e) if from_args => {
+                return Err(anyhow!("Invalid argument `{input}` for \"{msg}\": {e}"));
+            }
+            Err(e) => eprintln!("Invalid input: {e}. Please try again.\n"),
+        }
+    }
+}
+
+/// The outer error is an RPC failure, the inner one a rejected address
+async fn check_pubkey(
+    ctx: &ScillaContext,
+    input: &str,
+    check: PubkeyCheck,
+) -> anyhow::Result<Result<Pubkey, InvalidPubkey>> {
+    let Ok(pubkey) = parse_input::<Pubkey>(input) else {
+        return Ok(Err(InvalidPubkey::Malformed(input.trim().to_string())));
+    };
+
+    Ok(match check {
+        PubkeyCheck::Wallet => ensure_on_curve(pubkey),
+        PubkeyCheck::Existing => match account_exists(ctx, &pubkey).await? {
+            true => Ok(pubkey),
+            false => Err(InvalidPubkey::NotFound(pubkey)),
+        },
+    })
+}
+
+/// Reads a seed phrase, passphrase or key with the typed characters masked.
+/// Secrets are never taken from command line arguments, where they would end
+/// up in shell history.
+pub fn prompt_secret(msg: &str) -> anyhow::Result<String> {
+    Ok(Password::new(msg)
+        .with_display_mode(PasswordDisplayMode::Masked)
+        .without_confirmation()
+        .prompt()?)
+}
+
+/// Like [`prompt_data`], but shows `default` in brackets and parses it when
+/// the input is left empty
+pub fn prompt_data_with_default<T>(msg: &str, default: &str) -> anyhow::Result<T>
+where
+    T: FromStr + 'static,
+    <T as FromStr>::Err: ToString + Send + Sync + 'static,
+{
+    let msg = format!("{msg} [{default}]");
+    let parse = |input: &str| {
+        let input = input.trim();
+        parse_input::<T>(if input.is_empty() { default } else { input })
+    };
+
+    if let Some(input) = next_preset_answer() {
+        log_answer(&input);
+        return parse(&input).map_err(|e| {
+            anyhow!(
+                "Invalid argument `{input}` for \"{msg}\": {}",
+                e.to_string()
+            )
+        });
+    }
+
+    loop {
+        let input = Text::new(&msg).prompt()?;
+        match parse(&input) {
+            Ok(value) => {
+                log_answer(&input);
+                return Ok(value);
+            }
             Err(e) => {
                 eprintln!("Invalid input: {}. Please try again.\n", e.to_string());
             }
@@ -1,27 +1,208 @@
 use {
+    crate::{
+        context::{OutputFormat, ScillaContext},
+        misc::helpers::explorer_tx_url,
+    },
+    comfy_table::Table,
     console::style,
     indicatif::{ProgressBar, ProgressStyle},
+    serde::Serialize,
+    serde_json::json,
+    solana_signature::Signature,
+    std::sync::{
+        Mutex,
+        atomic::{AtomicBool, Ordering},
+    },
 };
 
+/// Set by `--json`, keeps stdout for the JSON result alone
+static JSON_OUTPUT: AtomicBool = AtomicBool::new(false);
+
+pub fn set_json_output(json_output: bool) {
+    JSON_OUTPUT.store(json_output, Ordering::Relaxed);
+}
+
+pub fn json_output() -> bool {
+    JSON_OUTPUT.load(Ordering::Relaxed)
+}
+
+/// `println!` for anything besides a command's result, such as notices,
+/// warnings and previews. It goes to stderr in JSON mode so piping the output
+/// into a JSON parser keeps working.
+#[macro_export]
+macro_rules! notice {
+    ($($arg:tt)*) => {
+        if $crate::ui::json_output() {
+            eprintln!($($arg)*);
+        } else {
+            println!($($arg)*);
+        }
+    };
+}
+
+/// Turns off colors and text attributes everywhere, for `--no-color`.
+/// `NO_COLOR` is honoured without it.
+pub fn disable_colors() {
+    console::set_colors_enabled(false);
+    console::set_colors_enabled_stderr(false);
+}
+
+/// A table that is only styled when colors are enabled
+pub fn new_table() -> Table {
+    let mut table = Table::new();
+    if !console::colors_enabled() {
+        table.force_no_tty();
+    }
+    table
+}
+
+/// The spinner currently on screen, so a prompt raised from inside a task can
+/// hide it while waiting for input
+static ACTIVE_SPINNER: Mutex<Option<ProgressBar>> = Mutex::new(None);
+
+/// Runs `f` with the active spinner, if any, cleared from the terminal
+pub fn suspend_spinner<R>(f: impl FnOnce() -> R) -> R {
+    let spinner = ACTIVE_SPINNER.lock().unwrap().clone();
+    match spinner {
+        Some(spinner) => spinner.suspend(f),
+        None => f(),
+    }
+}
+
+/// Stops the active spinner after Ctrl-C dropped its task, so the terminal is
+/// left on a clean line
+pub fn abandon_spinner() {
+    if let Some(spinner) = ACTIVE_SPINNER.lock().unwrap().take() {
+        spinner.set_style(spinner_style("{spinner:.cyan} {msg}"));
+        spinner.abandon_with_message(
+            style("✗ Interrupted. A transaction already sent may still land")
+                .yellow()
+                .to_string(),
+        );
+    }
+    let _ = console::Term::stderr().show_cursor();
+}
+
+fn spinner_style(template: &str) -> ProgressStyle {
+    ProgressStyle::with_template(template)
+        .unwrap()
+        .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏ ")
+}
+
+fn new_spinner(message: &str) -> ProgressBar {
+    let spinner = ProgressBar::new_spinner();
+    // The elapsed time ticks along so a slow RPC call doesn't look hung
+    spinner.set_style(spinner_style("{spinner:.cyan} {msg} {elapsed:.dim}"));
+    spinner.enable_steady_tick(std::time::Duration::from_millis(100));
+    spinner.set_message(message.to_string());
+    *ACTIVE_SPINNER.lock().unwrap() = Some(spinner.clone());
+    spinner
+}
+
+fn finish_spinner<T>(spinner: &ProgressBar, result: &anyhow::Result<T>) {
+    ACTIVE_SPINNER.lock().unwrap().take();
+    let elapsed = format!("{:.1}s", spinner.elapsed().as_secs_f64());
+    spinner.set_style(spinner_style("{spinner:.cyan} {msg}"));
+    match result {
+        Ok(_) => {
+            spinner.finish_with_message(format!("✅ Done {}", style(format!("in {elapsed}")).dim()))
+        }
+        Err(err) => spinner.finish_with_message(
+            style(format!("✗ Failed after {elapsed}: {err}"))
+                .red()
+                .to_string(),
+        ),
+    }
+}
+
 pub async fn show_spinner<F, T>(message: &str, fut: F) -> anyhow::Result<T>
 where
     F: std::future::Future<Output = anyhow::Result<T>>,
 {
-    let spinner = ProgressBar::new_spinner();
-    spinner.set_style(
-        ProgressStyle::with_template("{spinner:.cyan} {msg}")
-            .unwrap()
-            .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏ "),
-    );
-    spinner.enable_steady_tick(std::time::Duration::from_millis(100));
-    spinner.set_message(message.to_string());
+    let spinner = new_spinner(message);
 
     let result = fut.await;
-    spinner.finish_with_message("✅ Done");
+    finish_spinner(&spinner, &result);
+
+    result
+}
+
+/// Like [`show_spinner`], but hands the spinner to the task so it can update
+/// the message while it runs
+pub async fn show_spinner_with_status<F, Fut, T>(message: &str, task: F) -> anyhow::Result<T>
+where
+    F: FnOnce(ProgressBar) -> Fut,
+    Fut: std::future::Future<Output = anyhow::Result<T>>,
+{
+    let spinner = new_spinner(message);
+
+    let result = task(spinner.clone()).await;
+    finish_spinner(&spinner, &result);
 
     result
 }
 
 pub fn print_error(message: impl std::fmt::Display) {
-    println!("\n{}\n", style(message).red().bold());
+    notice!("\n{}\n", style(message).red().bold());
+}
+
+pub fn print_explorer_link(ctx: &ScillaContext, signature: &Signature) {
+    let url = explorer_tx_url(ctx.explorer(), &ctx.rpc().url(), signature);
+    println!("{}", style(format!("Explorer: {url}")).dim());
+}
+
+/// Prints `value` as JSON in JSON mode, otherwise runs `render` to print the
+/// human readable output
+pub fn print_output<T: Serialize>(
+    ctx: &ScillaContext,
+    value: &T,
+    render: impl FnOnce(),
+) -> anyhow::Result<()> {
+    let Some(commitment) = ctx.commitment_override() else {
+        match ctx.output_format() {
+            OutputFormat::Text => render(),
+            OutputFormat::Json => println!("{}", serde_json::to_string_pretty(value)?),
+        }
+        return Ok(());
+    };
+
+    match ctx.output_format() {
+        OutputFormat::Text => {
+            render();
+            println!(
+                "{}",
+                style(format!("Commitment: {commitment} (overridden)")).dim()
+            );
+        }
+        OutputFormat::Json => {
+            let mut value = serde_json::to_value(value)?;
+            if let Some(fields) = value.as_object_mut() {
+                fields.insert("commitment".to_string(), json!(commitment.to_string()));
+            }
+            println!("{}", serde_json::to_string_pretty(&value)?);
+        }
+    }
+    Ok(())
+}
+
+/// Like [`print_output`] for a landed transaction. The JSON output gains the
+/// signature and explorer link, the text output ends with the explorer link.
+pub fn print_tx_output(
+    ctx: &ScillaContext,
+    signature: &Signature,
+    mut value: serde_json::Value,
+    render: impl FnOnce(),
+) -> anyhow::Result<()> {
+    if let Some(fields) = value.as_object_mut() {
+        fields.insert("signature".to_string(), json!(signature.to_string()));
+        fields.insert(
+            "explorer".to_string(),
+            json!(explorer_tx_url(ctx.explorer(), &ctx.rpc().url(), signature)),
+        );
+    }
+
+    print_output(ctx, &value, || {
+        render();
+        print_explorer_link(ctx, signature);
+    })
 }